@@ -0,0 +1,229 @@
+/* src/capi.rs에서 cbindgen --config cbindgen.toml로 생성 - 직접 수정하지 말 것 */
+
+#ifndef DISCRETE_MATHEMATICS_EQUIVALENCE_H
+#define DISCRETE_MATHEMATICS_EQUIVALENCE_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+/**
+ * C API 버전 (호환되지 않는 변경이 있을 때만 증가)
+ */
+#define DME_API_VERSION 1
+
+/**
+ * 폐포 종류 (report::ClosureKind와 같은 순서)
+ */
+#define DME_CLOSURE_REFLEXIVE 0
+
+#define DME_CLOSURE_SYMMETRIC 1
+
+#define DME_CLOSURE_TRANSITIVE 2
+
+#define DME_CLOSURE_EQUIVALENCE 3
+
+/**
+ * 모든 함수가 반환하는 상태 코드
+ */
+typedef enum {
+  /**
+   * 성공
+   */
+  DME_STATUS_OK = 0,
+  /**
+   * 필수 포인터 인자가 NULL
+   */
+  DME_STATUS_NULL_POINTER = 1,
+  /**
+   * 행렬 크기가 0이거나 버퍼 길이가 n×n과 다름, 또는 빈 관계의 크기가 1024를 넘음
+   */
+  DME_STATUS_INVALID_SIZE = 2,
+  /**
+   * 행렬 원소가 0 또는 1이 아님
+   */
+  DME_STATUS_INVALID_VALUE = 3,
+  /**
+   * 원소 또는 동치류 번호가 범위를 벗어남
+   */
+  DME_STATUS_OUT_OF_RANGE = 4,
+  /**
+   * 동치 관계가 아니어서 동치류를 구할 수 없음
+   */
+  DME_STATUS_NOT_EQUIVALENCE = 5,
+  /**
+   * 알 수 없는 폐포 종류
+   */
+  DME_STATUS_INVALID_CLOSURE = 6,
+} DmeStatus;
+
+/**
+ * C 쪽에서는 내용을 볼 수 없는 동치류 목록 핸들
+ */
+typedef struct DmeClasses DmeClasses;
+
+/**
+ * C 쪽에서는 내용을 볼 수 없는 관계 핸들
+ */
+typedef struct DmeRelation DmeRelation;
+
+
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * n×n 빈 관계(모든 원소 0)를 만드는 함수 (n은 1 이상 1024 이하)
+ *
+ * # Safety
+ * out은 NULL이거나 DmeRelation 포인터를 쓸 수 있는 유효한 주소여야 함
+ */
+DmeStatus dme_relation_new(size_t n, DmeRelation **out);
+
+/**
+ * 행 우선(row-major) 순서의 n×n 바이트 배열로 관계를 만드는 함수 (n은 1 이상 1024 이하)
+ *
+ * # Safety
+ * cells는 len 바이트를 읽을 수 있는 유효한 포인터, out은 쓰기 가능한 유효한 주소여야 함
+ */
+DmeStatus dme_relation_from_matrix(const uint8_t *cells,
+                                   size_t len,
+                                   size_t n,
+                                   DmeRelation **out);
+
+/**
+ * 관계 핸들을 해제하는 함수 (NULL이면 아무것도 하지 않음)
+ *
+ * # Safety
+ * relation은 NULL이거나 이 라이브러리가 만든 아직 해제되지 않은 핸들이어야 함
+ */
+void dme_relation_free(DmeRelation *relation);
+
+/**
+ * 원소의 개수 n을 out에 쓰는 함수
+ *
+ * # Safety
+ * relation은 유효한 핸들, out은 쓰기 가능한 유효한 주소여야 함
+ */
+DmeStatus dme_relation_size(const DmeRelation *relation, size_t *out);
+
+/**
+ * R(i, j)를 value(0 또는 1)로 바꾸는 함수
+ *
+ * # Safety
+ * relation은 유효한 핸들이어야 함
+ */
+DmeStatus dme_relation_set(DmeRelation *relation, size_t i, size_t j, uint8_t value);
+
+/**
+ * R(i, j)를 out에 쓰는 함수
+ *
+ * # Safety
+ * relation은 유효한 핸들, out은 쓰기 가능한 유효한 주소여야 함
+ */
+DmeStatus dme_relation_get(const DmeRelation *relation, size_t i, size_t j, uint8_t *out);
+
+/**
+ * 관계 행렬을 행 우선 순서로 호출자의 버퍼(길이 len = n×n)에 복사하는 함수
+ *
+ * # Safety
+ * relation은 유효한 핸들, buffer는 len 바이트를 쓸 수 있는 유효한 포인터여야 함
+ */
+DmeStatus dme_relation_copy_matrix(const DmeRelation *relation,
+                                   uint8_t *buffer,
+                                   size_t len);
+
+/**
+ * 반사성 판별 (equivalence::is_reflexive)
+ *
+ * # Safety
+ * relation은 유효한 핸들, out은 쓰기 가능한 유효한 주소여야 함
+ */
+DmeStatus dme_is_reflexive(const DmeRelation *relation, bool *out);
+
+/**
+ * 대칭성 판별 (equivalence::is_symmetric)
+ *
+ * # Safety
+ * relation은 유효한 핸들, out은 쓰기 가능한 유효한 주소여야 함
+ */
+DmeStatus dme_is_symmetric(const DmeRelation *relation, bool *out);
+
+/**
+ * 추이성 판별 (equivalence::is_transitive)
+ *
+ * # Safety
+ * relation은 유효한 핸들, out은 쓰기 가능한 유효한 주소여야 함
+ */
+DmeStatus dme_is_transitive(const DmeRelation *relation, bool *out);
+
+/**
+ * 동치 관계 판별 (equivalence::is_equivalence_relation)
+ *
+ * # Safety
+ * relation은 유효한 핸들, out은 쓰기 가능한 유효한 주소여야 함
+ */
+DmeStatus dme_is_equivalence_relation(const DmeRelation *relation, bool *out);
+
+/**
+ * 폐포를 계산하여 새 관계 핸들을 out에 쓰는 함수 (원래 관계는 그대로)
+ * kind는 DME_CLOSURE_REFLEXIVE, _SYMMETRIC, _TRANSITIVE, _EQUIVALENCE 중 하나
+ *
+ * # Safety
+ * relation은 유효한 핸들, out은 쓰기 가능한 유효한 주소여야 함
+ */
+DmeStatus dme_closure(const DmeRelation *relation, uint32_t kind, DmeRelation **out);
+
+/**
+ * 동치류 목록을 계산하여 out에 쓰는 함수 (동치 관계가 아니면 DME_STATUS_NOT_EQUIVALENCE)
+ *
+ * # Safety
+ * relation은 유효한 핸들, out은 쓰기 가능한 유효한 주소여야 함
+ */
+DmeStatus dme_equivalence_classes(const DmeRelation *relation,
+                                  DmeClasses **out);
+
+/**
+ * 동치류의 개수를 out에 쓰는 함수
+ *
+ * # Safety
+ * classes는 유효한 핸들, out은 쓰기 가능한 유효한 주소여야 함
+ */
+DmeStatus dme_classes_count(const DmeClasses *classes, size_t *out);
+
+/**
+ * index번째 동치류의 원소 배열과 길이를 elements/len에 쓰는 함수
+ * 배열은 classes 핸들이 해제될 때까지 유효하며 호출자가 해제하지 않음
+ *
+ * # Safety
+ * classes는 유효한 핸들, elements와 len은 쓰기 가능한 유효한 주소여야 함
+ */
+DmeStatus dme_classes_get(const DmeClasses *classes,
+                          size_t index,
+                          const size_t **elements,
+                          size_t *len);
+
+/**
+ * 동치류 목록 핸들을 해제하는 함수 (NULL이면 아무것도 하지 않음)
+ *
+ * # Safety
+ * classes는 NULL이거나 이 라이브러리가 만든 아직 해제되지 않은 핸들이어야 함
+ */
+void dme_classes_free(DmeClasses *classes);
+
+/**
+ * 상태 코드의 영어 설명 (정적 문자열이므로 해제하지 않음, 알 수 없는 코드도 허용)
+ */
+const char *dme_status_message(uint32_t status);
+
+/**
+ * C API 버전 (DME_API_VERSION)
+ */
+uint32_t dme_api_version(void);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* DISCRETE_MATHEMATICS_EQUIVALENCE_H */
@@ -0,0 +1,657 @@
+/// C 언어용 ABI 모듈 - C/Python 등에서 관계 생성, 속성 판별, 폐포, 동치류 조회를 호출
+/// 헤더는 include/discrete_mathematics_equivalence.h (cbindgen --config cbindgen.toml로 재생성)
+/// 소유권: *_new/*_from_*/dme_closure/dme_equivalence_classes가 돌려준 포인터는 호출자가
+/// 해당 *_free 함수로 한 번만 해제해야 하며, 원소 번호는 C 관례에 맞춰 0부터 시작
+use std::ffi::c_char;
+
+use crate::Matrix;
+
+/// C API 버전 (호환되지 않는 변경이 있을 때만 증가)
+pub const DME_API_VERSION: u32 = 1;
+
+/// 모든 함수가 반환하는 상태 코드
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DmeStatus {
+    /// 성공
+    Ok = 0,
+    /// 필수 포인터 인자가 NULL
+    NullPointer = 1,
+    /// 행렬 크기가 0이거나 버퍼 길이가 n×n과 다름, 또는 빈 관계의 크기가 1024를 넘음
+    InvalidSize = 2,
+    /// 행렬 원소가 0 또는 1이 아님
+    InvalidValue = 3,
+    /// 원소 또는 동치류 번호가 범위를 벗어남
+    OutOfRange = 4,
+    /// 동치 관계가 아니어서 동치류를 구할 수 없음
+    NotEquivalence = 5,
+    /// 알 수 없는 폐포 종류
+    InvalidClosure = 6,
+}
+
+/// 폐포 종류 (report::ClosureKind와 같은 순서)
+pub const DME_CLOSURE_REFLEXIVE: u32 = 0;
+pub const DME_CLOSURE_SYMMETRIC: u32 = 1;
+pub const DME_CLOSURE_TRANSITIVE: u32 = 2;
+pub const DME_CLOSURE_EQUIVALENCE: u32 = 3;
+
+/// C 쪽에서는 내용을 볼 수 없는 관계 핸들
+pub struct DmeRelation {
+    matrix: Matrix,
+}
+
+/// C 쪽에서는 내용을 볼 수 없는 동치류 목록 핸들
+pub struct DmeClasses {
+    classes: Vec<Vec<usize>>,
+}
+
+/// 관계 핸들을 힙에 만들어 out에 쓰는 헬퍼 함수
+///
+/// # Safety
+/// out은 유효한 쓰기 가능 포인터여야 함
+unsafe fn write_relation(matrix: Matrix, out: *mut *mut DmeRelation) -> DmeStatus {
+    unsafe { *out = Box::into_raw(Box::new(DmeRelation { matrix })) };
+    DmeStatus::Ok
+}
+
+/// n×n 빈 관계(모든 원소 0)를 만드는 함수 (n은 1 이상 1024 이하)
+///
+/// # Safety
+/// out은 NULL이거나 DmeRelation 포인터를 쓸 수 있는 유효한 주소여야 함
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dme_relation_new(n: usize, out: *mut *mut DmeRelation) -> DmeStatus {
+    if out.is_null() {
+        return DmeStatus::NullPointer;
+    }
+    if super::parse::check_size(n).is_err() {
+        return DmeStatus::InvalidSize;
+    }
+    unsafe { write_relation(vec![vec![0; n]; n], out) }
+}
+
+/// 행 우선(row-major) 순서의 n×n 바이트 배열로 관계를 만드는 함수 (n은 1 이상 1024 이하)
+///
+/// # Safety
+/// cells는 len 바이트를 읽을 수 있는 유효한 포인터, out은 쓰기 가능한 유효한 주소여야 함
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dme_relation_from_matrix(
+    cells: *const u8,
+    len: usize,
+    n: usize,
+    out: *mut *mut DmeRelation,
+) -> DmeStatus {
+    if cells.is_null() || out.is_null() {
+        return DmeStatus::NullPointer;
+    }
+    if super::parse::check_size(n).is_err() || n.checked_mul(n) != Some(len) {
+        return DmeStatus::InvalidSize;
+    }
+    let cells = unsafe { std::slice::from_raw_parts(cells, len) };
+    if cells.iter().any(|&v| v > 1) {
+        return DmeStatus::InvalidValue;
+    }
+    let matrix = cells.chunks(n).map(<[u8]>::to_vec).collect();
+    unsafe { write_relation(matrix, out) }
+}
+
+/// 관계 핸들을 해제하는 함수 (NULL이면 아무것도 하지 않음)
+///
+/// # Safety
+/// relation은 NULL이거나 이 라이브러리가 만든 아직 해제되지 않은 핸들이어야 함
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dme_relation_free(relation: *mut DmeRelation) {
+    if !relation.is_null() {
+        drop(unsafe { Box::from_raw(relation) });
+    }
+}
+
+/// 관계 핸들 참조를 얻는 헬퍼 함수
+///
+/// # Safety
+/// relation은 NULL이거나 유효한 핸들이어야 함
+unsafe fn relation_ref<'a>(relation: *const DmeRelation) -> Option<&'a Matrix> {
+    unsafe { relation.as_ref() }.map(|relation| &relation.matrix)
+}
+
+/// 원소의 개수 n을 out에 쓰는 함수
+///
+/// # Safety
+/// relation은 유효한 핸들, out은 쓰기 가능한 유효한 주소여야 함
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dme_relation_size(
+    relation: *const DmeRelation,
+    out: *mut usize,
+) -> DmeStatus {
+    match unsafe { (relation_ref(relation), out.as_mut()) } {
+        (Some(matrix), Some(out)) => {
+            *out = matrix.len();
+            DmeStatus::Ok
+        }
+        _ => DmeStatus::NullPointer,
+    }
+}
+
+/// R(i, j)를 value(0 또는 1)로 바꾸는 함수
+///
+/// # Safety
+/// relation은 유효한 핸들이어야 함
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dme_relation_set(
+    relation: *mut DmeRelation,
+    i: usize,
+    j: usize,
+    value: u8,
+) -> DmeStatus {
+    let Some(relation) = (unsafe { relation.as_mut() }) else {
+        return DmeStatus::NullPointer;
+    };
+    let n = relation.matrix.len();
+    if i >= n || j >= n {
+        return DmeStatus::OutOfRange;
+    }
+    if value > 1 {
+        return DmeStatus::InvalidValue;
+    }
+    relation.matrix[i][j] = value;
+    DmeStatus::Ok
+}
+
+/// R(i, j)를 out에 쓰는 함수
+///
+/// # Safety
+/// relation은 유효한 핸들, out은 쓰기 가능한 유효한 주소여야 함
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dme_relation_get(
+    relation: *const DmeRelation,
+    i: usize,
+    j: usize,
+    out: *mut u8,
+) -> DmeStatus {
+    let (Some(matrix), Some(out)) = (unsafe { (relation_ref(relation), out.as_mut()) }) else {
+        return DmeStatus::NullPointer;
+    };
+    let n = matrix.len();
+    if i >= n || j >= n {
+        return DmeStatus::OutOfRange;
+    }
+    *out = matrix[i][j];
+    DmeStatus::Ok
+}
+
+/// 관계 행렬을 행 우선 순서로 호출자의 버퍼(길이 len = n×n)에 복사하는 함수
+///
+/// # Safety
+/// relation은 유효한 핸들, buffer는 len 바이트를 쓸 수 있는 유효한 포인터여야 함
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dme_relation_copy_matrix(
+    relation: *const DmeRelation,
+    buffer: *mut u8,
+    len: usize,
+) -> DmeStatus {
+    let Some(matrix) = (unsafe { relation_ref(relation) }) else {
+        return DmeStatus::NullPointer;
+    };
+    if buffer.is_null() {
+        return DmeStatus::NullPointer;
+    }
+    if len != matrix.len() * matrix.len() {
+        return DmeStatus::InvalidSize;
+    }
+    let buffer = unsafe { std::slice::from_raw_parts_mut(buffer, len) };
+    for (target, &value) in buffer.iter_mut().zip(matrix.iter().flatten()) {
+        *target = value;
+    }
+    DmeStatus::Ok
+}
+
+/// 속성 판별 결과를 out에 쓰는 헬퍼 함수
+///
+/// # Safety
+/// relation은 유효한 핸들, out은 쓰기 가능한 유효한 주소여야 함
+unsafe fn check_property(
+    relation: *const DmeRelation,
+    out: *mut bool,
+    property: fn(&Matrix) -> bool,
+) -> DmeStatus {
+    match unsafe { (relation_ref(relation), out.as_mut()) } {
+        (Some(matrix), Some(out)) => {
+            *out = property(matrix);
+            DmeStatus::Ok
+        }
+        _ => DmeStatus::NullPointer,
+    }
+}
+
+/// 반사성 판별 (equivalence::is_reflexive)
+///
+/// # Safety
+/// relation은 유효한 핸들, out은 쓰기 가능한 유효한 주소여야 함
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dme_is_reflexive(
+    relation: *const DmeRelation,
+    out: *mut bool,
+) -> DmeStatus {
+    unsafe { check_property(relation, out, super::equivalence::is_reflexive) }
+}
+
+/// 대칭성 판별 (equivalence::is_symmetric)
+///
+/// # Safety
+/// relation은 유효한 핸들, out은 쓰기 가능한 유효한 주소여야 함
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dme_is_symmetric(
+    relation: *const DmeRelation,
+    out: *mut bool,
+) -> DmeStatus {
+    unsafe { check_property(relation, out, super::equivalence::is_symmetric) }
+}
+
+/// 추이성 판별 (equivalence::is_transitive)
+///
+/// # Safety
+/// relation은 유효한 핸들, out은 쓰기 가능한 유효한 주소여야 함
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dme_is_transitive(
+    relation: *const DmeRelation,
+    out: *mut bool,
+) -> DmeStatus {
+    unsafe { check_property(relation, out, super::equivalence::is_transitive) }
+}
+
+/// 동치 관계 판별 (equivalence::is_equivalence_relation)
+///
+/// # Safety
+/// relation은 유효한 핸들, out은 쓰기 가능한 유효한 주소여야 함
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dme_is_equivalence_relation(
+    relation: *const DmeRelation,
+    out: *mut bool,
+) -> DmeStatus {
+    unsafe { check_property(relation, out, super::equivalence::is_equivalence_relation) }
+}
+
+/// 폐포를 계산하여 새 관계 핸들을 out에 쓰는 함수 (원래 관계는 그대로)
+/// kind는 DME_CLOSURE_REFLEXIVE, _SYMMETRIC, _TRANSITIVE, _EQUIVALENCE 중 하나
+///
+/// # Safety
+/// relation은 유효한 핸들, out은 쓰기 가능한 유효한 주소여야 함
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dme_closure(
+    relation: *const DmeRelation,
+    kind: u32,
+    out: *mut *mut DmeRelation,
+) -> DmeStatus {
+    let Some(matrix) = (unsafe { relation_ref(relation) }) else {
+        return DmeStatus::NullPointer;
+    };
+    if out.is_null() {
+        return DmeStatus::NullPointer;
+    }
+    let closed = match kind {
+        DME_CLOSURE_REFLEXIVE => super::closure::reflexive_closure(matrix),
+        DME_CLOSURE_SYMMETRIC => super::closure::symmetric_closure(matrix),
+        DME_CLOSURE_TRANSITIVE => super::closure::transitive_closure(matrix),
+        DME_CLOSURE_EQUIVALENCE => super::closure::equivalence_closure(matrix),
+        _ => return DmeStatus::InvalidClosure,
+    };
+    unsafe { write_relation(closed, out) }
+}
+
+/// 동치류 목록을 계산하여 out에 쓰는 함수 (동치 관계가 아니면 DME_STATUS_NOT_EQUIVALENCE)
+///
+/// # Safety
+/// relation은 유효한 핸들, out은 쓰기 가능한 유효한 주소여야 함
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dme_equivalence_classes(
+    relation: *const DmeRelation,
+    out: *mut *mut DmeClasses,
+) -> DmeStatus {
+    let Some(matrix) = (unsafe { relation_ref(relation) }) else {
+        return DmeStatus::NullPointer;
+    };
+    if out.is_null() {
+        return DmeStatus::NullPointer;
+    }
+    if !super::equivalence::is_equivalence_relation(matrix) {
+        return DmeStatus::NotEquivalence;
+    }
+    let classes = super::visualize::equivalence_classes(matrix);
+    unsafe { *out = Box::into_raw(Box::new(DmeClasses { classes })) };
+    DmeStatus::Ok
+}
+
+/// 동치류의 개수를 out에 쓰는 함수
+///
+/// # Safety
+/// classes는 유효한 핸들, out은 쓰기 가능한 유효한 주소여야 함
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dme_classes_count(
+    classes: *const DmeClasses,
+    out: *mut usize,
+) -> DmeStatus {
+    match unsafe { (classes.as_ref(), out.as_mut()) } {
+        (Some(classes), Some(out)) => {
+            *out = classes.classes.len();
+            DmeStatus::Ok
+        }
+        _ => DmeStatus::NullPointer,
+    }
+}
+
+/// index번째 동치류의 원소 배열과 길이를 elements/len에 쓰는 함수
+/// 배열은 classes 핸들이 해제될 때까지 유효하며 호출자가 해제하지 않음
+///
+/// # Safety
+/// classes는 유효한 핸들, elements와 len은 쓰기 가능한 유효한 주소여야 함
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dme_classes_get(
+    classes: *const DmeClasses,
+    index: usize,
+    elements: *mut *const usize,
+    len: *mut usize,
+) -> DmeStatus {
+    let (Some(classes), Some(elements), Some(len)) =
+        (unsafe { (classes.as_ref(), elements.as_mut(), len.as_mut()) })
+    else {
+        return DmeStatus::NullPointer;
+    };
+    let Some(class) = classes.classes.get(index) else {
+        return DmeStatus::OutOfRange;
+    };
+    *elements = class.as_ptr();
+    *len = class.len();
+    DmeStatus::Ok
+}
+
+/// 동치류 목록 핸들을 해제하는 함수 (NULL이면 아무것도 하지 않음)
+///
+/// # Safety
+/// classes는 NULL이거나 이 라이브러리가 만든 아직 해제되지 않은 핸들이어야 함
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dme_classes_free(classes: *mut DmeClasses) {
+    if !classes.is_null() {
+        drop(unsafe { Box::from_raw(classes) });
+    }
+}
+
+/// 상태 코드의 영어 설명 (정적 문자열이므로 해제하지 않음, 알 수 없는 코드도 허용)
+#[unsafe(no_mangle)]
+pub extern "C" fn dme_status_message(status: u32) -> *const c_char {
+    let message: &'static [u8] = match status {
+        0 => b"ok\0",
+        1 => b"null pointer argument\0",
+        2 => b"invalid matrix size\0",
+        3 => b"matrix entries must be 0 or 1\0",
+        4 => b"index out of range\0",
+        5 => b"relation is not an equivalence relation\0",
+        6 => b"unknown closure kind\0",
+        _ => b"unknown status\0",
+    };
+    message.as_ptr().cast()
+}
+
+/// C API 버전 (DME_API_VERSION)
+#[unsafe(no_mangle)]
+pub extern "C" fn dme_api_version() -> u32 {
+    DME_API_VERSION
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ffi::CStr;
+    use std::ptr::{null, null_mut};
+
+    /// 행 우선 바이트 배열로 관계 핸들을 만드는 헬퍼 함수 (성공해야 함)
+    fn relation_from(cells: &[u8], n: usize) -> *mut DmeRelation {
+        let mut relation = null_mut();
+        let status =
+            unsafe { dme_relation_from_matrix(cells.as_ptr(), cells.len(), n, &mut relation) };
+        assert_eq!(status, DmeStatus::Ok);
+        relation
+    }
+
+    /// 관계 핸들의 내용을 행 우선 바이트 배열로 복사하는 헬퍼 함수
+    fn cells_of(relation: *const DmeRelation) -> Vec<u8> {
+        let mut n = 0;
+        assert_eq!(
+            unsafe { dme_relation_size(relation, &mut n) },
+            DmeStatus::Ok
+        );
+        let mut buffer = vec![9; n * n];
+        let status = unsafe { dme_relation_copy_matrix(relation, buffer.as_mut_ptr(), n * n) };
+        assert_eq!(status, DmeStatus::Ok);
+        buffer
+    }
+
+    #[test]
+    fn new_relation_checks_size() {
+        let mut relation = null_mut();
+        unsafe {
+            assert_eq!(dme_relation_new(3, null_mut()), DmeStatus::NullPointer);
+            assert_eq!(dme_relation_new(0, &mut relation), DmeStatus::InvalidSize);
+            assert_eq!(
+                dme_relation_new(crate::parse::MAX_SIZE + 1, &mut relation),
+                DmeStatus::InvalidSize
+            );
+            assert!(relation.is_null());
+            assert_eq!(dme_relation_new(3, &mut relation), DmeStatus::Ok);
+        }
+        assert_eq!(cells_of(relation), vec![0; 9]);
+        unsafe { dme_relation_free(relation) };
+    }
+
+    #[test]
+    fn from_matrix_validates_buffer() {
+        let cells = [1u8, 0, 0, 1];
+        let mut relation = null_mut();
+        unsafe {
+            assert_eq!(
+                dme_relation_from_matrix(null(), 4, 2, &mut relation),
+                DmeStatus::NullPointer
+            );
+            assert_eq!(
+                dme_relation_from_matrix(cells.as_ptr(), 4, 2, null_mut()),
+                DmeStatus::NullPointer
+            );
+            assert_eq!(
+                dme_relation_from_matrix(cells.as_ptr(), 4, 0, &mut relation),
+                DmeStatus::InvalidSize
+            );
+            assert_eq!(
+                dme_relation_from_matrix(cells.as_ptr(), 3, 2, &mut relation),
+                DmeStatus::InvalidSize
+            );
+            // 최대 크기를 넘는 n은 배열을 읽기 전에 거절
+            let n = crate::parse::MAX_SIZE + 1;
+            assert_eq!(
+                dme_relation_from_matrix(cells.as_ptr(), n * n, n, &mut relation),
+                DmeStatus::InvalidSize
+            );
+            // n×n이 넘치는 크기
+            assert_eq!(
+                dme_relation_from_matrix(cells.as_ptr(), 4, usize::MAX, &mut relation),
+                DmeStatus::InvalidSize
+            );
+            assert_eq!(
+                dme_relation_from_matrix([1u8, 2, 0, 1].as_ptr(), 4, 2, &mut relation),
+                DmeStatus::InvalidValue
+            );
+        }
+        assert!(relation.is_null());
+        let relation = relation_from(&cells, 2);
+        assert_eq!(cells_of(relation), cells);
+        unsafe { dme_relation_free(relation) };
+    }
+
+    #[test]
+    fn get_and_set_check_range() {
+        let relation = relation_from(&[0; 4], 2);
+        let mut value = 9;
+        unsafe {
+            assert_eq!(dme_relation_set(relation, 0, 1, 1), DmeStatus::Ok);
+            assert_eq!(dme_relation_set(relation, 2, 0, 1), DmeStatus::OutOfRange);
+            assert_eq!(dme_relation_set(relation, 0, 0, 2), DmeStatus::InvalidValue);
+            assert_eq!(
+                dme_relation_set(null_mut(), 0, 0, 1),
+                DmeStatus::NullPointer
+            );
+            assert_eq!(dme_relation_get(relation, 0, 1, &mut value), DmeStatus::Ok);
+            assert_eq!(value, 1);
+            assert_eq!(
+                dme_relation_get(relation, 1, 2, &mut value),
+                DmeStatus::OutOfRange
+            );
+            assert_eq!(
+                dme_relation_get(relation, 0, 0, null_mut()),
+                DmeStatus::NullPointer
+            );
+            assert_eq!(
+                dme_relation_get(null(), 0, 0, &mut value),
+                DmeStatus::NullPointer
+            );
+            assert_eq!(
+                dme_relation_size(relation, null_mut()),
+                DmeStatus::NullPointer
+            );
+
+            let mut short = [0u8; 3];
+            assert_eq!(
+                dme_relation_copy_matrix(relation, short.as_mut_ptr(), 3),
+                DmeStatus::InvalidSize
+            );
+            assert_eq!(
+                dme_relation_copy_matrix(relation, null_mut(), 4),
+                DmeStatus::NullPointer
+            );
+        }
+        assert_eq!(cells_of(relation), vec![0, 1, 0, 0]);
+        unsafe { dme_relation_free(relation) };
+    }
+
+    #[test]
+    fn properties_are_reported() {
+        // {1, 2}와 {3}으로 나누는 동치 관계
+        let relation = relation_from(&[1, 1, 0, 1, 1, 0, 0, 0, 1], 3);
+        let checks: [unsafe extern "C" fn(*const DmeRelation, *mut bool) -> DmeStatus; 4] = [
+            dme_is_reflexive,
+            dme_is_symmetric,
+            dme_is_transitive,
+            dme_is_equivalence_relation,
+        ];
+        for check in checks {
+            let mut result = false;
+            unsafe {
+                assert_eq!(check(relation, &mut result), DmeStatus::Ok);
+                assert_eq!(check(null(), &mut result), DmeStatus::NullPointer);
+                assert_eq!(check(relation, null_mut()), DmeStatus::NullPointer);
+            }
+            assert!(result);
+        }
+        unsafe { dme_relation_free(relation) };
+    }
+
+    #[test]
+    fn closure_returns_new_handle() {
+        let relation = relation_from(&[0, 1, 0, 0, 0, 1, 0, 0, 0], 3);
+        let mut closed = null_mut();
+        unsafe {
+            assert_eq!(
+                dme_closure(relation, 7, &mut closed),
+                DmeStatus::InvalidClosure
+            );
+            assert_eq!(
+                dme_closure(null(), DME_CLOSURE_TRANSITIVE, &mut closed),
+                DmeStatus::NullPointer
+            );
+            assert_eq!(
+                dme_closure(relation, DME_CLOSURE_TRANSITIVE, null_mut()),
+                DmeStatus::NullPointer
+            );
+            assert!(closed.is_null());
+            assert_eq!(
+                dme_closure(relation, DME_CLOSURE_TRANSITIVE, &mut closed),
+                DmeStatus::Ok
+            );
+        }
+        assert_eq!(cells_of(closed), vec![0, 1, 1, 0, 0, 1, 0, 0, 0]);
+        // 원래 관계는 그대로
+        assert_eq!(cells_of(relation), vec![0, 1, 0, 0, 0, 1, 0, 0, 0]);
+        unsafe {
+            dme_relation_free(closed);
+            dme_relation_free(relation);
+        }
+    }
+
+    #[test]
+    fn classes_are_zero_based() {
+        let relation = relation_from(&[1, 0, 1, 0, 1, 0, 1, 0, 1], 3);
+        let path = relation_from(&[0, 1, 0, 0], 2);
+        let mut classes = null_mut();
+        let mut count = 0;
+        let mut elements = null();
+        let mut len = 0;
+        unsafe {
+            assert_eq!(
+                dme_equivalence_classes(path, &mut classes),
+                DmeStatus::NotEquivalence
+            );
+            assert_eq!(
+                dme_equivalence_classes(relation, null_mut()),
+                DmeStatus::NullPointer
+            );
+            assert_eq!(
+                dme_equivalence_classes(relation, &mut classes),
+                DmeStatus::Ok
+            );
+            assert_eq!(dme_classes_count(classes, &mut count), DmeStatus::Ok);
+            assert_eq!(count, 2);
+
+            let mut found = Vec::new();
+            for index in 0..count {
+                assert_eq!(
+                    dme_classes_get(classes, index, &mut elements, &mut len),
+                    DmeStatus::Ok
+                );
+                found.push(std::slice::from_raw_parts(elements, len).to_vec());
+            }
+            assert_eq!(found, vec![vec![0, 2], vec![1]]);
+            assert_eq!(
+                dme_classes_get(classes, count, &mut elements, &mut len),
+                DmeStatus::OutOfRange
+            );
+            assert_eq!(
+                dme_classes_get(classes, 0, null_mut(), &mut len),
+                DmeStatus::NullPointer
+            );
+            assert_eq!(
+                dme_classes_count(null(), &mut count),
+                DmeStatus::NullPointer
+            );
+
+            dme_classes_free(classes);
+            dme_relation_free(relation);
+            dme_relation_free(path);
+        }
+    }
+
+    #[test]
+    fn freeing_null_is_harmless() {
+        unsafe {
+            dme_relation_free(null_mut());
+            dme_classes_free(null_mut());
+        }
+    }
+
+    #[test]
+    fn status_messages_match_codes() {
+        let message = |status: u32| unsafe { CStr::from_ptr(dme_status_message(status)) };
+        assert_eq!(message(DmeStatus::Ok as u32).to_str(), Ok("ok"));
+        assert_eq!(
+            message(DmeStatus::InvalidClosure as u32).to_str(),
+            Ok("unknown closure kind")
+        );
+        assert_eq!(message(99).to_str(), Ok("unknown status"));
+        assert_eq!(dme_api_version(), DME_API_VERSION);
+    }
+}
@@ -1,5 +1,6 @@
 /// 관계 행렬의 폐포(closure) 연산을 수행하는 모듈 (반사, 대칭, 추이 폐포)
 use crate::Matrix;
+use crate::i18n::{Msg, t, tf};
 
 /// 행렬을 복사하는 헬퍼 함수 - 동치 관계 판별시 원본 행렬 보존용
 fn copy_matrix(matrix: &Matrix) -> Matrix {
@@ -7,6 +8,7 @@ fn copy_matrix(matrix: &Matrix) -> Matrix {
 }
 
 /// 반사 폐포 계산 - 관계가 반사성을 만족하도록 모든 대각선 요소를 1로 설정
+#[allow(clippy::needless_range_loop)]
 pub fn reflexive_closure(matrix: &Matrix) -> Matrix {
     let mut result = copy_matrix(matrix);
     let n = result.len();
@@ -19,6 +21,7 @@ pub fn reflexive_closure(matrix: &Matrix) -> Matrix {
 }
 
 /// 대칭 폐포 계산 - 관계가 대칭성을 만족하도록 R(i,j)=1이면 R(j,i)=1로 설정
+#[allow(clippy::needless_range_loop)]
 pub fn symmetric_closure(matrix: &Matrix) -> Matrix {
     let mut result = copy_matrix(matrix);
     let n = result.len();
@@ -53,98 +56,434 @@ pub fn transitive_closure(matrix: &Matrix) -> Matrix {
     result
 }
 
+/// 동치 폐포 계산 - 반사, 대칭, 추이 폐포를 차례로 적용하여 R을 포함하는 가장 작은 동치 관계를 구함
+pub fn equivalence_closure(matrix: &Matrix) -> Matrix {
+    transitive_closure(&symmetric_closure(&reflexive_closure(matrix)))
+}
+
+/// 폐포가 추가한 쌍 하나의 가장 짧은 근거
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Justification {
+    /// 반사성에 의해 추가된 대각선 쌍 (i, i)
+    Reflexive,
+    /// 원래 관계에 있는 쌍 (j, i)의 대칭으로 추가된 쌍 (i, j)
+    SymmetricOf(usize, usize),
+    /// 원래 관계의 쌍들로 이어진 가장 짧은 경로 i → ... → k
+    Path(Vec<usize>),
+    /// 방향을 무시한 가장 짧은 경로 i ~ ... ~ k (각 단계는 원래 관계의 쌍 또는 그 역)
+    UndirectedPath(Vec<usize>),
+}
+
+/// 근거가 기록된 폐포 계산 결과
+/// added는 폐포가 추가한 쌍들과 각 쌍의 근거 (행 우선 순서)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClosureTrace {
+    pub matrix: Matrix,
+    pub added: Vec<((usize, usize), Justification)>,
+}
+
+impl ClosureTrace {
+    /// 쌍 (i, j)가 폐포에 의해 추가되었다면 그 근거를 반환
+    pub fn justification(&self, i: usize, j: usize) -> Option<&Justification> {
+        self.added
+            .iter()
+            .find(|(pair, _)| *pair == (i, j))
+            .map(|(_, justification)| justification)
+    }
+}
+
+/// 원래 관계와 폐포를 비교하여 추가된 쌍마다 근거를 붙이는 헬퍼 함수
+fn trace_added(
+    matrix: &Matrix,
+    closed: Matrix,
+    justify: impl Fn(usize, usize) -> Justification,
+) -> ClosureTrace {
+    let n = matrix.len();
+    let mut added = Vec::new();
+    for i in 0..n {
+        for j in 0..n {
+            if matrix[i][j] == 0 && closed[i][j] == 1 {
+                added.push(((i, j), justify(i, j)));
+            }
+        }
+    }
+    ClosureTrace {
+        matrix: closed,
+        added,
+    }
+}
+
+/// 시작 원소에서 한 단계 이상 이동하여 각 원소에 도달하는 가장 짧은 경로를 구하는 헬퍼 함수
+/// undirected가 참이면 관계의 방향을 무시하며, 도달할 수 없는 원소는 None
+fn shortest_paths(matrix: &Matrix, start: usize, undirected: bool) -> Vec<Option<Vec<usize>>> {
+    let n = matrix.len();
+    let step = |u: usize, v: usize| matrix[u][v] == 1 || (undirected && matrix[v][u] == 1);
+    let mut parent: Vec<Option<usize>> = vec![None; n];
+    let mut reached = vec![false; n];
+    let mut queue = std::collections::VecDeque::new();
+
+    // 시작 원소 자신으로 돌아오는 경로도 찾을 수 있도록 첫 이웃부터 탐색
+    for v in 0..n {
+        if step(start, v) {
+            reached[v] = true;
+            parent[v] = Some(start);
+            queue.push_back(v);
+        }
+    }
+    while let Some(u) = queue.pop_front() {
+        for v in 0..n {
+            if !reached[v] && step(u, v) {
+                reached[v] = true;
+                parent[v] = Some(u);
+                queue.push_back(v);
+            }
+        }
+    }
+
+    (0..n)
+        .map(|target| {
+            if !reached[target] {
+                return None;
+            }
+            let mut path = vec![target];
+            let mut current = target;
+            while let Some(p) = parent[current] {
+                path.push(p);
+                if p == start {
+                    break;
+                }
+                current = p;
+            }
+            path.reverse();
+            Some(path)
+        })
+        .collect()
+}
+
+/// 근거를 기록하는 반사 폐포 - 추가된 대각선 쌍마다 Reflexive
+pub fn reflexive_closure_trace(matrix: &Matrix) -> ClosureTrace {
+    trace_added(matrix, reflexive_closure(matrix), |_, _| {
+        Justification::Reflexive
+    })
+}
+
+/// 근거를 기록하는 대칭 폐포 - 추가된 (i, j)마다 원래 쌍 (j, i)
+pub fn symmetric_closure_trace(matrix: &Matrix) -> ClosureTrace {
+    trace_added(matrix, symmetric_closure(matrix), |i, j| {
+        Justification::SymmetricOf(j, i)
+    })
+}
+
+/// 근거를 기록하는 추이 폐포 - 추가된 (i, k)마다 원래 관계에서의 가장 짧은 경로
+pub fn transitive_closure_trace(matrix: &Matrix) -> ClosureTrace {
+    let paths: Vec<Vec<Option<Vec<usize>>>> = (0..matrix.len())
+        .map(|i| shortest_paths(matrix, i, false))
+        .collect();
+    trace_added(matrix, transitive_closure(matrix), |i, k| {
+        Justification::Path(paths[i][k].clone().unwrap_or_default())
+    })
+}
+
+/// 근거를 기록하는 동치 폐포 - 추가된 쌍마다 가장 짧은 근거를 선택
+/// 대각선은 반사성, 역방향 쌍이 있으면 대칭성, 같은 길이의 방향 경로가 있으면 방향 경로,
+/// 그 외에는 방향을 무시한 경로를 근거로 사용
+pub fn equivalence_closure_trace(matrix: &Matrix) -> ClosureTrace {
+    let n = matrix.len();
+    let directed: Vec<Vec<Option<Vec<usize>>>> =
+        (0..n).map(|i| shortest_paths(matrix, i, false)).collect();
+    let undirected: Vec<Vec<Option<Vec<usize>>>> =
+        (0..n).map(|i| shortest_paths(matrix, i, true)).collect();
+    trace_added(matrix, equivalence_closure(matrix), |i, k| {
+        if i == k {
+            return Justification::Reflexive;
+        }
+        if matrix[k][i] == 1 {
+            return Justification::SymmetricOf(k, i);
+        }
+        let path = undirected[i][k].clone().unwrap_or_default();
+        match &directed[i][k] {
+            Some(forward) if forward.len() == path.len() => Justification::Path(forward.clone()),
+            _ => Justification::UndirectedPath(path),
+        }
+    })
+}
+
+/// 근거를 설명 문자열로 변환하는 함수 (1부터 시작하는 번호 사용)
+pub fn describe_justification(justification: &Justification) -> String {
+    let join = |path: &[usize], separator: &str| -> String {
+        path.iter()
+            .map(|&x| (x + 1).to_string())
+            .collect::<Vec<String>>()
+            .join(separator)
+    };
+    match justification {
+        Justification::Reflexive => t(Msg::JustifyReflexive).to_string(),
+        Justification::SymmetricOf(j, i) => tf(Msg::JustifySymmetric, &[&(j + 1), &(i + 1)]),
+        Justification::Path(path) => tf(Msg::JustifyPath, &[&join(path, " → ")]),
+        Justification::UndirectedPath(path) => {
+            tf(Msg::JustifyUndirectedPath, &[&join(path, " ~ ")])
+        }
+    }
+}
+
+/// 폐포가 추가한 쌍과 근거를 표 형태로 출력하는 함수
+pub fn print_justification_table(trace: &ClosureTrace) {
+    if trace.added.is_empty() {
+        return;
+    }
+    println!("{}", t(Msg::JustificationHeader));
+    println!(
+        "  {:<10} | {}",
+        t(Msg::JustificationPair),
+        t(Msg::JustificationReason)
+    );
+    for ((i, j), justification) in &trace.added {
+        println!(
+            "  {:<10} | {}",
+            format!("({}, {})", i + 1, j + 1),
+            describe_justification(justification)
+        );
+    }
+}
+
+/// 단일 폐포(반사/대칭/추이)의 적용 전후와 근거를 출력하는 헬퍼 함수
+/// property: 폐포가 만들어 주는 속성 이름, closure: 폐포 이름
+fn print_single_closure(matrix: &Matrix, trace: &ClosureTrace, property: Msg, closure: Msg) {
+    let closed = &trace.matrix;
+    let changed = closed != matrix;
+
+    super::terminal::print_closure_diff(
+        matrix,
+        closed,
+        (
+            t(Msg::MatrixBefore),
+            &tf(Msg::MatrixAfterClosure, &[&t(closure)]),
+        ),
+    );
+
+    if changed {
+        println!("{}", tf(Msg::ClosureChanged, &[&t(closure)]));
+    } else {
+        println!("{}", tf(Msg::ClosureUnchanged, &[&t(property)]));
+    }
+
+    print_justification_table(trace);
+
+    println!("\n{}", tf(Msg::CheckAfterClosure, &[&t(closure)]));
+    super::equivalence::print_equivalence_result(closed);
+}
+
 /// 폐포 종합 분석 - 세 가지 폐포(반사/대칭/추이)를 적용하고 그 효과를 분석하여 출력
 pub fn perform_closure_analysis(matrix: &Matrix) {
-    println!("\n=== 폐포 분석 ===");
+    println!("\n=== {} ===", t(Msg::ClosureAnalysisTitle));
 
     let is_already_equivalence = super::equivalence::is_equivalence_relation(matrix);
 
     if is_already_equivalence {
-        println!("이 관계는 이미 완벽한 동치 관계입니다.");
-        println!("하지만 교육적 목적으로 각 폐포의 효과를 시연합니다.\n");
+        println!("{}", t(Msg::AlreadyEquivalence));
+        println!("{}\n", t(Msg::DemonstrateClosures));
     }
 
     // 반사 폐포
-    println!("--- 반사 폐포 ---");
+    println!("{}", t(Msg::ReflexiveClosureSection));
     if super::equivalence::is_reflexive(matrix) {
-        println!("반사성이 이미 만족되어 반사 폐포를 건너뜁니다.");
+        println!(
+            "{}",
+            tf(
+                Msg::ClosureSkipped,
+                &[&t(Msg::Reflexivity), &t(Msg::ReflexiveClosure)]
+            )
+        );
     } else {
-        let reflexive_closed = reflexive_closure(matrix);
-        let changed_reflexive = reflexive_closed != *matrix;
-
-        super::visualize::print_matrix(matrix, "변환 전 행렬");
-        super::visualize::print_matrix(&reflexive_closed, "반사 폐포 후 행렬");
-
-        if changed_reflexive {
-            println!("변화: 반사 폐포에 의해 행렬이 변경되었습니다.");
-        } else {
-            println!("변화: 이미 반사성이 만족되어 변경되지 않았습니다.");
-        }
-
-        println!("\n반사 폐포 후 동치 관계 판별:");
-        super::equivalence::print_equivalence_result(&reflexive_closed);
+        print_single_closure(
+            matrix,
+            &reflexive_closure_trace(matrix),
+            Msg::Reflexivity,
+            Msg::ReflexiveClosure,
+        );
     }
 
     // 대칭 폐포
-    println!("\n--- 대칭 폐포 ---");
+    println!("\n{}", t(Msg::SymmetricClosureSection));
     if super::equivalence::is_symmetric(matrix) {
-        println!("대칭성이 이미 만족되어 대칭 폐포를 건너뜁니다.");
+        println!(
+            "{}",
+            tf(
+                Msg::ClosureSkipped,
+                &[&t(Msg::Symmetry), &t(Msg::SymmetricClosure)]
+            )
+        );
     } else {
-        let symmetric_closed = symmetric_closure(matrix);
-        let changed_symmetric = symmetric_closed != *matrix;
-
-        super::visualize::print_matrix(matrix, "변환 전 행렬");
-        super::visualize::print_matrix(&symmetric_closed, "대칭 폐포 후 행렬");
-
-        if changed_symmetric {
-            println!("변화: 대칭 폐포에 의해 행렬이 변경되었습니다.");
-        } else {
-            println!("변화: 이미 대칭성이 만족되어 변경되지 않았습니다.");
-        }
-
-        println!("\n대칭 폐포 후 동치 관계 판별:");
-        super::equivalence::print_equivalence_result(&symmetric_closed);
+        print_single_closure(
+            matrix,
+            &symmetric_closure_trace(matrix),
+            Msg::Symmetry,
+            Msg::SymmetricClosure,
+        );
     }
 
     // 추이 폐포
-    println!("\n--- 추이 폐포 ---");
+    println!("\n{}", t(Msg::TransitiveClosureSection));
     if super::equivalence::is_transitive(matrix) {
-        println!("추이성이 이미 만족되어 추이 폐포를 건너뜁니다.");
+        println!(
+            "{}",
+            tf(
+                Msg::ClosureSkipped,
+                &[&t(Msg::Transitivity), &t(Msg::TransitiveClosure)]
+            )
+        );
     } else {
-        let transitive_closed = transitive_closure(matrix);
-        let changed_transitive = transitive_closed != *matrix;
-
-        super::visualize::print_matrix(matrix, "변환 전 행렬");
-        super::visualize::print_matrix(&transitive_closed, "추이 폐포 후 행렬");
-
-        if changed_transitive {
-            println!("변화: 추이 폐포에 의해 행렬이 변경되었습니다.");
-        } else {
-            println!("변화: 이미 추이성이 만족되어 변경되지 않았습니다.");
-        }
-
-        println!("\n추이 폐포 후 동치 관계 판별:");
-        super::equivalence::print_equivalence_result(&transitive_closed);
+        print_single_closure(
+            matrix,
+            &transitive_closure_trace(matrix),
+            Msg::Transitivity,
+            Msg::TransitiveClosure,
+        );
     }
 
     // 모든 폐포를 한 번에 적용한 경우
-    let all_closed = transitive_closure(&symmetric_closure(&reflexive_closure(matrix)));
+    let all_trace = equivalence_closure_trace(matrix);
+    let all_closed = all_trace.matrix.clone();
     let changed_all = all_closed != *matrix;
 
-    println!("\n--- 모든 폐포 적용 (반사 + 대칭 + 추이) ---");
+    println!("\n{}", t(Msg::AllClosuresSection));
     if !is_already_equivalence || changed_all {
-        super::visualize::print_matrix(matrix, "원본 행렬");
-        super::visualize::print_matrix(&all_closed, "모든 폐포 적용 후 행렬");
+        super::terminal::print_closure_diff(
+            matrix,
+            &all_closed,
+            (t(Msg::OriginalMatrix), t(Msg::MatrixAfterAllClosures)),
+        );
 
         if changed_all {
-            println!("변화: 폐포 적용에 의해 행렬이 변경되었습니다.");
+            println!("{}", t(Msg::AllClosuresChanged));
         } else {
-            println!("변화: 이미 완벽한 동치 관계여서 변경되지 않았습니다.");
+            println!("{}", t(Msg::AllClosuresUnchanged));
         }
 
-        println!("\n모든 폐포 적용 후 동치 관계 판별:");
+        print_justification_table(&all_trace);
+
+        println!("\n{}", t(Msg::CheckAfterAllClosures));
         super::equivalence::print_equivalence_result(&all_closed);
     } else {
-        println!("원본 행렬이 이미 완벽한 동치 관계이므로 모든 폐포 적용을 건너뜁니다.");
+        println!("{}", t(Msg::AllClosuresSkipped));
+    }
+
+    // 쌍의 제거까지 허용했을 때 가장 가까운 동치 관계
+    if !is_already_equivalence {
+        super::nearest::print_nearest_equivalence(matrix);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::order::{SimpleRng, random_relation};
+
+    /// i에서 k로 가는 가장 짧은 경로의 간선 수를 관계의 거듭제곱으로 구하는 헬퍼 함수
+    fn distance(matrix: &Matrix, i: usize, k: usize) -> Option<usize> {
+        let n = matrix.len();
+        let mut frontier = matrix[i].clone();
+        for steps in 1..=n {
+            if frontier[k] == 1 {
+                return Some(steps);
+            }
+            frontier = (0..n)
+                .map(|v| u8::from((0..n).any(|u| frontier[u] == 1 && matrix[u][v] == 1)))
+                .collect();
+        }
+        None
+    }
+
+    #[test]
+    fn traces_match_plain_closures() {
+        for seed in 0..20 {
+            let matrix = random_relation(&mut SimpleRng::new(seed), 6, 4);
+            assert_eq!(
+                reflexive_closure_trace(&matrix).matrix,
+                reflexive_closure(&matrix)
+            );
+            assert_eq!(
+                symmetric_closure_trace(&matrix).matrix,
+                symmetric_closure(&matrix)
+            );
+            assert_eq!(
+                transitive_closure_trace(&matrix).matrix,
+                transitive_closure(&matrix)
+            );
+            assert_eq!(
+                equivalence_closure_trace(&matrix).matrix,
+                equivalence_closure(&matrix)
+            );
+        }
+    }
+
+    #[test]
+    fn transitive_justifications_are_shortest_paths() {
+        for seed in 0..20 {
+            let matrix = random_relation(&mut SimpleRng::new(seed), 6, 4);
+            let trace = transitive_closure_trace(&matrix);
+            for ((i, k), justification) in &trace.added {
+                let Justification::Path(path) = justification else {
+                    panic!("추이 폐포의 근거는 방향 경로: {justification:?}");
+                };
+                assert_eq!((path[0], *path.last().unwrap()), (*i, *k));
+                assert!(path.windows(2).all(|w| matrix[w[0]][w[1]] == 1));
+                assert_eq!(Some(path.len() - 1), distance(&matrix, *i, *k));
+            }
+        }
+    }
+
+    #[test]
+    fn equivalence_justifications_are_valid() {
+        for seed in 0..20 {
+            let matrix = random_relation(&mut SimpleRng::new(seed), 6, 4);
+            let trace = equivalence_closure_trace(&matrix);
+            for ((i, k), justification) in &trace.added {
+                match justification {
+                    Justification::Reflexive => assert_eq!(i, k),
+                    Justification::SymmetricOf(a, b) => {
+                        assert_eq!((a, b), (k, i));
+                        assert_eq!(matrix[*a][*b], 1);
+                    }
+                    Justification::Path(path) => {
+                        assert!(path.windows(2).all(|w| matrix[w[0]][w[1]] == 1));
+                    }
+                    Justification::UndirectedPath(path) => {
+                        assert_eq!((path[0], *path.last().unwrap()), (*i, *k));
+                        assert!(
+                            path.windows(2)
+                                .all(|w| matrix[w[0]][w[1]] == 1 || matrix[w[1]][w[0]] == 1)
+                        );
+                    }
+                }
+                assert_eq!(trace.justification(*i, *k), Some(justification));
+            }
+        }
+    }
+
+    #[test]
+    fn justification_lookup_and_description() {
+        // 1 → 2 → 3
+        let matrix = vec![vec![0, 1, 0], vec![0, 0, 1], vec![0, 0, 0]];
+        let trace = transitive_closure_trace(&matrix);
+        assert_eq!(
+            trace.added,
+            vec![((0, 2), Justification::Path(vec![0, 1, 2]))]
+        );
+        assert_eq!(trace.justification(0, 1), None);
+        let text = describe_justification(&Justification::Path(vec![0, 1, 2]));
+        assert!(text.contains("1 → 2 → 3"), "{text}");
+        let text = describe_justification(&Justification::UndirectedPath(vec![2, 0]));
+        assert!(text.contains("3 ~ 1"), "{text}");
+
+        let trace = equivalence_closure_trace(&matrix);
+        assert_eq!(
+            trace.justification(1, 0),
+            Some(&Justification::SymmetricOf(0, 1))
+        );
+        assert_eq!(
+            trace.justification(2, 0),
+            Some(&Justification::UndirectedPath(vec![2, 1, 0]))
+        );
     }
 }
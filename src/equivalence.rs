@@ -1,8 +1,10 @@
 /// 동치 관계의 속성 판별 및 동치류 계산을 수행하는 모듈
 use crate::Matrix;
+use crate::i18n::{Msg, mark, t, tf};
 
 /// 관계가 반사성(reflexive)을 만족하는지 판별하는 함수
 /// 모든 i에 대해 R(i,i) = 1인지 확인
+#[allow(clippy::needless_range_loop)]
 pub fn is_reflexive(matrix: &Matrix) -> bool {
     let n = matrix.len();
     for i in 0..n {
@@ -15,6 +17,7 @@ pub fn is_reflexive(matrix: &Matrix) -> bool {
 
 /// 관계가 대칭성(symmetric)을 만족하는지 판별하는 함수
 /// 모든 i,j에 대해 R(i,j) = R(j,i)인지 확인
+#[allow(clippy::needless_range_loop)]
 pub fn is_symmetric(matrix: &Matrix) -> bool {
     let n = matrix.len();
     for i in 0..n {
@@ -49,8 +52,111 @@ pub fn is_equivalence_relation(matrix: &Matrix) -> bool {
     is_reflexive(matrix) && is_symmetric(matrix) && is_transitive(matrix)
 }
 
+/// 함수 f: A → B의 핵(kernel) 동치 관계를 반환하는 함수
+/// values[a] = f(a) 로 주어진 함수에 대해 a ~ b ⇔ f(a) = f(b)
+pub fn kernel_relation<T: PartialEq>(values: &[T]) -> Matrix {
+    let n = values.len();
+    let mut matrix = vec![vec![0; n]; n];
+    for a in 0..n {
+        for b in 0..n {
+            if values[a] == values[b] {
+                matrix[a][b] = 1;
+            }
+        }
+    }
+    matrix
+}
+
+/// 클로저로 주어진 함수 f: {0, ..., n-1} → B의 핵 동치 관계를 반환하는 함수
+pub fn kernel_relation_fn<T: PartialEq>(n: usize, f: impl Fn(usize) -> T) -> Matrix {
+    let values: Vec<T> = (0..n).map(f).collect();
+    kernel_relation(&values)
+}
+
+/// 함수의 표준 분해 A → A/ker f → im f → B
+/// classes: 핵이 유도하는 분할 A/ker f
+/// projection: 표준 사영 π(a) = a가 속한 동치류의 번호
+/// image: 전단사 함수 [a] ↦ f(a), 즉 image[k]는 k번째 동치류의 함숫값 (im f ⊆ B로의 포함 사상은 그대로)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Factorization<T> {
+    pub classes: Vec<Vec<usize>>,
+    pub projection: Vec<usize>,
+    pub image: Vec<T>,
+}
+
+/// values[a] = f(a) 로 주어진 함수의 표준 분해를 계산하는 함수
+/// 동치류는 가장 작은 원소의 순서로 번호를 매김
+pub fn canonical_factorization<T: PartialEq + Clone>(values: &[T]) -> Factorization<T> {
+    let mut classes: Vec<Vec<usize>> = Vec::new();
+    let mut projection = Vec::with_capacity(values.len());
+    let mut image: Vec<T> = Vec::new();
+    for (a, value) in values.iter().enumerate() {
+        match image.iter().position(|v| v == value) {
+            Some(k) => {
+                classes[k].push(a);
+                projection.push(k);
+            }
+            None => {
+                classes.push(vec![a]);
+                projection.push(image.len());
+                image.push(value.clone());
+            }
+        }
+    }
+    Factorization {
+        classes,
+        projection,
+        image,
+    }
+}
+
+/// 함수의 핵이 유도하는 분할과 표준 분해를 출력하는 함수
+/// describe는 함숫값 f(a)를 출력용 문자열로 바꾸는 함수
+pub fn print_kernel_factorization<T: PartialEq + Clone>(
+    values: &[T],
+    describe: impl Fn(&T) -> String,
+) {
+    let factorization = canonical_factorization(values);
+    let kernel = kernel_relation(values);
+
+    super::visualize::print_matrix(&kernel, t(Msg::KernelMatrixTitle));
+    println!(
+        "{}",
+        tf(
+            Msg::KernelIsEquivalence,
+            &[&mark(is_equivalence_relation(&kernel))]
+        )
+    );
+
+    println!("\n{}", t(Msg::CanonicalFactorization));
+    for (k, class) in factorization.classes.iter().enumerate() {
+        println!(
+            "  {} ↦ [{}] ↦ {}",
+            super::order::format_set(class),
+            class[0] + 1,
+            describe(&factorization.image[k])
+        );
+    }
+    println!(
+        "|A| = {}, |A/ker f| = |im f| = {}",
+        values.len(),
+        factorization.classes.len()
+    );
+}
+
+/// 각 원소를 그 원소의 후속자 집합 f(a) = {b | R(a,b)}로 보내는 함수의 핵을 출력하는 함수
+/// 같은 행을 갖는 원소들이 한 동치류로 묶임
+pub fn print_row_kernel(matrix: &Matrix) {
+    println!("\n=== {} ===", t(Msg::RowKernelTitle));
+    print_kernel_factorization(matrix, |row| {
+        let successors: Vec<usize> = (0..row.len()).filter(|&b| row[b] == 1).collect();
+        super::order::format_set(&successors)
+    });
+}
+
 /// 관계가 반대칭성(antisymmetric)을 만족하는지 판별하는 함수
 /// 모든 i ≠ j에 대해, R(i,j)와 R(j,i)가 동시에 성립하지 않아야 함
+#[allow(clippy::needless_range_loop)]
 pub fn is_antisymmetric(matrix: &Matrix) -> bool {
     let n = matrix.len();
     for i in 0..n {
@@ -63,8 +169,15 @@ pub fn is_antisymmetric(matrix: &Matrix) -> bool {
     true
 }
 
+/// 관계가 부분 순서(partial order)인지 판별하는 함수
+/// 반대칭성과 추이성을 만족하는지 확인 (대각선은 순서 해석시 항상 포함된 것으로 간주)
+pub fn is_partial_order(matrix: &Matrix) -> bool {
+    is_antisymmetric(matrix) && is_transitive(matrix)
+}
+
 /// 관계가 비반사성(irreflexive)을 만족하는지 판별하는 함수
 /// 모든 i에 대해, R(i,i)가 성립하지 않아야 함
+#[allow(clippy::needless_range_loop)]
 pub fn is_irreflexive(matrix: &Matrix) -> bool {
     let n = matrix.len();
     for i in 0..n {
@@ -77,6 +190,7 @@ pub fn is_irreflexive(matrix: &Matrix) -> bool {
 
 /// 관계가 연결성(connectedness)을 만족하는지 판별하는 함수
 /// 모든 서로 다른 i, j에 대해 R(i,j) 또는 R(j,i) 중 하나는 참이어야 함
+#[allow(clippy::needless_range_loop)]
 pub fn is_connected_relation(matrix: &Matrix) -> bool {
     let n = matrix.len();
     for i in 0..n {
@@ -89,72 +203,261 @@ pub fn is_connected_relation(matrix: &Matrix) -> bool {
     true
 }
 
+/// 두 원소의 만남(meet, a ∧ b)을 반환하는 함수 - 하한이 존재하지 않으면 None
+pub fn meet(matrix: &Matrix, a: usize, b: usize) -> Option<usize> {
+    super::order::infimum(matrix, &[a, b])
+}
+
+/// 두 원소의 이음(join, a ∨ b)을 반환하는 함수 - 상한이 존재하지 않으면 None
+pub fn join(matrix: &Matrix, a: usize, b: usize) -> Option<usize> {
+    super::order::supremum(matrix, &[a, b])
+}
+
+/// 관계가 격자(lattice)인지 판별하는 함수
+/// 부분 순서이면서 모든 두 원소 쌍이 만남과 이음을 가져야 함
+pub fn is_lattice(matrix: &Matrix) -> bool {
+    if !is_partial_order(matrix) {
+        return false;
+    }
+    let n = matrix.len();
+    for a in 0..n {
+        for b in (a + 1)..n {
+            if meet(matrix, a, b).is_none() || join(matrix, a, b).is_none() {
+                return false;
+            }
+        }
+    }
+    true
+}
+
+/// 격자의 만남/이음 연산표 - meet[a][b] = a ∧ b, join[a][b] = a ∨ b
+/// lattice_tables로 한 번 만들어 두고 아래의 격자 법칙 판별 함수들이 함께 사용
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LatticeTables {
+    pub meet: Vec<Vec<usize>>,
+    pub join: Vec<Vec<usize>>,
+}
+
+impl LatticeTables {
+    /// a ≤ b 인지 판별하는 함수 (a ∧ b = a)
+    pub fn leq(&self, a: usize, b: usize) -> bool {
+        self.meet[a][b] == a
+    }
+
+    /// 최소 원소 0 (모든 원소의 만남, 원소가 없으면 None)
+    pub fn bottom(&self) -> Option<usize> {
+        (0..self.meet.len()).reduce(|x, y| self.meet[x][y])
+    }
+
+    /// 최대 원소 1 (모든 원소의 이음, 원소가 없으면 None)
+    pub fn top(&self) -> Option<usize> {
+        (0..self.join.len()).reduce(|x, y| self.join[x][y])
+    }
+
+    /// 유계 격자인지 여부 (원소가 하나 이상인 유한 격자는 항상 유계)
+    pub fn is_bounded(&self) -> bool {
+        self.bottom().is_some() && self.top().is_some()
+    }
+}
+
+/// 관계가 격자이면 만남/이음 연산표를 함께 만들어 반환하는 함수 (격자가 아니면 None)
+/// 부분 순서인지는 한 번만 확인하고, 만남이나 이음이 없는 쌍이 나오면 바로 None
+pub fn lattice_tables(matrix: &Matrix) -> Option<LatticeTables> {
+    if !is_partial_order(matrix) {
+        return None;
+    }
+    let n = matrix.len();
+    let table = |op: fn(&Matrix, usize, usize) -> Option<usize>| -> Option<Vec<Vec<usize>>> {
+        (0..n)
+            .map(|a| (0..n).map(|b| op(matrix, a, b)).collect())
+            .collect()
+    };
+    Some(LatticeTables {
+        meet: table(meet)?,
+        join: table(join)?,
+    })
+}
+
+/// 격자가 유계(bounded)인지 판별하는 함수
+/// 최소 원소(0)와 최대 원소(1)가 모두 존재해야 함 (유한 격자는 항상 유계)
+pub fn is_bounded_lattice(matrix: &Matrix) -> bool {
+    is_lattice(matrix)
+        && super::order::least_element(matrix).is_some()
+        && super::order::greatest_element(matrix).is_some()
+}
+
+/// 분배 법칙 a ∧ (b ∨ c) = (a ∧ b) ∨ (a ∧ c)의 반례 (a, b, c)를 찾는 함수
+/// 분배 법칙이 모두 성립하면 None
+pub fn distributivity_counterexample(tables: &LatticeTables) -> Option<(usize, usize, usize)> {
+    let (m, j) = (&tables.meet, &tables.join);
+    let n = m.len();
+    for a in 0..n {
+        for b in 0..n {
+            for c in 0..n {
+                if m[a][j[b][c]] != j[m[a][b]][m[a][c]] {
+                    return Some((a, b, c));
+                }
+            }
+        }
+    }
+    None
+}
+
+/// 격자가 분배 격자(distributive lattice)인지 판별하는 함수
+pub fn is_distributive_lattice(tables: &LatticeTables) -> bool {
+    distributivity_counterexample(tables).is_none()
+}
+
+/// 모듈러 법칙 a ≤ b → a ∨ (x ∧ b) = (a ∨ x) ∧ b의 반례 (a, x, b)를 찾는 함수
+/// 모듈러 법칙이 모두 성립하면 None
+pub fn modularity_counterexample(tables: &LatticeTables) -> Option<(usize, usize, usize)> {
+    let (m, j) = (&tables.meet, &tables.join);
+    let n = m.len();
+    for a in 0..n {
+        for b in 0..n {
+            if !tables.leq(a, b) {
+                continue;
+            }
+            for x in 0..n {
+                if j[a][m[x][b]] != m[j[a][x]][b] {
+                    return Some((a, x, b));
+                }
+            }
+        }
+    }
+    None
+}
+
+/// 격자가 모듈러 격자(modular lattice)인지 판별하는 함수
+pub fn is_modular_lattice(tables: &LatticeTables) -> bool {
+    modularity_counterexample(tables).is_none()
+}
+
+/// 격자에서 원소 a의 여원(complement)들을 반환하는 함수
+/// a ∧ b = 0 이고 a ∨ b = 1 인 원소 b들
+pub fn complements(tables: &LatticeTables, a: usize) -> Vec<usize> {
+    let (bottom, top) = (tables.bottom(), tables.top());
+    (0..tables.meet.len())
+        .filter(|&b| Some(tables.meet[a][b]) == bottom && Some(tables.join[a][b]) == top)
+        .collect()
+}
+
+/// 격자가 여원 격자(complemented lattice)인지 판별하는 함수
+/// 모든 원소가 적어도 하나의 여원을 가져야 함 (원소가 없으면 거짓)
+pub fn is_complemented_lattice(tables: &LatticeTables) -> bool {
+    let n = tables.meet.len();
+    n > 0 && (0..n).all(|a| !complements(tables, a).is_empty())
+}
+
+/// 격자 판별 결과와 만남/이음 연산표, 분배/모듈러 법칙의 반례를 출력하는 함수
+pub fn print_lattice_analysis(matrix: &Matrix) {
+    println!("\n=== {} ===", t(Msg::LatticeTitle));
+
+    let Some(tables) = lattice_tables(matrix) else {
+        println!("{}", t(Msg::NotLattice));
+        return;
+    };
+
+    println!("{}", t(Msg::LatticeSatisfied));
+    println!("{}: {}", t(Msg::BoundedLattice), mark(tables.is_bounded()));
+    let distributive = distributivity_counterexample(&tables);
+    println!(
+        "{}: {}",
+        t(Msg::DistributiveLattice),
+        mark(distributive.is_none())
+    );
+    if let Some((a, b, c)) = distributive {
+        println!(
+            "{}",
+            tf(
+                Msg::DistributiveCounterexample,
+                &[&(a + 1), &(b + 1), &(c + 1)]
+            )
+        );
+    }
+    let modular = modularity_counterexample(&tables);
+    println!("{}: {}", t(Msg::ModularLattice), mark(modular.is_none()));
+    if let Some((a, x, b)) = modular {
+        println!(
+            "{}",
+            tf(Msg::ModularCounterexample, &[&(a + 1), &(b + 1), &(x + 1)])
+        );
+    }
+    println!(
+        "{}: {}",
+        t(Msg::ComplementedLattice),
+        mark(is_complemented_lattice(&tables))
+    );
+
+    print_operation_table(&tables.meet, "∧");
+    print_operation_table(&tables.join, "∨");
+}
+
+/// 격자 연산표를 행/열 머리글과 함께 출력하는 헬퍼 함수 (1부터 시작하는 번호 사용)
+fn print_operation_table(table: &[Vec<usize>], symbol: &str) {
+    println!("\n{}", tf(Msg::OperationTable, &[&symbol]));
+    print!(" {} |", symbol);
+    for b in 0..table.len() {
+        print!(" {:>2}", b + 1);
+    }
+    println!();
+    for (a, row) in table.iter().enumerate() {
+        print!("{:>2} |", a + 1);
+        for &value in row {
+            print!(" {:>2}", value + 1);
+        }
+        println!();
+    }
+}
+
 /// 반대칭성, 비반사성, 연결성의 판별 결과를 출력하는 함수
-/// 각 속성의 만족 여부를 한글로 표시
+/// 각 속성의 만족 여부를 선택된 언어로 표시
 pub fn print_additional_properties(matrix: &Matrix) {
-    println!("\n=== 추가 속성 판별 ===");
-    println!(
-        "반대칭성 (Antisymmetric): {}",
-        if is_antisymmetric(matrix) {
-            "만족"
+    let plain = |ok: bool| {
+        t(if ok {
+            Msg::SatisfiedPlain
         } else {
-            "불만족"
-        }
+            Msg::NotSatisfiedPlain
+        })
+    };
+    println!("\n=== {} ===", t(Msg::AdditionalPropertiesTitle));
+    println!(
+        "{}: {}",
+        t(Msg::AntisymmetricLabel),
+        plain(is_antisymmetric(matrix))
     );
     println!(
-        "비반사성 (Irreflexive): {}",
-        if is_irreflexive(matrix) {
-            "만족"
-        } else {
-            "불만족"
-        }
+        "{}: {}",
+        t(Msg::IrreflexiveLabel),
+        plain(is_irreflexive(matrix))
     );
 }
 
 /// 동치 관계 판별 결과를 출력하는 함수
 /// 반사성, 대칭성, 추이성의 만족 여부를 표시하고 동치 관계일 경우 동치류도 출력
 pub fn print_equivalence_result(matrix: &Matrix) {
-    println!("\n=== 동치 관계 판별 결과 ===");
+    println!("\n=== {} ===", t(Msg::EquivalenceResultTitle));
 
-    let reflexive = is_reflexive(matrix);
-    let symmetric = is_symmetric(matrix);
-    let transitive = is_transitive(matrix);
-    let equivalence = is_equivalence_relation(matrix);
+    let properties = super::report::Properties::of(matrix);
+    let equivalence = properties.is_equivalence();
 
+    println!("{}: {}", t(Msg::ReflexiveLabel), mark(properties.reflexive));
+    println!("{}: {}", t(Msg::SymmetricLabel), mark(properties.symmetric));
     println!(
-        "반사성 (Reflexive): {}",
-        if reflexive {
-            "✓ 만족"
-        } else {
-            "✗ 불만족"
-        }
-    );
-    println!(
-        "대칭성 (Symmetric): {}",
-        if symmetric {
-            "✓ 만족"
-        } else {
-            "✗ 불만족"
-        }
-    );
-    println!(
-        "추이성 (Transitive): {}",
-        if transitive {
-            "✓ 만족"
-        } else {
-            "✗ 불만족"
-        }
+        "{}: {}",
+        t(Msg::TransitiveLabel),
+        mark(properties.transitive)
     );
 
     print_additional_properties(matrix);
 
     println!(
         "\n{}",
-        if equivalence {
-            "이 관계는 동치 관계입니다!"
+        t(if equivalence {
+            Msg::IsEquivalence
         } else {
-            "이 관계는 동치 관계가 아닙니다."
-        }
+            Msg::IsNotEquivalence
+        })
     );
 
     // 동치 관계일 경우 동치류 출력
@@ -162,3 +465,169 @@ pub fn print_equivalence_result(matrix: &Matrix) {
         super::visualize::print_equivalence_classes(matrix);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 덮개 쌍(0부터 시작)으로부터 반사-추이 폐포인 부분 순서를 만드는 헬퍼 함수
+    fn order(n: usize, covers: &[(usize, usize)]) -> Matrix {
+        let mut matrix = vec![vec![0; n]; n];
+        for &(a, b) in covers {
+            matrix[a][b] = 1;
+        }
+        crate::closure::transitive_closure(&crate::closure::reflexive_closure(&matrix))
+    }
+
+    /// 0 < 1 < 2 < 4, 0 < 3 < 4 인 오각형 격자 N5
+    fn pentagon() -> Matrix {
+        order(5, &[(0, 1), (1, 2), (2, 4), (0, 3), (3, 4)])
+    }
+
+    /// 0 < 1, 2, 3 < 4 인 다이아몬드 격자 M3
+    fn diamond_m3() -> Matrix {
+        order(5, &[(0, 1), (0, 2), (0, 3), (1, 4), (2, 4), (3, 4)])
+    }
+
+    #[test]
+    fn basic_properties() {
+        let identity = order(3, &[]);
+        assert!(is_equivalence_relation(&identity));
+        assert!(is_partial_order(&identity));
+        let chain = order(3, &[(0, 1), (1, 2)]);
+        assert!(is_partial_order(&chain) && is_connected_relation(&chain));
+        assert!(!is_symmetric(&chain) && is_antisymmetric(&chain));
+        assert!(is_irreflexive(&vec![vec![0, 1], vec![0, 0]]));
+        assert!(!is_transitive(&vec![
+            vec![0, 1, 0],
+            vec![0, 0, 1],
+            vec![0, 0, 0]
+        ]));
+    }
+
+    /// 격자의 연산표를 만드는 헬퍼 함수 (격자여야 함)
+    fn tables(matrix: &Matrix) -> LatticeTables {
+        lattice_tables(matrix).expect("격자여야 함")
+    }
+
+    #[test]
+    fn meet_join_tables_of_boolean_lattice() {
+        // {} < {a}, {b} < {a, b}
+        let square = order(4, &[(0, 1), (0, 2), (1, 3), (2, 3)]);
+        assert!(is_lattice(&square) && is_bounded_lattice(&square));
+        assert_eq!(meet(&square, 1, 2), Some(0));
+        assert_eq!(join(&square, 1, 2), Some(3));
+        let square = tables(&square);
+        assert_eq!(
+            square.meet,
+            vec![
+                vec![0, 0, 0, 0],
+                vec![0, 1, 0, 1],
+                vec![0, 0, 2, 2],
+                vec![0, 1, 2, 3]
+            ]
+        );
+        assert_eq!(square.join[1][2], 3);
+        assert_eq!((square.bottom(), square.top()), (Some(0), Some(3)));
+        assert!(square.leq(1, 3) && !square.leq(1, 2));
+        assert!(square.is_bounded());
+        assert!(is_distributive_lattice(&square));
+        assert!(is_complemented_lattice(&square));
+        assert_eq!(complements(&square, 1), vec![2]);
+    }
+
+    #[test]
+    fn non_lattices_have_no_tables() {
+        let bowtie = order(4, &[(0, 2), (0, 3), (1, 2), (1, 3)]);
+        assert!(is_partial_order(&bowtie) && !is_lattice(&bowtie));
+        assert_eq!(lattice_tables(&bowtie), None);
+        let cycle = vec![vec![1, 1], vec![1, 1]];
+        assert!(!is_lattice(&cycle) && lattice_tables(&cycle).is_none());
+    }
+
+    #[test]
+    fn tables_agree_with_is_lattice() {
+        let mut rng = crate::order::SimpleRng::new(27);
+        for _ in 0..200 {
+            let n = 1 + rng.below(5) as usize;
+            let mut matrix = vec![vec![0; n]; n];
+            for (i, row) in matrix.iter_mut().enumerate() {
+                for cell in row.iter_mut().skip(i) {
+                    *cell = rng.below(2) as u8;
+                }
+            }
+            let matrix = crate::closure::transitive_closure(&matrix);
+            assert_eq!(lattice_tables(&matrix).is_some(), is_lattice(&matrix));
+        }
+    }
+
+    #[test]
+    fn pentagon_is_not_modular() {
+        let n5 = pentagon();
+        let lattice = tables(&n5);
+        let (a, x, b) = modularity_counterexample(&lattice).expect("N5는 모듈러가 아님");
+        assert!(super::super::order::leq(&n5, a, b));
+        let left = join(&n5, a, meet(&n5, x, b).unwrap());
+        let right = meet(&n5, join(&n5, a, x).unwrap(), b);
+        assert_ne!(left, right);
+        assert!(!is_modular_lattice(&lattice) && !is_distributive_lattice(&lattice));
+        assert!(is_complemented_lattice(&lattice));
+    }
+
+    #[test]
+    fn diamond_is_modular_but_not_distributive() {
+        let m3 = tables(&diamond_m3());
+        assert!(is_modular_lattice(&m3));
+        assert!(distributivity_counterexample(&m3).is_some());
+        assert!(!is_distributive_lattice(&m3));
+        assert_eq!(complements(&m3, 1), vec![2, 3]);
+    }
+
+    #[test]
+    fn chain_is_distributive_but_not_complemented() {
+        let chain = tables(&order(3, &[(0, 1), (1, 2)]));
+        assert!(is_distributive_lattice(&chain) && is_modular_lattice(&chain));
+        assert!(complements(&chain, 1).is_empty());
+        assert!(!is_complemented_lattice(&chain));
+    }
+
+    #[test]
+    fn kernel_of_a_function_is_an_equivalence() {
+        // {0, ..., 6} 위의 함수 f(a) = a mod 3
+        let kernel = kernel_relation_fn(7, |a| a % 3);
+        assert!(is_equivalence_relation(&kernel));
+        assert_eq!(kernel[0][3], 1);
+        assert_eq!(kernel[0][6], 1);
+        assert_eq!(kernel[1][2], 0);
+        assert_eq!(kernel, kernel_relation(&[0, 1, 2, 0, 1, 2, 0]));
+        assert_eq!(
+            crate::visualize::equivalence_classes(&kernel),
+            vec![vec![0, 3, 6], vec![1, 4], vec![2, 5]]
+        );
+    }
+
+    #[test]
+    fn canonical_factorization_recovers_the_function() {
+        let values = ["b", "a", "b", "c", "a"];
+        let factorization = canonical_factorization(&values);
+        assert_eq!(factorization.classes, vec![vec![0, 2], vec![1, 4], vec![3]]);
+        assert_eq!(factorization.projection, vec![0, 1, 0, 2, 1]);
+        assert_eq!(factorization.image, vec!["b", "a", "c"]);
+        // f = ι ∘ f̄ ∘ π
+        for (a, value) in values.iter().enumerate() {
+            assert_eq!(&factorization.image[factorization.projection[a]], value);
+        }
+        // 동치류는 핵 관계의 동치류와 같음
+        assert_eq!(
+            factorization.classes,
+            crate::visualize::equivalence_classes(&kernel_relation(&values))
+        );
+    }
+
+    #[test]
+    fn empty_function_has_empty_factorization() {
+        let factorization = canonical_factorization::<u8>(&[]);
+        assert!(factorization.classes.is_empty() && factorization.image.is_empty());
+        assert!(kernel_relation::<u8>(&[]).is_empty());
+    }
+}
@@ -0,0 +1,343 @@
+/// 오프라인에서 열 수 있는 단일 파일 대화형 HTML 보고서 모듈
+/// 행/열 강조가 되는 관계 행렬, 인라인 SVG 방향 그래프, 속성별 접이식 반례 목록, 폐포 전후 비교, 순서 구조를 포함
+use crate::Matrix;
+use crate::closure::describe_justification;
+use crate::i18n::{Msg, mark, t, tf};
+use crate::render::{
+    HTML_STYLE, MatrixData, Renderer, escape_html, html_matrix, html_order_sections,
+    violation_groups,
+};
+use crate::report::RelationReport;
+
+/// 대화형 보고서에 추가로 사용하는 스타일
+const INTERACTIVE_STYLE: &str = "td.hl, th.hl { outline: 2px solid #d0021b; }
+td.one.hl { background: #9cc3ef; }
+.side-by-side { display: flex; gap: 2em; flex-wrap: wrap; align-items: flex-start; }
+details { margin: 0.5em 0; border: 1px solid #ddd; border-radius: 4px; padding: 0.3em 0.8em; }
+summary { cursor: pointer; font-weight: bold; }
+svg .edge { stroke: #555; stroke-width: 1.5; fill: none; }
+svg .edge.hl { stroke: #d0021b; stroke-width: 3; }
+svg .node circle { fill: #fff; stroke: #333; stroke-width: 1.5; }
+svg .node text { font-size: 14px; text-anchor: middle; dominant-baseline: central; }
+.hint { color: #666; font-size: 0.9em; }";
+
+/// 행렬 칸에 마우스를 올렸을 때 같은 행/열과 그래프의 해당 간선을 강조하는 스크립트
+const INTERACTIVE_SCRIPT: &str = "document.querySelectorAll('table.interactive').forEach(function (table) {
+  var linked = table.hasAttribute('data-graph');
+  table.querySelectorAll('td').forEach(function (cell) {
+    var row = cell.dataset.row, col = cell.dataset.col;
+    var targets = function () {
+      var list = Array.from(table.querySelectorAll('[data-row=\"' + row + '\"], [data-col=\"' + col + '\"]'));
+      var edge = linked && document.getElementById('e-' + row + '-' + col);
+      if (edge) { list.push(edge); }
+      return list;
+    };
+    cell.addEventListener('mouseenter', function () {
+      targets().forEach(function (x) { x.classList.add('hl'); });
+    });
+    cell.addEventListener('mouseleave', function () {
+      targets().forEach(function (x) { x.classList.remove('hl'); });
+    });
+  });
+});";
+
+/// 관계의 방향 그래프를 인라인 SVG로 그리는 함수
+/// 원소를 원 위에 배치하며, 각 간선은 e-i-j (1부터 시작) id를 가짐
+pub fn svg_digraph(matrix: &Matrix) -> String {
+    const SIZE: f64 = 360.0;
+    const RADIUS: f64 = 130.0;
+    const NODE: f64 = 16.0;
+    let n = matrix.len();
+    let center = SIZE / 2.0;
+    let position = |k: usize| {
+        let angle = -std::f64::consts::FRAC_PI_2 + 2.0 * std::f64::consts::PI * k as f64 / n as f64;
+        (
+            center + RADIUS * angle.cos(),
+            center + RADIUS * angle.sin(),
+            angle,
+        )
+    };
+
+    let mut out = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" viewBox=\"0 0 {} {}\">\n",
+        SIZE, SIZE, SIZE, SIZE
+    );
+    out.push_str("<defs><marker id=\"arrow\" viewBox=\"0 0 10 10\" refX=\"10\" refY=\"5\" markerWidth=\"7\" markerHeight=\"7\" orient=\"auto-start-reverse\"><path d=\"M 0 0 L 10 5 L 0 10 z\" fill=\"#555\"/></marker></defs>\n");
+
+    for (i, row) in matrix.iter().enumerate() {
+        for (j, &cell) in row.iter().enumerate() {
+            if cell != 1 {
+                continue;
+            }
+            let (x1, y1, angle) = position(i);
+            let path = if i == j {
+                // 원 바깥쪽으로 나가는 고리
+                let (ox, oy) = (angle.cos(), angle.sin());
+                let (px, py) = (-oy, ox);
+                let sx = x1 + NODE * (ox + 0.5 * px);
+                let sy = y1 + NODE * (oy + 0.5 * py);
+                let ex = x1 + NODE * (ox - 0.5 * px);
+                let ey = y1 + NODE * (oy - 0.5 * py);
+                format!(
+                    "M {:.1} {:.1} C {:.1} {:.1} {:.1} {:.1} {:.1} {:.1}",
+                    sx,
+                    sy,
+                    sx + 40.0 * ox + 20.0 * px,
+                    sy + 40.0 * oy + 20.0 * py,
+                    ex + 40.0 * ox - 20.0 * px,
+                    ey + 40.0 * oy - 20.0 * py,
+                    ex,
+                    ey
+                )
+            } else {
+                let (x2, y2, _) = position(j);
+                let (dx, dy) = (x2 - x1, y2 - y1);
+                let length = (dx * dx + dy * dy).sqrt();
+                let (ux, uy) = (dx / length, dy / length);
+                let (sx, sy) = (x1 + NODE * ux, y1 + NODE * uy);
+                let (ex, ey) = (x2 - NODE * ux, y2 - NODE * uy);
+                if matrix[j][i] == 1 {
+                    // 양방향 쌍은 서로 겹치지 않도록 한쪽으로 휘게 그림
+                    let (mx, my) = ((sx + ex) / 2.0 - 20.0 * uy, (sy + ey) / 2.0 + 20.0 * ux);
+                    format!(
+                        "M {:.1} {:.1} Q {:.1} {:.1} {:.1} {:.1}",
+                        sx, sy, mx, my, ex, ey
+                    )
+                } else {
+                    format!("M {:.1} {:.1} L {:.1} {:.1}", sx, sy, ex, ey)
+                }
+            };
+            out.push_str(&format!(
+                "<path id=\"e-{}-{}\" class=\"edge\" d=\"{}\" marker-end=\"url(#arrow)\"><title>({}, {})</title></path>\n",
+                i + 1,
+                j + 1,
+                path,
+                i + 1,
+                j + 1
+            ));
+        }
+    }
+    for k in 0..n {
+        let (x, y, _) = position(k);
+        out.push_str(&format!(
+            "<g class=\"node\"><circle cx=\"{:.1}\" cy=\"{:.1}\" r=\"{}\"/><text x=\"{:.1}\" y=\"{:.1}\">{}</text></g>\n",
+            x,
+            y,
+            NODE,
+            x,
+            y,
+            k + 1
+        ));
+    }
+    out.push_str("</svg>\n");
+    out
+}
+
+/// 분석 보고서를 대화형 단일 HTML 파일로 출력 (외부 자원 없이 동작)
+pub struct InteractiveHtmlRenderer;
+
+impl Renderer for InteractiveHtmlRenderer {
+    fn render(&self, report: &RelationReport) -> String {
+        let matrix = &report.matrix;
+        let title = escape_html(t(Msg::ReportTitle));
+        let mut body = format!("<h1>{}</h1>\n", title);
+
+        // 관계 행렬과 방향 그래프
+        body.push_str(&format!(
+            "<details open>\n<summary>{} / {}</summary>\n<p class=\"hint\">{}</p>\n<div class=\"side-by-side\">\n{}{}</div>\n</details>\n",
+            escape_html(t(Msg::ReportMatrix)),
+            escape_html(t(Msg::DigraphTitle)),
+            escape_html(t(Msg::HtmlHoverHint)),
+            html_matrix(matrix, &[], MatrixData::Graph),
+            svg_digraph(matrix)
+        ));
+
+        // 속성별 반례
+        body.push_str(&format!(
+            "<h2>{}</h2>\n",
+            escape_html(t(Msg::ReportProperties))
+        ));
+        let groups = violation_groups(matrix, &report.violations);
+        for ((_, name, ok), (_, witnesses, total)) in report.properties.entries().iter().zip(groups)
+        {
+            body.push_str(&format!(
+                "<details{}>\n<summary>{}: <span class=\"{}\">{}</span></summary>\n",
+                if *ok { "" } else { " open" },
+                escape_html(t(*name)),
+                if *ok { "ok" } else { "fail" },
+                escape_html(mark(*ok))
+            ));
+            if witnesses.is_empty() {
+                body.push_str(&format!("<p>{}</p>\n", escape_html(t(Msg::ProofHolds))));
+            } else {
+                body.push_str(&format!(
+                    "<p>{}</p>\n<ul>\n",
+                    escape_html(&tf(Msg::HtmlWitnessCount, &[&total]))
+                ));
+                for witness in &witnesses {
+                    body.push_str(&format!("<li><code>{}</code></li>\n", escape_html(witness)));
+                }
+                if total > witnesses.len() {
+                    let more = tf(Msg::ReportMoreViolations, &[&(total - witnesses.len())]);
+                    body.push_str(&format!("<li>{}</li>\n", escape_html(&more)));
+                }
+                body.push_str("</ul>\n");
+            }
+            body.push_str("</details>\n");
+        }
+
+        // 관계 분류와 동치류
+        body.push_str(&format!(
+            "<h2>{}</h2>\n<ul>\n",
+            escape_html(t(Msg::ReportClassification))
+        ));
+        if report.classifications.is_empty() {
+            body.push_str(&format!("<li>{}</li>\n", escape_html(t(Msg::ReportNone))));
+        }
+        for class in &report.classifications {
+            body.push_str(&format!("<li>{}</li>\n", escape_html(class.label())));
+        }
+        body.push_str("</ul>\n");
+        if let Some(classes) = &report.classes {
+            body.push_str(&format!(
+                "<h2>{}</h2>\n<ul>\n",
+                escape_html(t(Msg::EquivalenceClassesTitle))
+            ));
+            for class in classes {
+                body.push_str(&format!(
+                    "<li>[{}] = {}</li>\n",
+                    class[0] + 1,
+                    escape_html(&super::order::format_set(class))
+                ));
+            }
+            body.push_str("</ul>\n");
+        }
+
+        // 폐포 전후 비교
+        body.push_str(&format!(
+            "<h2>{}</h2>\n",
+            escape_html(t(Msg::ReportClosures))
+        ));
+        for closure in &report.closures {
+            let added: Vec<(usize, usize)> =
+                closure.trace.added.iter().map(|(pair, _)| *pair).collect();
+            body.push_str(&format!(
+                "<details{}>\n<summary>{} - {}</summary>\n<div class=\"side-by-side\">\n<div><h4>{}</h4>\n{}</div>\n<div><h4>{}</h4>\n{}</div>\n</div>\n",
+                if added.is_empty() { "" } else { " open" },
+                escape_html(closure.kind.label()),
+                escape_html(&tf(Msg::ReportAddedCount, &[&added.len()])),
+                escape_html(t(Msg::HtmlBefore)),
+                html_matrix(matrix, &[], MatrixData::Cells),
+                escape_html(t(Msg::HtmlAfter)),
+                html_matrix(&closure.trace.matrix, &added, MatrixData::Cells)
+            ));
+            if !closure.trace.added.is_empty() {
+                body.push_str(&format!(
+                    "<table>\n<tr><th>{}</th><th>{}</th></tr>\n",
+                    escape_html(t(Msg::JustificationPair)),
+                    escape_html(t(Msg::JustificationReason))
+                ));
+                for ((i, j), justification) in &closure.trace.added {
+                    body.push_str(&format!(
+                        "<tr><td>({}, {})</td><td>{}</td></tr>\n",
+                        i + 1,
+                        j + 1,
+                        escape_html(&describe_justification(justification))
+                    ));
+                }
+                body.push_str("</table>\n");
+            }
+            body.push_str("</details>\n");
+        }
+
+        // 부분 순서일 때 순서 구조와 격자 판별
+        if let Some(order) = &report.order {
+            body.push_str(&html_order_sections(order, "h2"));
+        }
+
+        format!(
+            "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n<style>\n{}\n{}\n</style>\n</head>\n<body>\n{}<script>\n{}\n</script>\n</body>\n</html>\n",
+            title, HTML_STYLE, INTERACTIVE_STYLE, body, INTERACTIVE_SCRIPT
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::report::analyze_relation;
+
+    /// 1 → 2 → 3 경로와 3의 고리, 1 ↔ 3 양방향 쌍
+    fn sample() -> Matrix {
+        vec![vec![0, 1, 1], vec![0, 0, 1], vec![1, 0, 1]]
+    }
+
+    #[test]
+    fn svg_has_one_path_per_pair() {
+        let matrix = sample();
+        let svg = svg_digraph(&matrix);
+        let ones: usize = matrix.iter().flatten().map(|&v| v as usize).sum();
+        assert_eq!(svg.matches("class=\"edge\"").count(), ones);
+        assert_eq!(svg.matches("<g class=\"node\">").count(), 3);
+        for (i, row) in matrix.iter().enumerate() {
+            for (j, &cell) in row.iter().enumerate() {
+                let id = format!("id=\"e-{}-{}\"", i + 1, j + 1);
+                assert_eq!(svg.matches(&id).count(), cell as usize, "{id}");
+            }
+        }
+        // 양방향 쌍은 곡선(Q), 고리는 3차 곡선(C)
+        assert_eq!(svg.matches(" Q ").count(), 2);
+        assert_eq!(svg.matches(" C ").count(), 1);
+    }
+
+    #[test]
+    fn report_is_self_contained() {
+        let html = InteractiveHtmlRenderer.render(&analyze_relation(&sample()));
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(!html.contains("src=\"http") && !html.contains("href=\"http"));
+        assert_eq!(html.matches("<script>").count(), 1);
+        for tag in ["details", "table", "svg", "ul"] {
+            assert_eq!(
+                html.matches(&format!("<{tag}")).count(),
+                html.matches(&format!("</{tag}>")).count(),
+                "<{tag}>"
+            );
+        }
+    }
+
+    #[test]
+    fn closures_highlight_added_cells() {
+        let report = analyze_relation(&sample());
+        let html = InteractiveHtmlRenderer.render(&report);
+        let added: usize = report.closures.iter().map(|c| c.trace.added.len()).sum();
+        assert_eq!(html.matches("<td class=\"added\"").count(), added);
+        // 실패한 속성마다 반례 목록이 펼쳐진 채로 표시
+        let failing = report
+            .properties
+            .entries()
+            .iter()
+            .filter(|(_, _, ok)| !ok)
+            .count();
+        let opened_closures = report
+            .closures
+            .iter()
+            .filter(|c| !c.trace.added.is_empty())
+            .count();
+        assert_eq!(
+            html.matches("<details open>").count(),
+            1 + failing + opened_closures
+        );
+    }
+
+    #[test]
+    fn partial_orders_include_order_sections() {
+        let chain = vec![vec![1, 1], vec![0, 1]];
+        let with_order = InteractiveHtmlRenderer.render(&analyze_relation(&chain));
+        let without = InteractiveHtmlRenderer.render(&analyze_relation(&sample()));
+        for title in [Msg::ReportOrder, Msg::LatticeTitle] {
+            let heading = format!("<h2>{}</h2>", escape_html(t(title)));
+            assert!(with_order.contains(&heading));
+            assert!(!without.contains(&heading));
+        }
+    }
+}
@@ -0,0 +1,1557 @@
+/// 출력 문자열의 한국어/영어 메시지 카탈로그와 언어 선택을 담당하는 모듈
+/// 언어는 프로그램 전체에서 하나이며, --lang 옵션 또는 LC_ALL/LC_MESSAGES/LANG 환경 변수로 선택
+use std::fmt::Display;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+/// 지원하는 출력 언어
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Lang {
+    Ko,
+    En,
+}
+
+/// 현재 선택된 언어 (0: 한국어, 1: 영어)
+static CURRENT: AtomicU8 = AtomicU8::new(0);
+
+impl Lang {
+    /// "ko", "en", "en_US.UTF-8" 같은 언어 코드를 해석하는 함수
+    /// 알 수 없는 코드는 None
+    pub fn parse(code: &str) -> Option<Lang> {
+        let code = code.trim().to_ascii_lowercase();
+        if code.starts_with("ko") {
+            Some(Lang::Ko)
+        } else if code.starts_with("en") {
+            Some(Lang::En)
+        } else {
+            None
+        }
+    }
+
+    /// LC_ALL, LC_MESSAGES, LANG 순서로 환경 변수를 확인하여 언어를 정하는 함수
+    /// 비어 있지 않은 첫 변수만 사용하며, 해석할 수 없으면 None
+    pub fn from_env() -> Option<Lang> {
+        ["LC_ALL", "LC_MESSAGES", "LANG"]
+            .iter()
+            .filter_map(|name| std::env::var(name).ok())
+            .find(|value| !value.is_empty())
+            .and_then(|value| Lang::parse(&value))
+    }
+}
+
+/// 출력 언어를 설정하는 함수
+pub fn set_lang(lang: Lang) {
+    CURRENT.store(lang as u8, Ordering::Relaxed);
+}
+
+/// 현재 출력 언어를 반환하는 함수 (기본값은 한국어)
+pub fn lang() -> Lang {
+    match CURRENT.load(Ordering::Relaxed) {
+        1 => Lang::En,
+        _ => Lang::Ko,
+    }
+}
+
+/// 메시지 카탈로그의 항목
+/// 문자열 안의 {}는 tf의 인자로 차례로 채워짐
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Msg {
+    // 공통
+    Satisfied,
+    NotSatisfied,
+    SatisfiedPlain,
+    NotSatisfiedPlain,
+    Reflexivity,
+    Symmetry,
+    Transitivity,
+    Antisymmetry,
+    Irreflexivity,
+    Connectedness,
+    ReflexiveLabel,
+    SymmetricLabel,
+    TransitiveLabel,
+    AntisymmetricLabel,
+    IrreflexiveLabel,
+
+    // 동치 관계 판별 (equivalence.rs)
+    EquivalenceResultTitle,
+    AdditionalPropertiesTitle,
+    IsEquivalence,
+    IsNotEquivalence,
+    KernelMatrixTitle,
+    KernelIsEquivalence,
+    CanonicalFactorization,
+    RowKernelTitle,
+    LatticeTitle,
+    NotLattice,
+    LatticeSatisfied,
+    BoundedLattice,
+    DistributiveLattice,
+    ModularLattice,
+    ComplementedLattice,
+    DistributiveCounterexample,
+    ModularCounterexample,
+    OperationTable,
+
+    // 폐포 분석 (closure.rs)
+    JustifyReflexive,
+    JustifySymmetric,
+    JustifyPath,
+    JustifyUndirectedPath,
+    JustificationHeader,
+    JustificationPair,
+    JustificationReason,
+    ClosureAnalysisTitle,
+    AlreadyEquivalence,
+    DemonstrateClosures,
+    ReflexiveClosure,
+    SymmetricClosure,
+    TransitiveClosure,
+    ReflexiveClosureSection,
+    SymmetricClosureSection,
+    TransitiveClosureSection,
+    ClosureSkipped,
+    MatrixBefore,
+    MatrixAfterClosure,
+    ClosureChanged,
+    ClosureUnchanged,
+    CheckAfterClosure,
+    AllClosuresSection,
+    OriginalMatrix,
+    MatrixAfterAllClosures,
+    AllClosuresChanged,
+    AllClosuresUnchanged,
+    CheckAfterAllClosures,
+    AllClosuresSkipped,
+
+    // 시각화 (visualize.rs)
+    AdjacencyTitle,
+    ComponentsTitle,
+    EquivalenceClassesTitle,
+    ClassDetailTitle,
+    ClassDetailNotEquivalence,
+    SetLabel,
+    ClassOfEachElement,
+    ClassOfElement,
+    PartitionStructure,
+    IndividualTitle,
+    ReflexivityCheck,
+    SymmetryCheck,
+    TransitivityCheck,
+    ResultLine,
+    PropertyHolds,
+    PropertyFails,
+    AllPairsSymmetric,
+    NoTransitivityViolation,
+    TransitivityViolation,
+    CombinedResult,
+    IsEquivalenceMark,
+    IsNotEquivalenceMark,
+    RelationshipTitle,
+    BasicProperties,
+    AdditionalProperties,
+    Classification,
+    ClassEquivalence,
+    ClassPartialOrder,
+    ClassStrictPartialOrder,
+    ClassStrictTotalOrder,
+    ClassTotalOrder,
+    ClassMatched,
+
+    // 부분 순서 (order.rs)
+    PosetTitle,
+    MinimalElements,
+    MaximalElements,
+    LeastElement,
+    GreatestElement,
+    NoLeastElement,
+    NoGreatestElement,
+    UpperBoundsOfAll,
+    LowerBoundsOfAll,
+    WholeSetChain,
+    WholeSetAntichain,
+    IncomparableBoundsHeader,
+    IncomparableBounds,
+    TopologicalTitle,
+    TopologicalOrder,
+    TopologicalCycle,
+    LinearExtensionCount,
+    LinearExtensionUncountable,
+    LinearExtensionList,
+    RandomLinearExtension,
+    ChainDecompositionTitle,
+    PosetHeight,
+    PosetWidth,
+    DilworthHeader,
+    MirskyHeader,
+    MirskyLevel,
+    DimensionTitle,
+    ComparabilityEdges,
+    IncomparabilityEdges,
+    DimensionAtMostTwo,
+    DimensionAtLeastThree,
+    ChainProduct,
+    NotChainProduct,
+
+    // 동형 판별 (isomorphism.rs)
+    CanonicalFormTitle,
+    CanonicalLabeling,
+    CanonicalMatrix,
+    AutomorphismTitle,
+    GroupOrder,
+    GroupOrderOverflow,
+    NoGenerators,
+    Generators,
+    OrbitsHeader,
+    OrbitColumn,
+    ClassColumn,
+
+    // 몫 관계 (quotient.rs)
+    QuotientTitle,
+    QuotientNotEquivalence,
+    QuotientClasses,
+    QuotientMatrix,
+    QuotientCompatible,
+    QuotientIncompatible,
+
+    // 분할 비교 (partition.rs)
+    PartitionTitle,
+    PartitionNeedsEquivalences,
+    PartitionMeet,
+    PartitionJoin,
+    PartitionRefines,
+    PartitionDisagreements,
+    RandIndex,
+    VariationOfInformation,
+
+    // 가장 가까운 동치 관계 (nearest.rs)
+    NearestSection,
+    NearestMatrix,
+    NearestMethod,
+    NearestExact,
+    NearestHeuristic,
+    PairsToAdd,
+    PairsToRemove,
+    NearestDistance,
+
+    // 내부 분석 (interior.rs)
+    RemovalMissingInverse,
+    RemovalOnDiagonal,
+    RemovalConflict,
+    RemovalBreaksTransitivity,
+    InteriorUnchanged,
+    InteriorRemovedCount,
+    InteriorAnalysisTitle,
+    SymmetricInteriorSection,
+    IrreflexiveInteriorSection,
+    AntisymmetricInteriorSection,
+    TransitiveInteriorSection,
+    SymmetricInterior,
+    IrreflexiveInterior,
+    AntisymmetricInterior,
+    TransitiveInterior,
+    AntisymmetricInteriorCount,
+    LargestTransitiveSubrelation,
+    MaximalTransitiveSubrelation,
+    MaximalTransitiveCount,
+    MaximalTransitiveGreedy,
+
+    // 일반 폐포 엔진 (rules.rs)
+    RuleReflexive,
+    RuleSymmetric,
+    RuleTransitive,
+    RuleEuclidean,
+    RuleImage,
+    Euclideanness,
+    EquivalenceProperty,
+    Asymmetry,
+    DerivationAxiom,
+    DerivationStep,
+    RuleClosureTitle,
+    RuleClosureAlready,
+    RuleClosureAdded,
+    RuleClosureMore,
+    NoClosureFullFails,
+    NoClosureIntersection,
+
+    // Warshall 단계 추적 (warshall.rs)
+    FrameOriginal,
+    FramePivot,
+    FrameChanged,
+    WarshallTitle,
+    WarshallLegend,
+    WarshallPause,
+    WarshallTotal,
+
+    // 분석 보고서 (render.rs)
+    ReportTitle,
+    ReportMatrix,
+    ReportProperties,
+    ReportProperty,
+    ReportResult,
+    ReportClassification,
+    ReportViolations,
+    ReportClosures,
+    ReportNone,
+    ReportMoreViolations,
+    ReportTransitivityWitness,
+    ReportAddedCount,
+    UnknownClosure,
+    NoEquivalenceClasses,
+    ReportOrder,
+    ReportDimensionSkipped,
+    ReportLatticeSkipped,
+
+    // LaTeX 내보내기 (latex.rs)
+    ProofTitle,
+    ProofHolds,
+    ProofFails,
+    ProofMoreRows,
+    DigraphTitle,
+    HasseTitle,
+
+    // 대화형 HTML 보고서 (html_report.rs)
+    HtmlHoverHint,
+    HtmlWitnessCount,
+    HtmlBefore,
+    HtmlAfter,
+
+    // 터미널 행렬 출력 (terminal.rs)
+    DiffLegend,
+    ViolationLegend,
+    NoViolationCells,
+
+    // 터미널 UI (tui.rs)
+    TuiTitle,
+    TuiHelp,
+    TuiNothingToUndo,
+    TuiNothingToRedo,
+    TuiNeedsTerminal,
+    TuiEmptyMatrix,
+    TuiFinalMatrix,
+
+    // 관계 표기 해석 (parse.rs)
+    ParseEmpty,
+    ParseTooLarge,
+    ParseNotSquare,
+    ParseNotBinary,
+    ParseBadNumber,
+    ParseBadSet,
+    ParseOutOfRange,
+    ParseBadMatrix,
+    ParseBadJson,
+    ParseUnknownNotation,
+
+    // 대화형 셸 (repl.rs)
+    ReplBanner,
+    ReplHelp,
+    ReplUsage,
+    ReplUnknownCommand,
+    ReplUnknownRelation,
+    ReplBadName,
+    ReplNotEquivalence,
+    ReplSaved,
+    ReplNoRelations,
+
+    // 브라우저용 API (wasm.rs)
+    WasmBadLength,
+
+    // Python 확장 모듈 (python.rs)
+    PyIndexOutOfRange,
+
+    // 입력 및 명령행 (main.rs)
+    Banner,
+    ErrorPrefix,
+    UnknownOption,
+    MissingFramesDir,
+    MissingLang,
+    UnknownLang,
+    MissingFormat,
+    UnknownFormat,
+    MissingOutput,
+    MissingReportHtml,
+    MissingColor,
+    UnknownColor,
+    MissingHighlight,
+    UnknownHighlight,
+    ReportSaved,
+    ReportFailed,
+    FramesSaved,
+    FramesFailed,
+    InputMatrixTitle,
+    StronglyConnectedComponents,
+    EquivalenceClosureName,
+    InputEnded,
+    NotationHint,
+    SizePrompt,
+    SizeLabel,
+    SizeOutOfRange,
+    SizeNotInteger,
+    RowsPrompt,
+    RowsHint,
+    RowLabel,
+    RowLength,
+    RowNotBinary,
+    RowNotNumber,
+}
+
+impl Msg {
+    /// 모든 메시지 목록 (선언 순서)
+    pub const ALL: &[Msg] = &[
+        // 공통
+        Msg::Satisfied,
+        Msg::NotSatisfied,
+        Msg::SatisfiedPlain,
+        Msg::NotSatisfiedPlain,
+        Msg::Reflexivity,
+        Msg::Symmetry,
+        Msg::Transitivity,
+        Msg::Antisymmetry,
+        Msg::Irreflexivity,
+        Msg::Connectedness,
+        Msg::ReflexiveLabel,
+        Msg::SymmetricLabel,
+        Msg::TransitiveLabel,
+        Msg::AntisymmetricLabel,
+        Msg::IrreflexiveLabel,
+        // 동치 관계 판별 (equivalence.rs)
+        Msg::EquivalenceResultTitle,
+        Msg::AdditionalPropertiesTitle,
+        Msg::IsEquivalence,
+        Msg::IsNotEquivalence,
+        Msg::KernelMatrixTitle,
+        Msg::KernelIsEquivalence,
+        Msg::CanonicalFactorization,
+        Msg::RowKernelTitle,
+        Msg::LatticeTitle,
+        Msg::NotLattice,
+        Msg::LatticeSatisfied,
+        Msg::BoundedLattice,
+        Msg::DistributiveLattice,
+        Msg::ModularLattice,
+        Msg::ComplementedLattice,
+        Msg::DistributiveCounterexample,
+        Msg::ModularCounterexample,
+        Msg::OperationTable,
+        // 폐포 분석 (closure.rs)
+        Msg::JustifyReflexive,
+        Msg::JustifySymmetric,
+        Msg::JustifyPath,
+        Msg::JustifyUndirectedPath,
+        Msg::JustificationHeader,
+        Msg::JustificationPair,
+        Msg::JustificationReason,
+        Msg::ClosureAnalysisTitle,
+        Msg::AlreadyEquivalence,
+        Msg::DemonstrateClosures,
+        Msg::ReflexiveClosure,
+        Msg::SymmetricClosure,
+        Msg::TransitiveClosure,
+        Msg::ReflexiveClosureSection,
+        Msg::SymmetricClosureSection,
+        Msg::TransitiveClosureSection,
+        Msg::ClosureSkipped,
+        Msg::MatrixBefore,
+        Msg::MatrixAfterClosure,
+        Msg::ClosureChanged,
+        Msg::ClosureUnchanged,
+        Msg::CheckAfterClosure,
+        Msg::AllClosuresSection,
+        Msg::OriginalMatrix,
+        Msg::MatrixAfterAllClosures,
+        Msg::AllClosuresChanged,
+        Msg::AllClosuresUnchanged,
+        Msg::CheckAfterAllClosures,
+        Msg::AllClosuresSkipped,
+        // 시각화 (visualize.rs)
+        Msg::AdjacencyTitle,
+        Msg::ComponentsTitle,
+        Msg::EquivalenceClassesTitle,
+        Msg::ClassDetailTitle,
+        Msg::ClassDetailNotEquivalence,
+        Msg::SetLabel,
+        Msg::ClassOfEachElement,
+        Msg::ClassOfElement,
+        Msg::PartitionStructure,
+        Msg::IndividualTitle,
+        Msg::ReflexivityCheck,
+        Msg::SymmetryCheck,
+        Msg::TransitivityCheck,
+        Msg::ResultLine,
+        Msg::PropertyHolds,
+        Msg::PropertyFails,
+        Msg::AllPairsSymmetric,
+        Msg::NoTransitivityViolation,
+        Msg::TransitivityViolation,
+        Msg::CombinedResult,
+        Msg::IsEquivalenceMark,
+        Msg::IsNotEquivalenceMark,
+        Msg::RelationshipTitle,
+        Msg::BasicProperties,
+        Msg::AdditionalProperties,
+        Msg::Classification,
+        Msg::ClassEquivalence,
+        Msg::ClassPartialOrder,
+        Msg::ClassStrictPartialOrder,
+        Msg::ClassStrictTotalOrder,
+        Msg::ClassTotalOrder,
+        Msg::ClassMatched,
+        // 부분 순서 (order.rs)
+        Msg::PosetTitle,
+        Msg::MinimalElements,
+        Msg::MaximalElements,
+        Msg::LeastElement,
+        Msg::GreatestElement,
+        Msg::NoLeastElement,
+        Msg::NoGreatestElement,
+        Msg::UpperBoundsOfAll,
+        Msg::LowerBoundsOfAll,
+        Msg::WholeSetChain,
+        Msg::WholeSetAntichain,
+        Msg::IncomparableBoundsHeader,
+        Msg::IncomparableBounds,
+        Msg::TopologicalTitle,
+        Msg::TopologicalOrder,
+        Msg::TopologicalCycle,
+        Msg::LinearExtensionCount,
+        Msg::LinearExtensionUncountable,
+        Msg::LinearExtensionList,
+        Msg::RandomLinearExtension,
+        Msg::ChainDecompositionTitle,
+        Msg::PosetHeight,
+        Msg::PosetWidth,
+        Msg::DilworthHeader,
+        Msg::MirskyHeader,
+        Msg::MirskyLevel,
+        Msg::DimensionTitle,
+        Msg::ComparabilityEdges,
+        Msg::IncomparabilityEdges,
+        Msg::DimensionAtMostTwo,
+        Msg::DimensionAtLeastThree,
+        Msg::ChainProduct,
+        Msg::NotChainProduct,
+        // 동형 판별 (isomorphism.rs)
+        Msg::CanonicalFormTitle,
+        Msg::CanonicalLabeling,
+        Msg::CanonicalMatrix,
+        Msg::AutomorphismTitle,
+        Msg::GroupOrder,
+        Msg::GroupOrderOverflow,
+        Msg::NoGenerators,
+        Msg::Generators,
+        Msg::OrbitsHeader,
+        Msg::OrbitColumn,
+        Msg::ClassColumn,
+        // 몫 관계 (quotient.rs)
+        Msg::QuotientTitle,
+        Msg::QuotientNotEquivalence,
+        Msg::QuotientClasses,
+        Msg::QuotientMatrix,
+        Msg::QuotientCompatible,
+        Msg::QuotientIncompatible,
+        // 분할 비교 (partition.rs)
+        Msg::PartitionTitle,
+        Msg::PartitionNeedsEquivalences,
+        Msg::PartitionMeet,
+        Msg::PartitionJoin,
+        Msg::PartitionRefines,
+        Msg::PartitionDisagreements,
+        Msg::RandIndex,
+        Msg::VariationOfInformation,
+        // 가장 가까운 동치 관계 (nearest.rs)
+        Msg::NearestSection,
+        Msg::NearestMatrix,
+        Msg::NearestMethod,
+        Msg::NearestExact,
+        Msg::NearestHeuristic,
+        Msg::PairsToAdd,
+        Msg::PairsToRemove,
+        Msg::NearestDistance,
+        // 내부 분석 (interior.rs)
+        Msg::RemovalMissingInverse,
+        Msg::RemovalOnDiagonal,
+        Msg::RemovalConflict,
+        Msg::RemovalBreaksTransitivity,
+        Msg::InteriorUnchanged,
+        Msg::InteriorRemovedCount,
+        Msg::InteriorAnalysisTitle,
+        Msg::SymmetricInteriorSection,
+        Msg::IrreflexiveInteriorSection,
+        Msg::AntisymmetricInteriorSection,
+        Msg::TransitiveInteriorSection,
+        Msg::SymmetricInterior,
+        Msg::IrreflexiveInterior,
+        Msg::AntisymmetricInterior,
+        Msg::TransitiveInterior,
+        Msg::AntisymmetricInteriorCount,
+        Msg::LargestTransitiveSubrelation,
+        Msg::MaximalTransitiveSubrelation,
+        Msg::MaximalTransitiveCount,
+        Msg::MaximalTransitiveGreedy,
+        // 일반 폐포 엔진 (rules.rs)
+        Msg::RuleReflexive,
+        Msg::RuleSymmetric,
+        Msg::RuleTransitive,
+        Msg::RuleEuclidean,
+        Msg::RuleImage,
+        Msg::Euclideanness,
+        Msg::EquivalenceProperty,
+        Msg::Asymmetry,
+        Msg::DerivationAxiom,
+        Msg::DerivationStep,
+        Msg::RuleClosureTitle,
+        Msg::RuleClosureAlready,
+        Msg::RuleClosureAdded,
+        Msg::RuleClosureMore,
+        Msg::NoClosureFullFails,
+        Msg::NoClosureIntersection,
+        // Warshall 단계 추적 (warshall.rs)
+        Msg::FrameOriginal,
+        Msg::FramePivot,
+        Msg::FrameChanged,
+        Msg::WarshallTitle,
+        Msg::WarshallLegend,
+        Msg::WarshallPause,
+        Msg::WarshallTotal,
+        // 분석 보고서 (render.rs)
+        Msg::ReportTitle,
+        Msg::ReportMatrix,
+        Msg::ReportProperties,
+        Msg::ReportProperty,
+        Msg::ReportResult,
+        Msg::ReportClassification,
+        Msg::ReportViolations,
+        Msg::ReportClosures,
+        Msg::ReportNone,
+        Msg::ReportMoreViolations,
+        Msg::ReportTransitivityWitness,
+        Msg::ReportAddedCount,
+        Msg::UnknownClosure,
+        Msg::NoEquivalenceClasses,
+        Msg::ReportOrder,
+        Msg::ReportDimensionSkipped,
+        Msg::ReportLatticeSkipped,
+        // LaTeX 내보내기 (latex.rs)
+        Msg::ProofTitle,
+        Msg::ProofHolds,
+        Msg::ProofFails,
+        Msg::ProofMoreRows,
+        Msg::DigraphTitle,
+        Msg::HasseTitle,
+        // 대화형 HTML 보고서 (html_report.rs)
+        Msg::HtmlHoverHint,
+        Msg::HtmlWitnessCount,
+        Msg::HtmlBefore,
+        Msg::HtmlAfter,
+        // 터미널 행렬 출력 (terminal.rs)
+        Msg::DiffLegend,
+        Msg::ViolationLegend,
+        Msg::NoViolationCells,
+        // 터미널 UI (tui.rs)
+        Msg::TuiTitle,
+        Msg::TuiHelp,
+        Msg::TuiNothingToUndo,
+        Msg::TuiNothingToRedo,
+        Msg::TuiNeedsTerminal,
+        Msg::TuiEmptyMatrix,
+        Msg::TuiFinalMatrix,
+        // 관계 표기 해석 (parse.rs)
+        Msg::ParseEmpty,
+        Msg::ParseTooLarge,
+        Msg::ParseNotSquare,
+        Msg::ParseNotBinary,
+        Msg::ParseBadNumber,
+        Msg::ParseBadSet,
+        Msg::ParseOutOfRange,
+        Msg::ParseBadMatrix,
+        Msg::ParseBadJson,
+        Msg::ParseUnknownNotation,
+        // 대화형 셸 (repl.rs)
+        Msg::ReplBanner,
+        Msg::ReplHelp,
+        Msg::ReplUsage,
+        Msg::ReplUnknownCommand,
+        Msg::ReplUnknownRelation,
+        Msg::ReplBadName,
+        Msg::ReplNotEquivalence,
+        Msg::ReplSaved,
+        Msg::ReplNoRelations,
+        // 브라우저용 API (wasm.rs)
+        Msg::WasmBadLength,
+        // Python 확장 모듈 (python.rs)
+        Msg::PyIndexOutOfRange,
+        // 입력 및 명령행 (main.rs)
+        Msg::Banner,
+        Msg::ErrorPrefix,
+        Msg::UnknownOption,
+        Msg::MissingFramesDir,
+        Msg::MissingLang,
+        Msg::UnknownLang,
+        Msg::MissingFormat,
+        Msg::UnknownFormat,
+        Msg::MissingOutput,
+        Msg::MissingReportHtml,
+        Msg::MissingColor,
+        Msg::UnknownColor,
+        Msg::MissingHighlight,
+        Msg::UnknownHighlight,
+        Msg::ReportSaved,
+        Msg::ReportFailed,
+        Msg::FramesSaved,
+        Msg::FramesFailed,
+        Msg::InputMatrixTitle,
+        Msg::StronglyConnectedComponents,
+        Msg::EquivalenceClosureName,
+        Msg::InputEnded,
+        Msg::NotationHint,
+        Msg::SizePrompt,
+        Msg::SizeLabel,
+        Msg::SizeOutOfRange,
+        Msg::SizeNotInteger,
+        Msg::RowsPrompt,
+        Msg::RowsHint,
+        Msg::RowLabel,
+        Msg::RowLength,
+        Msg::RowNotBinary,
+        Msg::RowNotNumber,
+    ];
+
+    /// (한국어, 영어) 문자열 쌍
+    fn entry(self) -> (&'static str, &'static str) {
+        match self {
+            Msg::Satisfied => ("✓ 만족", "✓ satisfied"),
+            Msg::NotSatisfied => ("✗ 불만족", "✗ not satisfied"),
+            Msg::SatisfiedPlain => ("만족", "satisfied"),
+            Msg::NotSatisfiedPlain => ("불만족", "not satisfied"),
+            Msg::Reflexivity => ("반사성", "Reflexivity"),
+            Msg::Symmetry => ("대칭성", "Symmetry"),
+            Msg::Transitivity => ("추이성", "Transitivity"),
+            Msg::Antisymmetry => ("반대칭성", "Antisymmetry"),
+            Msg::Irreflexivity => ("비반사성", "Irreflexivity"),
+            Msg::Connectedness => ("연결성", "Connectedness"),
+            Msg::ReflexiveLabel => ("반사성 (Reflexive)", "Reflexive"),
+            Msg::SymmetricLabel => ("대칭성 (Symmetric)", "Symmetric"),
+            Msg::TransitiveLabel => ("추이성 (Transitive)", "Transitive"),
+            Msg::AntisymmetricLabel => ("반대칭성 (Antisymmetric)", "Antisymmetric"),
+            Msg::IrreflexiveLabel => ("비반사성 (Irreflexive)", "Irreflexive"),
+
+            Msg::EquivalenceResultTitle => ("동치 관계 판별 결과", "Equivalence relation check"),
+            Msg::AdditionalPropertiesTitle => ("추가 속성 판별", "Additional properties"),
+            Msg::IsEquivalence => (
+                "이 관계는 동치 관계입니다!",
+                "This relation is an equivalence relation!",
+            ),
+            Msg::IsNotEquivalence => (
+                "이 관계는 동치 관계가 아닙니다.",
+                "This relation is not an equivalence relation.",
+            ),
+            Msg::KernelMatrixTitle => ("핵 동치 관계 ker f", "Kernel equivalence ker f"),
+            Msg::KernelIsEquivalence => (
+                "ker f의 동치 관계 여부: {}",
+                "ker f is an equivalence relation: {}",
+            ),
+            Msg::CanonicalFactorization => (
+                "표준 분해 A → A/ker f → im f → B:",
+                "Canonical factorization A → A/ker f → im f → B:",
+            ),
+            Msg::RowKernelTitle => (
+                "함수의 핵 (f(a) = {b | R(a,b)})",
+                "Kernel of a function (f(a) = {b | R(a,b)})",
+            ),
+            Msg::LatticeTitle => ("격자 분석", "Lattice analysis"),
+            Msg::NotLattice => (
+                "일부 원소 쌍의 상한 또는 하한이 존재하지 않으므로 격자가 아닙니다.",
+                "Some pair of elements has no supremum or infimum, so this is not a lattice.",
+            ),
+            Msg::LatticeSatisfied => ("격자 (Lattice): ✓ 만족", "Lattice: ✓ satisfied"),
+            Msg::BoundedLattice => ("유계 격자 (Bounded)", "Bounded lattice"),
+            Msg::DistributiveLattice => ("분배 격자 (Distributive)", "Distributive lattice"),
+            Msg::ModularLattice => ("모듈러 격자 (Modular)", "Modular lattice"),
+            Msg::ComplementedLattice => ("여원 격자 (Complemented)", "Complemented lattice"),
+            Msg::DistributiveCounterexample => (
+                "  반례: a={}, b={}, c={} 에서 a ∧ (b ∨ c) ≠ (a ∧ b) ∨ (a ∧ c)",
+                "  Counterexample: a={}, b={}, c={} gives a ∧ (b ∨ c) ≠ (a ∧ b) ∨ (a ∧ c)",
+            ),
+            Msg::ModularCounterexample => (
+                "  반례: a={} ≤ b={}, x={} 에서 a ∨ (x ∧ b) ≠ (a ∨ x) ∧ b",
+                "  Counterexample: a={} ≤ b={}, x={} gives a ∨ (x ∧ b) ≠ (a ∨ x) ∧ b",
+            ),
+            Msg::OperationTable => ("{} 연산표:", "{} operation table:"),
+
+            Msg::JustifyReflexive => ("반사성 (대각선)", "reflexivity (diagonal)"),
+            Msg::JustifySymmetric => ("({}, {})의 대칭", "symmetric of ({}, {})"),
+            Msg::JustifyPath => ("경로 {}", "path {}"),
+            Msg::JustifyUndirectedPath => ("방향 무시 경로 {}", "undirected path {}"),
+            Msg::JustificationHeader => ("추가된 쌍의 근거:", "Why each pair was added:"),
+            Msg::JustificationPair => ("쌍", "pair"),
+            Msg::JustificationReason => ("근거", "reason"),
+            Msg::ClosureAnalysisTitle => ("폐포 분석", "Closure analysis"),
+            Msg::AlreadyEquivalence => (
+                "이 관계는 이미 완벽한 동치 관계입니다.",
+                "This relation is already an equivalence relation.",
+            ),
+            Msg::DemonstrateClosures => (
+                "하지만 교육적 목적으로 각 폐포의 효과를 시연합니다.",
+                "The effect of each closure is still demonstrated for teaching purposes.",
+            ),
+            Msg::ReflexiveClosure => ("반사 폐포", "reflexive closure"),
+            Msg::SymmetricClosure => ("대칭 폐포", "symmetric closure"),
+            Msg::TransitiveClosure => ("추이 폐포", "transitive closure"),
+            Msg::ReflexiveClosureSection => ("--- 반사 폐포 ---", "--- Reflexive closure ---"),
+            Msg::SymmetricClosureSection => ("--- 대칭 폐포 ---", "--- Symmetric closure ---"),
+            Msg::TransitiveClosureSection => ("--- 추이 폐포 ---", "--- Transitive closure ---"),
+            Msg::ClosureSkipped => (
+                "{}이 이미 만족되어 {}를 건너뜁니다.",
+                "{} already holds, so the {} is skipped.",
+            ),
+            Msg::MatrixBefore => ("변환 전 행렬", "Matrix before"),
+            Msg::MatrixAfterClosure => ("{} 후 행렬", "Matrix after the {}"),
+            Msg::ClosureChanged => (
+                "변화: {}에 의해 행렬이 변경되었습니다.",
+                "Change: the {} modified the matrix.",
+            ),
+            Msg::ClosureUnchanged => (
+                "변화: 이미 {}이 만족되어 변경되지 않았습니다.",
+                "Change: {} already held, so nothing changed.",
+            ),
+            Msg::CheckAfterClosure => ("{} 후 동치 관계 판별:", "Equivalence check after the {}:"),
+            Msg::AllClosuresSection => (
+                "--- 모든 폐포 적용 (반사 + 대칭 + 추이) ---",
+                "--- All closures (reflexive + symmetric + transitive) ---",
+            ),
+            Msg::OriginalMatrix => ("원본 행렬", "Original matrix"),
+            Msg::MatrixAfterAllClosures => ("모든 폐포 적용 후 행렬", "Matrix after all closures"),
+            Msg::AllClosuresChanged => (
+                "변화: 폐포 적용에 의해 행렬이 변경되었습니다.",
+                "Change: applying the closures modified the matrix.",
+            ),
+            Msg::AllClosuresUnchanged => (
+                "변화: 이미 완벽한 동치 관계여서 변경되지 않았습니다.",
+                "Change: already an equivalence relation, so nothing changed.",
+            ),
+            Msg::CheckAfterAllClosures => (
+                "모든 폐포 적용 후 동치 관계 판별:",
+                "Equivalence check after all closures:",
+            ),
+            Msg::AllClosuresSkipped => (
+                "원본 행렬이 이미 완벽한 동치 관계이므로 모든 폐포 적용을 건너뜁니다.",
+                "The original matrix is already an equivalence relation, so applying all closures is skipped.",
+            ),
+
+            Msg::AdjacencyTitle => ("그래프 (인접 리스트)", "Graph (adjacency lists)"),
+            Msg::ComponentsTitle => (
+                "연결 요소 (약연결성)",
+                "Connected components (weak connectivity)",
+            ),
+            Msg::EquivalenceClassesTitle => ("동치류", "Equivalence classes"),
+            Msg::ClassDetailTitle => ("동치류 상세 분석", "Equivalence classes in detail"),
+            Msg::ClassDetailNotEquivalence => (
+                "동치 관계가 아니므로 동치류 분석을 수행할 수 없습니다.",
+                "Not an equivalence relation, so equivalence classes cannot be analyzed.",
+            ),
+            Msg::SetLabel => ("집합: {}", "Set: {}"),
+            Msg::ClassOfEachElement => ("각 원소의 동치류:", "Equivalence class of each element:"),
+            Msg::ClassOfElement => ("동치류 [{}]: {}", "Class [{}]: {}"),
+            Msg::PartitionStructure => (
+                "동치 관계의 파티션 구조:",
+                "Partition induced by the relation:",
+            ),
+            Msg::IndividualTitle => ("개별 속성 상세 분석", "Property-by-property analysis"),
+            Msg::ReflexivityCheck => ("반사성 검증:", "Reflexivity check:"),
+            Msg::SymmetryCheck => ("대칭성 검증:", "Symmetry check:"),
+            Msg::TransitivityCheck => ("추이성 검증:", "Transitivity check:"),
+            Msg::ResultLine => ("결과: {}", "Result: {}"),
+            Msg::PropertyHolds => ("{} 만족 ✓", "{} holds ✓"),
+            Msg::PropertyFails => ("{} 불만족 ✗", "{} fails ✗"),
+            Msg::AllPairsSymmetric => ("  모든 쌍이 대칭적입니다 ✓", "  Every pair is symmetric ✓"),
+            Msg::NoTransitivityViolation => (
+                "  추이성 위반사항이 없습니다 ✓",
+                "  No transitivity violations ✓",
+            ),
+            Msg::TransitivityViolation => (
+                "  R({}, {}) ∧ R({}, {}) = 1, 1이지만 R({}, {}) = {}",
+                "  R({}, {}) ∧ R({}, {}) = 1, 1 but R({}, {}) = {}",
+            ),
+            Msg::CombinedResult => ("통합 결과: {}", "Overall: {}"),
+            Msg::IsEquivalenceMark => (
+                "이 관계는 동치 관계입니다! ✓",
+                "This relation is an equivalence relation! ✓",
+            ),
+            Msg::IsNotEquivalenceMark => (
+                "이 관계는 동치 관계가 아닙니다 ✗",
+                "This relation is not an equivalence relation ✗",
+            ),
+            Msg::RelationshipTitle => ("관계 속성 종합 분석", "Relation properties overview"),
+            Msg::BasicProperties => ("기본 동치 관계 속성:", "Equivalence properties:"),
+            Msg::AdditionalProperties => ("추가 관계 속성:", "Additional properties:"),
+            Msg::Classification => ("관계 분류:", "Classification:"),
+            Msg::ClassEquivalence => ("동치 관계 (Equivalence Relation)", "Equivalence relation"),
+            Msg::ClassPartialOrder => ("부분 순서 관계 (Partial Order Relation)", "Partial order"),
+            Msg::ClassStrictPartialOrder => (
+                "엄밀 부분 순서 관계 (Strict Partial Order)",
+                "Strict partial order",
+            ),
+            Msg::ClassStrictTotalOrder => (
+                "엄밀 전순서 관계 (Strict Total Order)",
+                "Strict total order",
+            ),
+            Msg::ClassTotalOrder => ("전순서 관계 (Total Order)", "Total order"),
+            Msg::ClassMatched => ("✓ {}", "✓ {}"),
+
+            Msg::PosetTitle => ("부분 순서 집합 분석", "Partially ordered set analysis"),
+            Msg::MinimalElements => (
+                "극소 원소: {} (자신보다 작은 원소가 없음)",
+                "Minimal elements: {} (nothing lies below them)",
+            ),
+            Msg::MaximalElements => (
+                "극대 원소: {} (자신보다 큰 원소가 없음)",
+                "Maximal elements: {} (nothing lies above them)",
+            ),
+            Msg::LeastElement => ("최소 원소: {}", "Least element: {}"),
+            Msg::GreatestElement => ("최대 원소: {}", "Greatest element: {}"),
+            Msg::NoLeastElement => (
+                " (극소 원소가 여러 개이므로 존재하지 않음)",
+                " (there are several minimal elements)",
+            ),
+            Msg::NoGreatestElement => (
+                " (극대 원소가 여러 개이므로 존재하지 않음)",
+                " (there are several maximal elements)",
+            ),
+            Msg::UpperBoundsOfAll => ("전체 집합의 상계: {}", "Upper bounds of the whole set: {}"),
+            Msg::LowerBoundsOfAll => ("전체 집합의 하계: {}", "Lower bounds of the whole set: {}"),
+            Msg::WholeSetChain => (
+                "모든 원소가 서로 비교 가능하므로 전체 집합이 사슬(chain)입니다.",
+                "Every two elements are comparable, so the whole set is a chain.",
+            ),
+            Msg::WholeSetAntichain => (
+                "모든 원소가 서로 비교 불가능하므로 전체 집합이 반사슬(antichain)입니다.",
+                "No two elements are comparable, so the whole set is an antichain.",
+            ),
+            Msg::IncomparableBoundsHeader => (
+                "비교 불가능한 쌍의 상한/하한:",
+                "Suprema and infima of incomparable pairs:",
+            ),
+            Msg::IncomparableBounds => (
+                "  {{}, {}}: 상계 {} → 상한 {}, 하계 {} → 하한 {}",
+                "  {{}, {}}: upper bounds {} → supremum {}, lower bounds {} → infimum {}",
+            ),
+            Msg::TopologicalTitle => ("위상 정렬 및 선형 확장", "Topological sort and linear extensions"),
+            Msg::TopologicalOrder => ("위상 정렬: {}", "Topological order: {}"),
+            Msg::TopologicalCycle => (
+                "사이클 {}이(가) 있어 위상 정렬이 불가능합니다.",
+                "The cycle {} makes a topological sort impossible.",
+            ),
+            Msg::LinearExtensionCount => ("선형 확장의 개수: {}", "Number of linear extensions: {}"),
+            Msg::LinearExtensionUncountable => (
+                "원소가 너무 많아 선형 확장의 개수를 셀 수 없습니다.",
+                "Too many elements to count the linear extensions.",
+            ),
+            Msg::LinearExtensionList => (
+                "선형 확장 (사전순 최대 {}개):",
+                "Linear extensions (at most {} in lexicographic order):",
+            ),
+            Msg::RandomLinearExtension => (
+                "균등 무작위 선형 확장: {}",
+                "Uniformly random linear extension: {}",
+            ),
+            Msg::ChainDecompositionTitle => ("사슬 및 반사슬 분해", "Chain and antichain decompositions"),
+            Msg::PosetHeight => (
+                "높이 (가장 긴 사슬의 길이): {} - {}",
+                "Height (length of a longest chain): {} - {}",
+            ),
+            Msg::PosetWidth => (
+                "폭 (가장 큰 반사슬의 크기): {} - {}",
+                "Width (size of a largest antichain): {} - {}",
+            ),
+            Msg::DilworthHeader => (
+                "Dilworth 분해 (최소 사슬 덮개, 폭과 같은 개수):",
+                "Dilworth decomposition (minimum chain cover, as many chains as the width):",
+            ),
+            Msg::MirskyHeader => (
+                "Mirsky 분해 (반사슬 분할, 높이와 같은 개수):",
+                "Mirsky decomposition (antichain partition, as many levels as the height):",
+            ),
+            Msg::MirskyLevel => ("  단계 {}: {}", "  Level {}: {}"),
+            Msg::DimensionTitle => (
+                "순서 차원 및 비교 가능성 그래프",
+                "Order dimension and comparability graphs",
+            ),
+            Msg::ComparabilityEdges => ("비교 가능성 그래프 간선: {}", "Comparability graph edges: {}"),
+            Msg::IncomparabilityEdges => ("비교 불가능성 그래프 간선: {}", "Incomparability graph edges: {}"),
+            Msg::DimensionAtMostTwo => (
+                "차원 ≤ 2: 두 선형 확장의 교집합으로 표현됩니다.",
+                "Dimension ≤ 2: the intersection of two linear extensions.",
+            ),
+            Msg::DimensionAtLeastThree => (
+                "차원 ≥ 3: 두 선형 확장의 교집합으로 표현할 수 없습니다.",
+                "Dimension ≥ 3: not the intersection of two linear extensions.",
+            ),
+            Msg::ChainProduct => (
+                "사슬 곱 구조: 크기 {} 사슬들의 곱과 순서 동형입니다.",
+                "Product of chains: order-isomorphic to a product of chains of sizes {}.",
+            ),
+            Msg::NotChainProduct => (
+                "사슬 곱 구조: 사슬들의 곱과 순서 동형이 아닙니다.",
+                "Product of chains: not order-isomorphic to a product of chains.",
+            ),
+
+            Msg::CanonicalFormTitle => ("정규형 (동형 판별용)", "Canonical form (for isomorphism testing)"),
+            Msg::CanonicalLabeling => ("정규 라벨링: {}", "Canonical labeling: {}"),
+            Msg::CanonicalMatrix => ("정규형 행렬", "Canonical matrix"),
+            Msg::AutomorphismTitle => ("자기 동형군", "Automorphism group"),
+            Msg::GroupOrder => ("군의 위수: {}", "Group order: {}"),
+            Msg::GroupOrderOverflow => ("군의 위수: {} (u128 범위 초과)", "Group order: {} (exceeds u128)"),
+            Msg::NoGenerators => ("생성원: 없음 (항등 사상만 존재)", "Generators: none (only the identity)"),
+            Msg::Generators => ("생성원: {}", "Generators: {}"),
+            Msg::OrbitsHeader => ("궤도:", "Orbits:"),
+            Msg::OrbitColumn => ("궤도", "Orbits"),
+            Msg::ClassColumn => ("동치류", "Classes"),
+
+            Msg::QuotientTitle => ("몫 관계 ({})", "Quotient relation ({})"),
+            Msg::QuotientNotEquivalence => (
+                "주어진 관계가 동치 관계가 아니므로 몫 관계를 만들 수 없습니다.",
+                "The given relation is not an equivalence relation, so there is no quotient.",
+            ),
+            Msg::QuotientClasses => ("동치류:", "Equivalence classes:"),
+            Msg::QuotientMatrix => (
+                "몫 관계 행렬 (C1, C2, ... 순서)",
+                "Quotient matrix (in the order C1, C2, ...)",
+            ),
+            Msg::QuotientCompatible => (
+                "합동: ✓ R(x,y)의 값이 동치류에만 의존하므로 R은 몫 관계로 정확히 표현됩니다.",
+                "Compatible: ✓ R(x,y) depends only on the classes, so the quotient represents R exactly.",
+            ),
+            Msg::QuotientIncompatible => (
+                "합동: ✗ R({}, {}) = 1 이고 {} ~ {}, {} ~ {} 이지만 R({}, {}) = 0",
+                "Compatible: ✗ R({}, {}) = 1 and {} ~ {}, {} ~ {}, but R({}, {}) = 0",
+            ),
+
+            Msg::PartitionTitle => ("분할 비교 ({} / {})", "Partition comparison ({} / {})"),
+            Msg::PartitionNeedsEquivalences => (
+                "두 관계가 모두 같은 집합 위의 동치 관계여야 분할을 비교할 수 있습니다.",
+                "Both relations must be equivalence relations on the same set to compare partitions.",
+            ),
+            Msg::PartitionMeet => ("만남 (공통 세분): {}", "Meet (common refinement): {}"),
+            Msg::PartitionJoin => ("이음 (공통 조대화): {}", "Join (common coarsening): {}"),
+            Msg::PartitionRefines => ("{} ≤ {} (세분): {}", "{} ≤ {} (refinement): {}"),
+            Msg::PartitionDisagreements => (
+                "판단이 다른 쌍의 수: {} {}",
+                "Pairs classified differently: {} {}",
+            ),
+            Msg::RandIndex => ("Rand 지수: {}", "Rand index: {}"),
+            Msg::VariationOfInformation => (
+                "정보 변화량 (VI): {} 비트",
+                "Variation of information (VI): {} bits",
+            ),
+
+            Msg::NearestSection => (
+                "--- 가장 가까운 동치 관계 (대칭차 최소) ---",
+                "--- Nearest equivalence relation (smallest symmetric difference) ---",
+            ),
+            Msg::NearestMatrix => ("가장 가까운 동치 관계", "Nearest equivalence relation"),
+            Msg::NearestMethod => ("방법: {}", "Method: {}"),
+            Msg::NearestExact => ("분기 한정법 (정확한 해)", "branch and bound (exact)"),
+            Msg::NearestHeuristic => ("지역 탐색 휴리스틱 (근사 해)", "local search heuristic (approximate)"),
+            Msg::PairsToAdd => ("추가할 쌍: {}", "Pairs to add: {}"),
+            Msg::PairsToRemove => ("제거할 쌍: {}", "Pairs to remove: {}"),
+            Msg::NearestDistance => (
+                "대칭차 크기: {} (동치 폐포는 {}개의 쌍을 추가)",
+                "Symmetric difference: {} (the equivalence closure adds {} pairs)",
+            ),
+
+            Msg::RemovalMissingInverse => (
+                "({}, {}) 제거: 역방향 쌍 ({}, {})가 없음",
+                "Removed ({}, {}): the reverse pair ({}, {}) is missing",
+            ),
+            Msg::RemovalOnDiagonal => ("({}, {}) 제거: 대각선 원소", "Removed ({}, {}): diagonal entry"),
+            Msg::RemovalConflict => (
+                "({}, {}) 제거: ({}, {})와 함께 있으면 반대칭성 위반, ({}, {})를 남김",
+                "Removed ({}, {}): together with ({}, {}) it violates antisymmetry, so ({}, {}) is kept",
+            ),
+            Msg::RemovalBreaksTransitivity => (
+                "({}, {}) 제거: 남기면 ({}, {}), ({}, {})는 있지만 ({}, {})가 없어 추이성 위반",
+                "Removed ({}, {}): keeping it gives ({}, {}) and ({}, {}) without ({}, {}), violating transitivity",
+            ),
+            Msg::InteriorUnchanged => ("변화: 제거된 쌍이 없습니다.", "Change: no pairs were removed."),
+            Msg::InteriorRemovedCount => ("변화: {}개의 쌍이 제거되었습니다.", "Change: {} pairs were removed."),
+            Msg::InteriorAnalysisTitle => (
+                "내부 분석 (가장 큰 부분 관계)",
+                "Interior analysis (largest subrelations)",
+            ),
+            Msg::SymmetricInteriorSection => ("--- 대칭 내부 ---", "--- Symmetric interior ---"),
+            Msg::IrreflexiveInteriorSection => ("--- 비반사 내부 ---", "--- Irreflexive interior ---"),
+            Msg::AntisymmetricInteriorSection => (
+                "--- 반대칭 내부 ---",
+                "--- Antisymmetric interior ---",
+            ),
+            Msg::TransitiveInteriorSection => ("--- 추이 내부 ---", "--- Transitive interior ---"),
+            Msg::SymmetricInterior => ("대칭 내부", "symmetric interior"),
+            Msg::IrreflexiveInterior => ("비반사 내부", "irreflexive interior"),
+            Msg::AntisymmetricInterior => ("반대칭 내부", "antisymmetric interior"),
+            Msg::TransitiveInterior => ("추이 내부", "transitive interior"),
+            Msg::AntisymmetricInteriorCount => (
+                "가장 큰 반대칭 부분 관계는 모두 {}개이며 크기가 같습니다 (양방향 쌍마다 남길 방향 선택).",
+                "There are {} largest antisymmetric subrelations, all of the same size (one direction is kept for each two-way pair).",
+            ),
+            Msg::LargestTransitiveSubrelation => (
+                "가장 큰 추이 부분 관계",
+                "largest transitive subrelation",
+            ),
+            Msg::MaximalTransitiveSubrelation => ("극대 추이 부분 관계", "maximal transitive subrelation"),
+            Msg::MaximalTransitiveCount => (
+                "극대 추이 부분 관계는 모두 {}개입니다.",
+                "There are {} maximal transitive subrelations.",
+            ),
+            Msg::MaximalTransitiveGreedy => (
+                "순서쌍이 {}개보다 많아 모든 극대 추이 부분 관계를 나열하지 않고 탐욕적으로 하나만 구했습니다.",
+                "With more than {} pairs, one maximal transitive subrelation was found greedily instead of listing them all.",
+            ),
+
+            Msg::RuleReflexive => ("반사", "reflexive"),
+            Msg::RuleSymmetric => ("대칭", "symmetric"),
+            Msg::RuleTransitive => ("추이", "transitive"),
+            Msg::RuleEuclidean => ("유클리드", "Euclidean"),
+            Msg::RuleImage => ("함수 상", "image"),
+            Msg::Euclideanness => ("유클리드성", "Euclideanness"),
+            Msg::EquivalenceProperty => ("동치 관계", "Equivalence relation"),
+            Msg::Asymmetry => ("비대칭성", "Asymmetry"),
+            Msg::DerivationAxiom => ("({}, {}) ← {} 규칙", "({}, {}) ← {} rule"),
+            Msg::DerivationStep => ("({}, {}) ← {}: {}", "({}, {}) ← {}: {}"),
+            Msg::RuleClosureTitle => ("일반 폐포 엔진 (Horn 규칙)", "General closure engine (Horn rules)"),
+            Msg::RuleClosureAlready => (
+                "이미 {}을(를) 만족하여 추가된 쌍이 없습니다.",
+                "{} already holds, so no pairs were added.",
+            ),
+            Msg::RuleClosureAdded => ("{}번의 반복으로 {}개의 쌍이 추가되었습니다.", "{} round(s) of rules added {} pair(s)."),
+            Msg::RuleClosureMore => ("  ... 외 {}개", "  ... and {} more"),
+            Msg::NoClosureFullFails => (
+                "폐포 없음: 전체 관계조차 이 속성을 만족하지 않으므로 쌍을 추가해서 만족시킬 수 없습니다.",
+                "No closure: even the full relation fails this property, so adding pairs cannot satisfy it.",
+            ),
+            Msg::NoClosureIntersection => (
+                "폐포 없음: {}와 {}는 모두 만족하지만 교집합은 만족하지 않아 가장 작은 상위 관계가 유일하지 않습니다.",
+                "No closure: {} and {} both satisfy it but their intersection does not, so there is no unique smallest superset.",
+            ),
+
+            Msg::FrameOriginal => ("W_0 (원래 관계)", "W_0 (original relation)"),
+            Msg::FramePivot => ("W_{} (경유 원소 {} 추가)", "W_{} (element {} allowed as intermediate)"),
+            Msg::FrameChanged => ("바뀐 칸: {}", "Changed cells: {}"),
+            Msg::WarshallTitle => ("Warshall 알고리즘 단계별 추적", "Warshall's algorithm step by step"),
+            Msg::WarshallLegend => (
+                "* 표시는 이번 단계의 경유 원소, [1]은 이번 단계에서 새로 추가된 칸",
+                "* marks this step's intermediate element, [1] marks cells added in this step",
+            ),
+            Msg::WarshallPause => ("(Enter를 누르면 다음 단계로 진행)", "(press Enter for the next step)"),
+            Msg::WarshallTotal => (
+                "총 {}개의 칸이 추가되어 추이 폐포가 완성되었습니다.",
+                "{} cells were added in total, completing the transitive closure.",
+            ),
+
+            Msg::ReportTitle => ("관계 분석 보고서", "Relation analysis report"),
+            Msg::ReportMatrix => ("관계 행렬", "Relation matrix"),
+            Msg::ReportProperties => ("속성", "Properties"),
+            Msg::ReportProperty => ("속성", "Property"),
+            Msg::ReportResult => ("결과", "Result"),
+            Msg::ReportClassification => ("관계 분류", "Classification"),
+            Msg::ReportViolations => ("위반 사항", "Violations"),
+            Msg::ReportClosures => ("폐포", "Closures"),
+            Msg::ReportNone => ("없음", "none"),
+            Msg::ReportMoreViolations => ("... 외 {}개", "... and {} more"),
+            Msg::ReportTransitivityWitness => (
+                "R({}, {}) = R({}, {}) = 1이지만 R({}, {}) = 0",
+                "R({}, {}) = R({}, {}) = 1 but R({}, {}) = 0",
+            ),
+            Msg::ReportAddedCount => ("추가된 쌍: {}개", "Pairs added: {}"),
+            Msg::UnknownClosure => (
+                "알 수 없는 폐포: {} (refl, sym, trans, equiv)",
+                "Unknown closure: {} (refl, sym, trans, equiv)",
+            ),
+            Msg::NoEquivalenceClasses => (
+                "동치 관계가 아니므로 동치류를 구할 수 없습니다.",
+                "Not an equivalence relation, so it has no equivalence classes.",
+            ),
+            Msg::ReportOrder => ("순서 구조", "Order structure"),
+            Msg::ReportDimensionSkipped => (
+                "원소가 {}개보다 많아 차원과 사슬 곱 구조는 생략합니다.",
+                "Dimension and product-of-chains structure are omitted above {} elements.",
+            ),
+            Msg::ReportLatticeSkipped => (
+                "원소가 {}개보다 많아 격자 분석은 생략합니다.",
+                "Lattice analysis is omitted above {} elements.",
+            ),
+
+            Msg::ProofTitle => ("속성 증명 스케치", "Proof sketches"),
+            Msg::ProofHolds => (
+                "모든 경우를 확인하였으므로 성립한다.",
+                "Every case was checked, so the property holds.",
+            ),
+            Msg::ProofFails => (
+                "다음 반례가 있으므로 성립하지 않는다.",
+                "The property fails because of the following counterexamples.",
+            ),
+            Msg::ProofMoreRows => ("(외 {}개의 반례 생략)", "({} more counterexamples omitted)"),
+            Msg::DigraphTitle => ("방향 그래프", "Directed graph"),
+            Msg::HasseTitle => ("Hasse 도표", "Hasse diagram"),
+
+            Msg::HtmlHoverHint => (
+                "행렬의 칸에 마우스를 올리면 같은 행과 열, 그래프의 해당 간선이 강조됩니다.",
+                "Hover over a matrix cell to highlight its row, its column and the matching edge in the graph.",
+            ),
+            Msg::HtmlWitnessCount => ("반례 {}개:", "{} counterexamples:"),
+            Msg::HtmlBefore => ("변환 전", "Before"),
+            Msg::HtmlAfter => ("변환 후", "After"),
+
+            Msg::DiffLegend => ("{} 폐포로 추가된 쌍", "{} pair added by the closure"),
+            Msg::ViolationLegend => ("{} {} 위반 칸", "{} cell violating {}"),
+            Msg::NoViolationCells => ("{} 위반 칸 없음", "No cell violates {}"),
+
+            Msg::TuiTitle => ("관계 행렬 편집기", "Relation matrix editor"),
+            Msg::TuiHelp => (
+                "화살표/hjkl 이동  Space 토글  r/s/t/e 반사/대칭/추이/동치 폐포  u 되돌리기  y 다시 실행  q 종료",
+                "arrows/hjkl move  Space toggle  r/s/t/e reflexive/symmetric/transitive/equivalence closure  u undo  y redo  q quit",
+            ),
+            Msg::TuiNothingToUndo => ("되돌릴 작업이 없습니다.", "Nothing to undo."),
+            Msg::TuiNothingToRedo => ("다시 실행할 작업이 없습니다.", "Nothing to redo."),
+            Msg::TuiNeedsTerminal => (
+                "tui는 표준 입력과 출력이 터미널일 때만 실행할 수 있습니다.",
+                "tui requires stdin and stdout to be a terminal.",
+            ),
+            Msg::TuiEmptyMatrix => (
+                "빈 행렬은 편집할 수 없습니다.",
+                "An empty matrix cannot be edited.",
+            ),
+            Msg::TuiFinalMatrix => ("편집을 마친 관계행렬", "Edited relation matrix"),
+
+            Msg::ParseEmpty => ("빈 관계입니다.", "The relation is empty."),
+            Msg::ParseTooLarge => (
+                "원소 개수 {}이(가) 최대 {}개를 넘습니다.",
+                "Size {} exceeds the maximum of {} elements.",
+            ),
+            Msg::ParseNotSquare => (
+                "{}행의 길이가 {}입니다 ({}이어야 함).",
+                "Row {} has length {} (expected {}).",
+            ),
+            Msg::ParseNotBinary => (
+                "행렬의 원소는 0 또는 1이어야 합니다: {}",
+                "Matrix entries must be 0 or 1: {}",
+            ),
+            Msg::ParseBadNumber => ("숫자가 아닙니다: {}", "Not a number: {}"),
+            Msg::ParseBadSet => (
+                "순서쌍 집합은 {(1, 2), (2, 3)} 형식이어야 합니다.",
+                "A set of pairs must look like {(1, 2), (2, 3)}.",
+            ),
+            Msg::ParseOutOfRange => (
+                "순서쌍 ({}, {})이 1부터 {} 사이를 벗어났습니다.",
+                "Pair ({}, {}) is outside 1 to {}.",
+            ),
+            Msg::ParseBadMatrix => (
+                "행렬은 [1 0; 0 1] 형식이어야 합니다.",
+                "A matrix must look like [1 0; 0 1].",
+            ),
+            Msg::ParseBadJson => (
+                "JSON 행렬은 [[1, 0], [0, 1]] 형식이어야 합니다.",
+                "A JSON matrix must look like [[1, 0], [0, 1]].",
+            ),
+            Msg::ParseUnknownNotation => (
+                "알 수 없는 표기: {} ({…}, […], [[…]] 또는 관계 이름)",
+                "Unknown notation: {} ({…}, […], [[…]] or a relation name)",
+            ),
+
+            Msg::ReplBanner => (
+                "=== 관계 셸 === (help: 명령 목록, quit: 종료, Tab: 자동 완성)",
+                "=== Relation shell === (help: commands, quit: exit, Tab: completion)",
+            ),
+            Msg::ReplHelp => (
+                "  let R = {(1, 2), (2, 3)} [on N]   순서쌍 집합으로 관계 정의 (N: 원소 개수)
+  let R = [1 0; 0 1]                  행 단위 행렬 (JSON [[1, 0], [0, 1]]도 가능)
+  let R = load 파일.csv|파일.json      파일에서 읽기 (let S = R은 복사)
+  show R                              행렬 출력
+  check R                             동치 관계 판별
+  close R refl|sym|trans|equiv [as S] 폐포 계산 (as가 없으면 R을 바꿈)
+  classes R                           동치류 출력
+  save R 파일.csv|파일.json            파일로 저장
+  list                                정의된 관계 목록
+  quit                                종료",
+                "  let R = {(1, 2), (2, 3)} [on N]   define a relation by its pairs (N: number of elements)
+  let R = [1 0; 0 1]                  row-by-row matrix (JSON [[1, 0], [0, 1]] also works)
+  let R = load file.csv|file.json     read from a file (let S = R copies)
+  show R                              print the matrix
+  check R                             check for an equivalence relation
+  close R refl|sym|trans|equiv [as S] compute a closure (replaces R without as)
+  classes R                           print the equivalence classes
+  save R file.csv|file.json           save to a file
+  list                                list defined relations
+  quit                                exit",
+            ),
+            Msg::ReplUsage => ("사용법:\n{}", "Usage:\n{}"),
+            Msg::ReplUnknownCommand => (
+                "알 수 없는 명령: {} (help로 목록 확인)",
+                "Unknown command: {} (see help)",
+            ),
+            Msg::ReplUnknownRelation => ("정의되지 않은 관계: {}", "Undefined relation: {}"),
+            Msg::ReplBadName => (
+                "관계 이름으로 쓸 수 없습니다: {}",
+                "Not a valid relation name: {}",
+            ),
+            Msg::ReplNotEquivalence => (
+                "{}은(는) 동치 관계가 아닙니다. close {} equiv로 동치 폐포를 만들 수 있습니다.",
+                "{} is not an equivalence relation. Use close {} equiv to build its equivalence closure.",
+            ),
+            Msg::ReplSaved => ("{}을(를) {}에 저장했습니다.", "Saved {} to {}."),
+            Msg::ReplNoRelations => ("정의된 관계가 없습니다.", "No relations defined."),
+
+            Msg::WasmBadLength => (
+                "원소 {}개로는 {}×{} 행렬을 만들 수 없습니다.",
+                "{} cells do not form a {}×{} matrix.",
+            ),
+            Msg::PyIndexOutOfRange => (
+                "위치 ({}, {})이 0부터 {} 사이를 벗어났습니다.",
+                "Index ({}, {}) is outside 0 to {}.",
+            ),
+
+            Msg::Banner => (
+                "=== 관계행렬 입력 및 동치 관계 판별 ===",
+                "=== Relation matrix input and equivalence check ===",
+            ),
+            Msg::ErrorPrefix => ("오류: {}", "Error: {}"),
+            Msg::UnknownOption => ("알 수 없는 옵션: {}", "Unknown option: {}"),
+            Msg::MissingFramesDir => (
+                "--frames 뒤에 디렉터리를 지정하세요.",
+                "--frames requires a directory.",
+            ),
+            Msg::MissingColor => (
+                "--color 뒤에 auto, always, never 중 하나를 지정하세요.",
+                "--color requires auto, always or never.",
+            ),
+            Msg::UnknownColor => (
+                "지원하지 않는 색상 설정: {} (auto, always, never)",
+                "Unsupported color setting: {} (auto, always, never)",
+            ),
+            Msg::MissingHighlight => (
+                "--highlight 뒤에 속성(reflexive, symmetric, transitive, antisymmetric, irreflexive, connected)을 지정하세요.",
+                "--highlight requires a property (reflexive, symmetric, transitive, antisymmetric, irreflexive, connected).",
+            ),
+            Msg::UnknownHighlight => (
+                "알 수 없는 속성: {} (reflexive, symmetric, transitive, antisymmetric, irreflexive, connected)",
+                "Unknown property: {} (reflexive, symmetric, transitive, antisymmetric, irreflexive, connected)",
+            ),
+            Msg::MissingLang => (
+                "--lang 뒤에 언어(ko 또는 en)를 지정하세요.",
+                "--lang requires a language (ko or en).",
+            ),
+            Msg::UnknownLang => (
+                "지원하지 않는 언어: {} (ko 또는 en)",
+                "Unsupported language: {} (ko or en)",
+            ),
+            Msg::MissingFormat => (
+                "--format 뒤에 형식(terminal, markdown, html, json, latex)을 지정하세요.",
+                "--format requires a format (terminal, markdown, html, json, latex).",
+            ),
+            Msg::UnknownFormat => (
+                "지원하지 않는 형식: {} (terminal, markdown, html, json, latex)",
+                "Unsupported format: {} (terminal, markdown, html, json, latex)",
+            ),
+            Msg::MissingOutput => (
+                "--output 뒤에 파일 경로를 지정하세요.",
+                "--output requires a file path.",
+            ),
+            Msg::MissingReportHtml => (
+                "report 뒤에 --html <파일>을 지정하세요.",
+                "report requires --html <file>.",
+            ),
+            Msg::ReportSaved => ("보고서를 {}에 저장했습니다.", "Saved the report to {}."),
+            Msg::ReportFailed => ("보고서 저장 실패: {}", "failed to save the report: {}"),
+            Msg::FramesSaved => (
+                "Warshall 프레임 {}개를 {}에 저장했습니다.",
+                "Saved {} Warshall frames to {}.",
+            ),
+            Msg::FramesFailed => ("프레임 저장 실패: {}", "failed to save frames: {}"),
+            Msg::InputMatrixTitle => ("입력된 관계행렬", "Input relation matrix"),
+            Msg::StronglyConnectedComponents => ("강연결 요소", "strongly connected components"),
+            Msg::EquivalenceClosureName => ("동치 폐포", "equivalence closure"),
+            Msg::InputEnded => (
+                "입력이 끝나 관계행렬을 읽지 못했습니다.",
+                "Input ended before the relation matrix was read.",
+            ),
+            Msg::NotationHint => (
+                "또는 {(1, 2), (2, 3)} on 3, [[0, 1], [1, 0]] 같은 표기를 한 줄로 입력하세요.",
+                "Or enter a notation such as {(1, 2), (2, 3)} on 3 or [[0, 1], [1, 0]] on one line.",
+            ),
+            Msg::SizePrompt => (
+                "관계행렬의 크기를 입력하세요 ({}-{} 사이의 정수): ",
+                "Enter the size of the relation matrix (an integer from {} to {}): ",
+            ),
+            Msg::SizeLabel => ("크기: ", "Size: "),
+            Msg::SizeOutOfRange => (
+                "오류: 크기는 {}에서 {} 사이여야 합니다. 다시 입력하세요.",
+                "Error: the size must be between {} and {}. Please try again.",
+            ),
+            Msg::SizeNotInteger => (
+                "오류: 유효한 정수를 입력하세요. 다시 입력하세요.",
+                "Error: please enter a valid integer.",
+            ),
+            Msg::RowsPrompt => (
+                "{}×{} 관계행렬을 행 단위로 입력하세요.",
+                "Enter the {}×{} relation matrix one row at a time.",
+            ),
+            Msg::RowsHint => (
+                "각 행은 공백으로 구분된 {}개의 숫자(0 또는 1)를 입력하세요.",
+                "Each row is {} space-separated numbers (0 or 1).",
+            ),
+            Msg::RowLabel => ("행 {}: ", "Row {}: "),
+            Msg::RowLength => (
+                "오류: 각 행은 정확히 {}개의 요소를 가져야 합니다. 다시 입력하세요.",
+                "Error: each row must have exactly {} entries. Please try again.",
+            ),
+            Msg::RowNotBinary => (
+                "오류: 관계행렬의 요소는 0 또는 1이어야 합니다. 다시 입력하세요.",
+                "Error: entries of a relation matrix must be 0 or 1. Please try again.",
+            ),
+            Msg::RowNotNumber => (
+                "오류: 숫자가 아닌 값이 입력되었습니다. 다시 입력하세요.",
+                "Error: a non-numeric value was entered. Please try again.",
+            ),
+        }
+    }
+
+    /// 주어진 언어의 문자열
+    pub fn text_in(self, lang: Lang) -> &'static str {
+        let (ko, en) = self.entry();
+        match lang {
+            Lang::Ko => ko,
+            Lang::En => en,
+        }
+    }
+}
+
+/// 현재 언어로 메시지를 반환하는 함수
+pub fn t(msg: Msg) -> &'static str {
+    msg.text_in(lang())
+}
+
+/// 현재 언어로 메시지의 {} 자리를 인자로 차례로 채운 문자열을 반환하는 함수
+pub fn tf(msg: Msg, args: &[&dyn Display]) -> String {
+    tf_in(lang(), msg, args)
+}
+
+/// 주어진 언어로 메시지의 {} 자리를 인자로 차례로 채운 문자열을 반환하는 함수
+pub fn tf_in(lang: Lang, msg: Msg, args: &[&dyn Display]) -> String {
+    let template = msg.text_in(lang);
+    let mut result = String::with_capacity(template.len());
+    let mut args = args.iter();
+    let mut rest = template;
+    while let Some(position) = rest.find("{}") {
+        result.push_str(&rest[..position]);
+        match args.next() {
+            Some(arg) => result.push_str(&arg.to_string()),
+            None => result.push_str("{}"),
+        }
+        rest = &rest[position + 2..];
+    }
+    result.push_str(rest);
+    result
+}
+
+/// 만족 여부를 "✓ 만족" / "✗ 불만족" 형식으로 반환하는 함수
+pub fn mark(ok: bool) -> &'static str {
+    t(if ok {
+        Msg::Satisfied
+    } else {
+        Msg::NotSatisfied
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 카탈로그의 모든 (메시지, 한국어, 영어) 항목을 반환하는 헬퍼 함수
+    fn catalog_entries() -> Vec<(Msg, &'static str, &'static str)> {
+        Msg::ALL
+            .iter()
+            .map(|&msg| {
+                let (ko, en) = msg.entry();
+                (msg, ko, en)
+            })
+            .collect()
+    }
+
+    /// 한글 음절인지 판별하는 헬퍼 함수
+    fn is_hangul(c: char) -> bool {
+        ('\u{AC00}'..='\u{D7A3}').contains(&c)
+    }
+
+    #[test]
+    fn catalog_languages_agree_on_placeholders() {
+        let entries = catalog_entries();
+        assert!(entries.len() > 200);
+        for (msg, ko, en) in entries {
+            assert!(!ko.is_empty() && !en.is_empty(), "{msg:?}");
+            assert_eq!(
+                ko.matches("{}").count(),
+                en.matches("{}").count(),
+                "{msg:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn english_catalog_has_no_hangul() {
+        for (msg, _, en) in catalog_entries() {
+            assert!(!en.chars().any(is_hangul), "{msg:?}: {en}");
+        }
+    }
+
+    #[test]
+    fn all_lists_every_message_in_order() {
+        for (index, &msg) in Msg::ALL.iter().enumerate() {
+            assert_eq!(msg as usize, index, "{msg:?}");
+        }
+        assert_eq!(Msg::ALL.len(), Msg::RowNotNumber as usize + 1);
+    }
+
+    #[test]
+    fn text_in_selects_language() {
+        assert_eq!(Msg::Satisfied.text_in(Lang::Ko), "✓ 만족");
+        assert_eq!(Msg::Satisfied.text_in(Lang::En), "✓ satisfied");
+    }
+
+    #[test]
+    fn english_messages_are_rendered() {
+        assert_eq!(
+            tf_in(Lang::En, Msg::SizeOutOfRange, &[&2, &10]),
+            "Error: the size must be between 2 and 10. Please try again."
+        );
+        assert_eq!(tf_in(Lang::En, Msg::RowLabel, &[&3]), "Row 3: ");
+        assert_eq!(tf_in(Lang::Ko, Msg::RowLabel, &[&3]), "행 3: ");
+        assert_eq!(tf_in(Lang::En, Msg::Satisfied, &[]), "✓ satisfied");
+    }
+
+    #[test]
+    fn language_codes_are_parsed() {
+        assert_eq!(Lang::parse("ko"), Some(Lang::Ko));
+        assert_eq!(Lang::parse(" ko_KR.UTF-8"), Some(Lang::Ko));
+        assert_eq!(Lang::parse("EN"), Some(Lang::En));
+        assert_eq!(Lang::parse("en_US.UTF-8"), Some(Lang::En));
+        assert_eq!(Lang::parse("fr"), None);
+        assert_eq!(Lang::parse(""), None);
+    }
+
+    #[test]
+    fn tf_fills_placeholders_in_order() {
+        // 전역 언어를 바꾸지 않고 두 언어 모두에서 같은 동작을 확인
+        let expected = [
+            Msg::SizeOutOfRange.text_in(Lang::Ko),
+            Msg::SizeOutOfRange.text_in(Lang::En),
+        ];
+        let filled = tf(Msg::SizeOutOfRange, &[&2, &10]);
+        assert!(
+            expected.iter().any(|template| {
+                template.replacen("{}", "2", 1).replacen("{}", "10", 1) == filled
+            })
+        );
+        // 인자가 모자라면 {}를 그대로 남기고, 남는 인자는 무시
+        let partial = tf(Msg::SizeOutOfRange, &[&2]);
+        assert_eq!(partial.matches("{}").count(), 1);
+        assert_eq!(tf(Msg::Satisfied, &[&1]), t(Msg::Satisfied));
+    }
+}
@@ -0,0 +1,500 @@
+/// 관계 행렬의 내부(interior) 연산을 수행하는 모듈 (폐포의 쌍대 연산)
+/// 폐포가 쌍을 추가하여 속성을 만족시키는 것과 반대로, 쌍을 제거하여 속성을 만족하는 가장 큰 부분 관계를 구함
+use crate::Matrix;
+use crate::i18n::{Msg, t, tf};
+
+/// 극대 추이 부분 관계를 모두 나열할 때 허용하는 관계의 최대 순서쌍 개수
+pub const MAX_ENUMERATION_PAIRS: usize = 16;
+
+/// 순서쌍이 제거된 이유
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RemovalReason {
+    /// 역방향 쌍 (j, i)가 없어 대칭성을 만족할 수 없음
+    MissingInverse,
+    /// 대각선 원소 (i, i)라서 비반사성을 위반
+    OnDiagonal,
+    /// 역방향 쌍과 함께 있어 반대칭성을 위반하며, 남겨둔 쌍이 함께 기록됨
+    ConflictsWith(usize, usize),
+    /// 되돌려 넣으면 (a, b), (b, c)는 있지만 (a, c)가 없는 추이성 위반이 생김
+    BreaksTransitivity(usize, usize, usize),
+}
+
+/// 내부 연산의 결과 - 남은 부분 관계와 제거된 쌍 및 그 이유
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Interior {
+    pub matrix: Matrix,
+    pub removed: Vec<((usize, usize), RemovalReason)>,
+}
+
+/// 대칭 내부 계산 - R ∩ R⁻¹, 역방향 쌍이 없는 쌍을 모두 제거한 가장 큰 대칭 부분 관계
+pub fn symmetric_interior(matrix: &Matrix) -> Interior {
+    let mut result = matrix.clone();
+    let mut removed = Vec::new();
+    let n = matrix.len();
+
+    for i in 0..n {
+        for j in 0..n {
+            if matrix[i][j] == 1 && matrix[j][i] != 1 {
+                result[i][j] = 0;
+                removed.push(((i, j), RemovalReason::MissingInverse));
+            }
+        }
+    }
+
+    Interior {
+        matrix: result,
+        removed,
+    }
+}
+
+/// 비반사 내부 계산 - 대각선 요소를 모두 0으로 만든 가장 큰 비반사 부분 관계
+pub fn irreflexive_interior(matrix: &Matrix) -> Interior {
+    let mut result = matrix.clone();
+    let mut removed = Vec::new();
+
+    for i in 0..matrix.len() {
+        if matrix[i][i] == 1 {
+            result[i][i] = 0;
+            removed.push(((i, i), RemovalReason::OnDiagonal));
+        }
+    }
+
+    Interior {
+        matrix: result,
+        removed,
+    }
+}
+
+/// 양방향으로 모두 있는 쌍 {i, j} (i < j)의 목록을 반환하는 헬퍼 함수
+fn symmetric_pairs(matrix: &Matrix) -> Vec<(usize, usize)> {
+    let n = matrix.len();
+    (0..n)
+        .flat_map(|i| ((i + 1)..n).map(move |j| (i, j)))
+        .filter(|&(i, j)| matrix[i][j] == 1 && matrix[j][i] == 1)
+        .collect()
+}
+
+/// 가장 큰 반대칭 부분 관계의 개수를 반환하는 함수
+/// 양방향 쌍마다 어느 한쪽을 제거해야 하므로 2^(양방향 쌍의 수)개이며, 모두 크기가 같음
+/// 양방향 쌍이 128개 이상이면 u128 범위를 넘으므로 None
+pub fn antisymmetric_interior_count(matrix: &Matrix) -> Option<u128> {
+    u32::try_from(symmetric_pairs(matrix).len())
+        .ok()
+        .and_then(|bits| 1u128.checked_shl(bits))
+}
+
+/// 양방향 쌍마다 남길 방향을 정한 반대칭 부분 관계를 만드는 헬퍼 함수
+/// flipped[k]가 false이면 pairs[k] = (i, j)를, true이면 (j, i)를 남김
+fn antisymmetric_choice(matrix: &Matrix, pairs: &[(usize, usize)], flipped: &[bool]) -> Interior {
+    let mut result = matrix.clone();
+    let mut removed = Vec::new();
+    for (&(i, j), &flip) in pairs.iter().zip(flipped) {
+        let (keep, drop) = if flip {
+            ((j, i), (i, j))
+        } else {
+            ((i, j), (j, i))
+        };
+        result[drop.0][drop.1] = 0;
+        removed.push((drop, RemovalReason::ConflictsWith(keep.0, keep.1)));
+    }
+    removed.sort_by_key(|&(pair, _)| pair);
+    Interior {
+        matrix: result,
+        removed,
+    }
+}
+
+/// 가장 큰 반대칭 부분 관계를 최대 limit개까지 나열하는 함수
+/// 첫 번째 결과는 모든 양방향 쌍에서 (i, j) (i < j)를 남기고 (j, i)를 제거한 것
+pub fn antisymmetric_interiors(matrix: &Matrix, limit: usize) -> Vec<Interior> {
+    let pairs = symmetric_pairs(matrix);
+    let mut flipped = vec![false; pairs.len()];
+    let mut results = Vec::new();
+    while results.len() < limit {
+        results.push(antisymmetric_choice(matrix, &pairs, &flipped));
+        // 이진수 1 증가: 앞쪽의 true를 false로 바꾸고 처음 나오는 false를 true로
+        match flipped.iter().position(|&flip| !flip) {
+            Some(first) => {
+                flipped[..first].fill(false);
+                flipped[first] = true;
+            }
+            None => break,
+        }
+    }
+    results
+}
+
+/// 반대칭 내부 계산 - 가장 큰 반대칭 부분 관계 중 (i, j) (i < j)를 남기는 대표 하나
+pub fn antisymmetric_interior(matrix: &Matrix) -> Interior {
+    let pairs = symmetric_pairs(matrix);
+    antisymmetric_choice(matrix, &pairs, &vec![false; pairs.len()])
+}
+
+/// 관계에 쌍 (a, b)를 더했을 때 생기는 추이성 위반 (x, y, z)를 찾는 헬퍼 함수
+fn transitivity_violation_with(
+    matrix: &Matrix,
+    pair: (usize, usize),
+) -> Option<(usize, usize, usize)> {
+    let mut extended = matrix.clone();
+    extended[pair.0][pair.1] = 1;
+    let n = matrix.len();
+    for x in 0..n {
+        for y in 0..n {
+            for z in 0..n {
+                if extended[x][y] == 1 && extended[y][z] == 1 && extended[x][z] != 1 {
+                    return Some((x, y, z));
+                }
+            }
+        }
+    }
+    None
+}
+
+/// 원래 관계와 극대 추이 부분 관계로부터 제거된 쌍과 그 이유를 만드는 헬퍼 함수
+fn transitive_removals(matrix: &Matrix, result: &Matrix) -> Vec<((usize, usize), RemovalReason)> {
+    let n = matrix.len();
+    let mut removed = Vec::new();
+    for i in 0..n {
+        for j in 0..n {
+            if matrix[i][j] == 1 && result[i][j] == 0 {
+                // result가 극대이므로 빠진 쌍을 더하면 항상 추이성이 깨짐
+                let (x, y, z) = transitivity_violation_with(result, (i, j))
+                    .expect("극대 추이 부분 관계에 빠진 쌍을 더하면 추이성이 깨져야 함");
+                removed.push(((i, j), RemovalReason::BreaksTransitivity(x, y, z)));
+            }
+        }
+    }
+    removed
+}
+
+/// 극대 추이 부분 관계 하나를 탐욕적으로 구하는 함수
+/// 빈 관계에서 시작하여 행 우선 순서로 원래 관계의 쌍 p마다 (현재 관계 ∪ {p})의 추이 폐포가
+/// 원래 관계에 포함되면 그 폐포로 키우며, 더 키울 수 없을 때까지 반복하므로 결과는 극대(maximal)
+/// (한 쌍씩만 추가하면 전체 관계처럼 추이적인 상위 관계가 있어도 멈출 수 있음)
+pub fn maximal_transitive_subrelation(matrix: &Matrix) -> Interior {
+    let n = matrix.len();
+    let mut result = vec![vec![0; n]; n];
+    loop {
+        let mut grown = false;
+        for i in 0..n {
+            for j in 0..n {
+                if matrix[i][j] == 0 || result[i][j] == 1 {
+                    continue;
+                }
+                let mut extended = result.clone();
+                extended[i][j] = 1;
+                let closed = super::closure::transitive_closure(&extended);
+                let within = (0..n).all(|x| (0..n).all(|y| closed[x][y] <= matrix[x][y]));
+                if within {
+                    result = closed;
+                    grown = true;
+                }
+            }
+        }
+        if !grown {
+            break;
+        }
+    }
+    let removed = transitive_removals(matrix, &result);
+    Interior {
+        matrix: result,
+        removed,
+    }
+}
+
+/// 모든 극대 추이 부분 관계를 크기가 큰 것부터 나열하는 함수
+/// 관계의 순서쌍 부분집합을 모두 검사하므로 순서쌍이 MAX_ENUMERATION_PAIRS개보다 많으면 None
+pub fn maximal_transitive_subrelations(matrix: &Matrix) -> Option<Vec<Interior>> {
+    let n = matrix.len();
+    let pairs: Vec<(usize, usize)> = (0..n)
+        .flat_map(|i| (0..n).map(move |j| (i, j)))
+        .filter(|&(i, j)| matrix[i][j] == 1)
+        .collect();
+    if pairs.len() > MAX_ENUMERATION_PAIRS {
+        return None;
+    }
+
+    let to_matrix = |mask: u32| -> Matrix {
+        let mut result = vec![vec![0; n]; n];
+        for (bit, &(i, j)) in pairs.iter().enumerate() {
+            if mask & (1 << bit) != 0 {
+                result[i][j] = 1;
+            }
+        }
+        result
+    };
+
+    // 원소 수가 많은 것부터 보면, 추이적 상위 집합은 항상 그보다 먼저 찾은 극대 집합에 포함됨
+    let mut transitive: Vec<u32> = (0..(1u32 << pairs.len()))
+        .filter(|&mask| super::equivalence::is_transitive(&to_matrix(mask)))
+        .collect();
+    transitive.sort_by_key(|&mask| (std::cmp::Reverse(mask.count_ones()), mask));
+    let mut maximal: Vec<u32> = Vec::new();
+    for mask in transitive {
+        if !maximal.iter().any(|&other| other & mask == mask) {
+            maximal.push(mask);
+        }
+    }
+
+    Some(
+        maximal
+            .into_iter()
+            .map(|mask| {
+                let result = to_matrix(mask);
+                let removed = transitive_removals(matrix, &result);
+                Interior {
+                    matrix: result,
+                    removed,
+                }
+            })
+            .collect(),
+    )
+}
+
+/// 제거 이유를 설명 문장으로 변환하는 함수 (1부터 시작하는 번호 사용)
+pub fn describe_removal(pair: (usize, usize), reason: RemovalReason) -> String {
+    let (i, j) = (pair.0 + 1, pair.1 + 1);
+    match reason {
+        RemovalReason::MissingInverse => tf(Msg::RemovalMissingInverse, &[&i, &j, &j, &i]),
+        RemovalReason::OnDiagonal => tf(Msg::RemovalOnDiagonal, &[&i, &j]),
+        RemovalReason::ConflictsWith(a, b) => tf(
+            Msg::RemovalConflict,
+            &[&i, &j, &(a + 1), &(b + 1), &(a + 1), &(b + 1)],
+        ),
+        RemovalReason::BreaksTransitivity(x, y, z) => tf(
+            Msg::RemovalBreaksTransitivity,
+            &[
+                &i,
+                &j,
+                &(x + 1),
+                &(y + 1),
+                &(y + 1),
+                &(z + 1),
+                &(x + 1),
+                &(z + 1),
+            ],
+        ),
+    }
+}
+
+/// 내부 연산 결과를 변환 전/후 행렬과 제거 이유로 출력하는 헬퍼 함수
+fn print_interior(matrix: &Matrix, interior: &Interior, title: &str) {
+    super::visualize::print_matrix(matrix, t(Msg::MatrixBefore));
+    super::visualize::print_matrix(&interior.matrix, &tf(Msg::MatrixAfterClosure, &[&title]));
+
+    if interior.removed.is_empty() {
+        println!("{}", t(Msg::InteriorUnchanged));
+    } else {
+        println!(
+            "{}",
+            tf(Msg::InteriorRemovedCount, &[&interior.removed.len()])
+        );
+        for &(pair, reason) in &interior.removed {
+            println!("  {}", describe_removal(pair, reason));
+        }
+    }
+}
+
+/// 내부 종합 분석 - 네 가지 내부(대칭/비반사/반대칭/추이)를 적용하고 제거된 쌍과 그 이유를 출력
+pub fn perform_interior_analysis(matrix: &Matrix) {
+    println!("\n=== {} ===", t(Msg::InteriorAnalysisTitle));
+
+    let skipped = |property: Msg, interior: Msg| {
+        println!("{}", tf(Msg::ClosureSkipped, &[&t(property), &t(interior)]));
+    };
+
+    println!("\n{}", t(Msg::SymmetricInteriorSection));
+    if super::equivalence::is_symmetric(matrix) {
+        skipped(Msg::Symmetry, Msg::SymmetricInterior);
+    } else {
+        print_interior(
+            matrix,
+            &symmetric_interior(matrix),
+            t(Msg::SymmetricInterior),
+        );
+    }
+
+    println!("\n{}", t(Msg::IrreflexiveInteriorSection));
+    if super::equivalence::is_irreflexive(matrix) {
+        skipped(Msg::Irreflexivity, Msg::IrreflexiveInterior);
+    } else {
+        print_interior(
+            matrix,
+            &irreflexive_interior(matrix),
+            t(Msg::IrreflexiveInterior),
+        );
+    }
+
+    println!("\n{}", t(Msg::AntisymmetricInteriorSection));
+    if super::equivalence::is_antisymmetric(matrix) {
+        skipped(Msg::Antisymmetry, Msg::AntisymmetricInterior);
+    } else {
+        print_interior(
+            matrix,
+            &antisymmetric_interior(matrix),
+            t(Msg::AntisymmetricInterior),
+        );
+        let count = match antisymmetric_interior_count(matrix) {
+            Some(count) => count.to_string(),
+            None => format!("2^{}", symmetric_pairs(matrix).len()),
+        };
+        println!("{}", tf(Msg::AntisymmetricInteriorCount, &[&count]));
+    }
+
+    println!("\n{}", t(Msg::TransitiveInteriorSection));
+    if super::equivalence::is_transitive(matrix) {
+        skipped(Msg::Transitivity, Msg::TransitiveInterior);
+    } else {
+        match maximal_transitive_subrelations(matrix) {
+            Some(all) => {
+                if let Some(largest) = all.first() {
+                    print_interior(matrix, largest, t(Msg::LargestTransitiveSubrelation));
+                }
+                println!("{}", tf(Msg::MaximalTransitiveCount, &[&all.len()]));
+            }
+            None => {
+                print_interior(
+                    matrix,
+                    &maximal_transitive_subrelation(matrix),
+                    t(Msg::MaximalTransitiveSubrelation),
+                );
+                println!(
+                    "{}",
+                    tf(Msg::MaximalTransitiveGreedy, &[&MAX_ENUMERATION_PAIRS])
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::equivalence::{is_antisymmetric, is_irreflexive, is_symmetric, is_transitive};
+
+    /// 0 → 1 → 2 → 0 인 방향 3-사이클
+    fn three_cycle() -> Matrix {
+        vec![vec![0, 1, 0], vec![0, 0, 1], vec![1, 0, 0]]
+    }
+
+    /// sub가 matrix의 부분 관계인지 확인하는 헬퍼 함수
+    fn is_subrelation(sub: &Matrix, matrix: &Matrix) -> bool {
+        sub.iter()
+            .zip(matrix)
+            .all(|(a, b)| a.iter().zip(b).all(|(&x, &y)| x <= y))
+    }
+
+    #[test]
+    fn symmetric_interior_keeps_two_way_pairs() {
+        let matrix = vec![vec![1, 1, 1], vec![1, 0, 0], vec![0, 1, 1]];
+        let interior = symmetric_interior(&matrix);
+        assert_eq!(
+            interior.matrix,
+            vec![vec![1, 1, 0], vec![1, 0, 0], vec![0, 0, 1]]
+        );
+        assert!(is_symmetric(&interior.matrix));
+        assert_eq!(
+            interior.removed,
+            vec![
+                ((0, 2), RemovalReason::MissingInverse),
+                ((2, 1), RemovalReason::MissingInverse)
+            ]
+        );
+        assert!(symmetric_interior(&interior.matrix).removed.is_empty());
+    }
+
+    #[test]
+    fn irreflexive_interior_clears_diagonal() {
+        let matrix = vec![vec![1, 1], vec![0, 1]];
+        let interior = irreflexive_interior(&matrix);
+        assert_eq!(interior.matrix, vec![vec![0, 1], vec![0, 0]]);
+        assert!(is_irreflexive(&interior.matrix));
+        assert_eq!(interior.removed.len(), 2);
+    }
+
+    #[test]
+    fn antisymmetric_interiors_choose_one_direction_per_pair() {
+        let matrix = vec![vec![1, 1, 1], vec![1, 1, 1], vec![0, 1, 1]];
+        assert_eq!(antisymmetric_interior_count(&matrix), Some(4));
+        let all = antisymmetric_interiors(&matrix, usize::MAX);
+        assert_eq!(all.len(), 4);
+        for (k, interior) in all.iter().enumerate() {
+            assert!(is_antisymmetric(&interior.matrix));
+            assert!(is_subrelation(&interior.matrix, &matrix));
+            assert_eq!(interior.removed.len(), 2);
+            assert!(all[..k].iter().all(|other| other.matrix != interior.matrix));
+        }
+        assert_eq!(all[0], antisymmetric_interior(&matrix));
+        assert_eq!(antisymmetric_interiors(&matrix, 3).len(), 3);
+        assert_eq!(
+            antisymmetric_interior(&matrix).removed[0],
+            ((1, 0), RemovalReason::ConflictsWith(0, 1))
+        );
+    }
+
+    #[test]
+    fn greedy_transitive_subrelation_is_maximal() {
+        for matrix in [
+            three_cycle(),
+            vec![vec![1, 1, 0], vec![0, 1, 1], vec![1, 0, 1]],
+        ] {
+            let interior = maximal_transitive_subrelation(&matrix);
+            assert!(is_transitive(&interior.matrix));
+            assert!(is_subrelation(&interior.matrix, &matrix));
+            for &((i, j), reason) in &interior.removed {
+                let RemovalReason::BreaksTransitivity(x, y, z) = reason else {
+                    panic!("추이성 위반이어야 함: {reason:?}");
+                };
+                let mut extended = interior.matrix.clone();
+                extended[i][j] = 1;
+                assert_eq!((extended[x][y], extended[y][z], extended[x][z]), (1, 1, 0));
+            }
+        }
+    }
+
+    #[test]
+    fn three_cycle_has_three_maximal_transitive_subrelations() {
+        let all = maximal_transitive_subrelations(&three_cycle()).unwrap();
+        assert_eq!(all.len(), 3);
+        assert!(all.iter().all(|interior| interior.removed.len() == 2));
+        assert!(all.contains(&maximal_transitive_subrelation(&three_cycle())));
+
+        let full = vec![vec![1; 5]; 5];
+        assert!(maximal_transitive_subrelations(&full).is_none());
+    }
+
+    #[test]
+    fn transitive_relation_is_its_own_interior() {
+        // 한 쌍씩 추가하면 {1, 2} 블록과 3 사이에서 멈추지만 전체 관계가 이미 추이적
+        for n in 1..=5 {
+            let full = vec![vec![1; n]; n];
+            let interior = maximal_transitive_subrelation(&full);
+            assert_eq!(interior.matrix, full);
+            assert!(interior.removed.is_empty());
+        }
+    }
+
+    #[test]
+    fn greedy_result_is_one_of_the_enumerated() {
+        let mut rng = crate::order::SimpleRng::new(11);
+        for _ in 0..50 {
+            let matrix = crate::order::random_relation(&mut rng, 4, 2);
+            let all = maximal_transitive_subrelations(&matrix).unwrap();
+            assert!(
+                all.contains(&maximal_transitive_subrelation(&matrix)),
+                "{matrix:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn removal_descriptions_are_one_based() {
+        let text = describe_removal((0, 2), RemovalReason::MissingInverse);
+        assert!(text.contains('1') && text.contains('3'), "{text}");
+        let text = describe_removal((1, 0), RemovalReason::BreaksTransitivity(4, 5, 6));
+        assert!(
+            ["2", "5", "6", "7"].iter().all(|n| text.contains(n)),
+            "{text}"
+        );
+    }
+}
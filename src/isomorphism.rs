@@ -0,0 +1,597 @@
+/// 관계의 동형(isomorphism) 판별과 정규형(canonical form) 계산을 수행하는 모듈
+/// 색 정제(color refinement)와 개별화(individualization)를 이용하는 nauty 방식 탐색
+use crate::Matrix;
+use crate::i18n::{Msg, t, tf};
+use std::collections::{HashMap, HashSet};
+
+/// 색 정제에서 원소를 구분하는 서명: (현재 색, 자기 루프, (이웃 색, 나가는 간선, 들어오는 간선) 목록)
+type Signature = (usize, u8, Vec<(usize, u8, u8)>);
+
+/// 정점별 색을 정규적인 순서(0부터 시작하는 셀 번호)로 다시 매기는 헬퍼 함수
+/// 서명(signature)의 정렬 순서만 사용하므로 원소 번호와 무관하게 결정됨
+fn rank_by<K: Ord + Clone>(keys: &[K]) -> Vec<usize> {
+    let mut distinct = keys.to_vec();
+    distinct.sort();
+    distinct.dedup();
+    keys.iter()
+        .map(|k| distinct.binary_search(k).unwrap_or_default())
+        .collect()
+}
+
+/// 색 분할을 안정될 때까지 정제하는 함수 (방향 그래프용 1차원 Weisfeiler-Leman)
+/// 각 원소의 새 서명은 (현재 색, 자기 루프, 다른 원소들의 (색, 나가는 간선, 들어오는 간선) 다중집합)
+pub(crate) fn refine(matrix: &Matrix, colors: &[usize]) -> Vec<usize> {
+    let n = matrix.len();
+    let mut current = colors.to_vec();
+    loop {
+        let cell_count = current.iter().collect::<HashSet<_>>().len();
+        let signatures: Vec<Signature> = (0..n)
+            .map(|v| {
+                let mut neighbors: Vec<(usize, u8, u8)> = (0..n)
+                    .filter(|&u| u != v)
+                    .map(|u| (current[u], matrix[v][u], matrix[u][v]))
+                    .collect();
+                neighbors.sort();
+                (current[v], matrix[v][v], neighbors)
+            })
+            .collect();
+        let next = rank_by(&signatures);
+        let next_count = next.iter().collect::<HashSet<_>>().len();
+        current = next;
+        if next_count == cell_count {
+            return current;
+        }
+    }
+}
+
+/// 원소 v를 자신의 셀에서 떼어내 바로 앞의 새 셀로 만든 뒤 다시 정제하는 함수
+pub(crate) fn individualize(matrix: &Matrix, colors: &[usize], v: usize) -> Vec<usize> {
+    let keys: Vec<(usize, bool)> = colors
+        .iter()
+        .enumerate()
+        .map(|(u, &c)| (c, u != v))
+        .collect();
+    refine(matrix, &rank_by(&keys))
+}
+
+/// 초기 색 분할을 자기 루프 여부로 만든 뒤 정제하는 함수
+pub(crate) fn initial_colors(matrix: &Matrix) -> Vec<usize> {
+    let loops: Vec<u8> = (0..matrix.len()).map(|v| matrix[v][v]).collect();
+    refine(matrix, &rank_by(&loops))
+}
+
+/// 크기가 2 이상인 첫 번째 셀(가장 작은 색)의 원소들을 반환하는 함수
+/// 모든 셀이 한 원소뿐이면(이산 분할) None
+pub(crate) fn target_cell(colors: &[usize]) -> Option<Vec<usize>> {
+    let n = colors.len();
+    let mut sizes = vec![0usize; n];
+    for &c in colors {
+        sizes[c] += 1;
+    }
+    let color = (0..n).find(|&c| sizes[c] > 1)?;
+    Some((0..n).filter(|&v| colors[v] == color).collect())
+}
+
+/// 라벨링 label에 따라 원소 i를 label[i] 위치로 옮긴 행렬을 만드는 함수
+pub fn permute_matrix(matrix: &Matrix, label: &[usize]) -> Matrix {
+    let n = matrix.len();
+    let mut result = vec![vec![0; n]; n];
+    for i in 0..n {
+        for j in 0..n {
+            result[label[i]][label[j]] = matrix[i][j];
+        }
+    }
+    result
+}
+
+/// 라벨링의 역순열을 구하는 헬퍼 함수
+pub(crate) fn inverse(label: &[usize]) -> Vec<usize> {
+    let mut inv = vec![0; label.len()];
+    for (i, &l) in label.iter().enumerate() {
+        inv[l] = i;
+    }
+    inv
+}
+
+/// 자기 동형 사상들로 만든 궤도(orbit) 대표원을 구하는 union-find 헬퍼 함수
+pub(crate) fn orbit_representatives(n: usize, generators: &[Vec<usize>]) -> Vec<usize> {
+    fn find(parent: &mut [usize], x: usize) -> usize {
+        let mut root = x;
+        while parent[root] != root {
+            root = parent[root];
+        }
+        let mut cur = x;
+        while parent[cur] != root {
+            let next = parent[cur];
+            parent[cur] = root;
+            cur = next;
+        }
+        root
+    }
+
+    let mut parent: Vec<usize> = (0..n).collect();
+    for g in generators {
+        for (v, &w) in g.iter().enumerate() {
+            let (a, b) = (find(&mut parent, v), find(&mut parent, w));
+            if a != b {
+                parent[a.max(b)] = a.min(b);
+            }
+        }
+    }
+    (0..n).map(|v| find(&mut parent, v)).collect()
+}
+
+/// 정규 라벨링 탐색 상태
+/// 발견된 잎(leaf) 중 사전순으로 가장 작은 행렬을 정규형으로 선택하고,
+/// 같은 행렬을 주는 두 잎에서 얻은 자기 동형 사상으로 탐색 트리를 가지치기
+struct CanonicalSearch<'a> {
+    matrix: &'a Matrix,
+    first: Option<(Matrix, Vec<usize>)>,
+    best: Option<(Matrix, Vec<usize>)>,
+    automorphisms: Vec<Vec<usize>>,
+}
+
+impl CanonicalSearch<'_> {
+    fn search(&mut self, colors: Vec<usize>, prefix: &mut Vec<usize>) {
+        let Some(cell) = target_cell(&colors) else {
+            self.visit_leaf(colors);
+            return;
+        };
+
+        let mut explored: Vec<usize> = Vec::new();
+        for v in cell {
+            // 현재까지의 개별화 원소들을 고정하는 자기 동형 사상만 가지치기에 사용
+            let fixing: Vec<Vec<usize>> = self
+                .automorphisms
+                .iter()
+                .filter(|g| prefix.iter().all(|&p| g[p] == p))
+                .cloned()
+                .collect();
+            let orbit = orbit_representatives(colors.len(), &fixing);
+            if explored.iter().any(|&e| orbit[e] == orbit[v]) {
+                continue;
+            }
+            explored.push(v);
+
+            prefix.push(v);
+            let next = individualize(self.matrix, &colors, v);
+            self.search(next, prefix);
+            prefix.pop();
+        }
+    }
+
+    fn visit_leaf(&mut self, label: Vec<usize>) {
+        let permuted = permute_matrix(self.matrix, &label);
+        for reference in [&self.first, &self.best].into_iter().flatten() {
+            if reference.0 == permuted {
+                // 같은 행렬을 주는 두 라벨링: γ(i) = reference⁻¹(label(i))는 자기 동형 사상
+                let reference_inv = inverse(&reference.1);
+                let automorphism: Vec<usize> = label.iter().map(|&l| reference_inv[l]).collect();
+                if automorphism.iter().enumerate().any(|(i, &g)| i != g) {
+                    self.automorphisms.push(automorphism);
+                }
+                return;
+            }
+        }
+        if self.first.is_none() {
+            self.first = Some((permuted.clone(), label.clone()));
+        }
+        let better = match &self.best {
+            None => true,
+            Some((best, _)) => permuted < *best,
+        };
+        if better {
+            self.best = Some((permuted, label));
+        }
+    }
+}
+
+/// 정규 라벨링(canonical labeling)을 계산하는 함수
+/// 반환값 label은 원소 i를 정규형의 label[i]번째 원소로 옮기며,
+/// 서로 동형인 관계들은 항상 같은 정규형을 가짐
+pub fn canonical_labeling(matrix: &Matrix) -> Vec<usize> {
+    let mut search = CanonicalSearch {
+        matrix,
+        first: None,
+        best: None,
+        automorphisms: Vec::new(),
+    };
+    search.search(initial_colors(matrix), &mut Vec::new());
+    search.best.map(|(_, label)| label).unwrap_or_default()
+}
+
+/// 정규형 행렬(canonical form)을 계산하는 함수
+/// 원소 이름을 바꾼 것만 다른 관계들은 같은 행렬이 되므로 해시 및 중복 제거의 키로 사용 가능
+pub fn canonical_form(matrix: &Matrix) -> Matrix {
+    permute_matrix(matrix, &canonical_labeling(matrix))
+}
+
+/// 두 관계가 동형인지 판별하고 동형 사상을 증거로 반환하는 함수
+/// 반환값 p는 모든 i, j에 대해 R(i,j) = S(p[i], p[j])를 만족하는 순열, 동형이 아니면 None
+pub fn are_isomorphic(r: &Matrix, s: &Matrix) -> Option<Vec<usize>> {
+    if r.len() != s.len() {
+        return None;
+    }
+    let r_label = canonical_labeling(r);
+    let s_label = canonical_labeling(s);
+    if permute_matrix(r, &r_label) != permute_matrix(s, &s_label) {
+        return None;
+    }
+    let s_inv = inverse(&s_label);
+    Some(r_label.iter().map(|&l| s_inv[l]).collect())
+}
+
+/// 여러 관계를 동형류(isomorphism class)별로 묶는 함수
+/// 반환값은 같은 정규형을 갖는 관계들의 인덱스 목록이며, 처음 등장한 순서를 유지
+pub fn isomorphism_classes(relations: &[Matrix]) -> Vec<Vec<usize>> {
+    let mut index: HashMap<Matrix, usize> = HashMap::new();
+    let mut classes: Vec<Vec<usize>> = Vec::new();
+    for (i, relation) in relations.iter().enumerate() {
+        let key = canonical_form(relation);
+        let slot = *index.entry(key).or_insert_with(|| {
+            classes.push(Vec::new());
+            classes.len() - 1
+        });
+        classes[slot].push(i);
+    }
+    classes
+}
+
+/// 관계의 정규 라벨링과 정규형 행렬을 출력하는 함수
+pub fn print_canonical_form(matrix: &Matrix) {
+    println!("\n=== {} ===", t(Msg::CanonicalFormTitle));
+    let label = canonical_labeling(matrix);
+    let mapping = label
+        .iter()
+        .enumerate()
+        .map(|(i, &l)| format!("{}→{}", i + 1, l + 1))
+        .collect::<Vec<String>>()
+        .join(", ");
+    println!("{}", tf(Msg::CanonicalLabeling, &[&mapping]));
+    super::visualize::print_matrix(&permute_matrix(matrix, &label), t(Msg::CanonicalMatrix));
+}
+
+/// 관계의 자기 동형군(automorphism group) 정보
+/// generators: 군을 생성하는 자기 동형 사상들 (g[i]는 원소 i의 상)
+/// order: 군의 위수 (자기 동형 사상의 개수), u128 범위를 넘으면 None
+/// base_orbit_sizes: 기저 원소 b0, b1, ...의 안정자 궤도 크기 (곱이 위수)
+/// orbits: 원소들의 궤도 분할
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AutomorphismGroup {
+    pub generators: Vec<Vec<usize>>,
+    pub order: Option<u128>,
+    pub base_orbit_sizes: Vec<usize>,
+    pub orbits: Vec<Vec<usize>>,
+}
+
+/// 셀 크기들을 색 순서대로 나열하는 헬퍼 함수 (두 분할의 모양 비교용)
+fn cell_sizes(colors: &[usize]) -> Vec<usize> {
+    let mut sizes = vec![0usize; colors.len()];
+    for &c in colors {
+        sizes[c] += 1;
+    }
+    sizes
+}
+
+/// 주어진 분할에서 시작하여 목표 행렬과 같은 행렬을 주는 잎 라벨링을 찾는 함수
+/// shapes는 첫 번째 경로의 각 깊이에서의 셀 크기로, 모양이 다른 가지는 바로 잘라냄
+fn find_matching_leaf(
+    matrix: &Matrix,
+    colors: Vec<usize>,
+    depth: usize,
+    shapes: &[Vec<usize>],
+    target: &Matrix,
+) -> Option<Vec<usize>> {
+    if shapes.get(depth) != Some(&cell_sizes(&colors)) {
+        return None;
+    }
+    let Some(cell) = target_cell(&colors) else {
+        return (permute_matrix(matrix, &colors) == *target).then_some(colors);
+    };
+    cell.into_iter().find_map(|v| {
+        let next = individualize(matrix, &colors, v);
+        find_matching_leaf(matrix, next, depth + 1, shapes, target)
+    })
+}
+
+/// 자기 동형군을 계산하는 함수
+/// 첫 번째 잎까지의 개별화 원소 b0, b1, ...을 기저로 삼아 각 단계의 안정자에서
+/// b_k의 궤도를 정확히 구하고, 궤도-안정자 정리로 위수를 ∏|궤도|로 계산
+pub fn automorphism_group(matrix: &Matrix) -> AutomorphismGroup {
+    let n = matrix.len();
+
+    // 첫 번째 경로: 각 깊이의 분할, 목표 셀, 선택한 기저 원소
+    let mut levels: Vec<(Vec<usize>, Vec<usize>, usize)> = Vec::new();
+    let mut shapes = Vec::new();
+    let mut colors = initial_colors(matrix);
+    while let Some(cell) = target_cell(&colors) {
+        shapes.push(cell_sizes(&colors));
+        let base = cell[0];
+        let next = individualize(matrix, &colors, base);
+        levels.push((colors, cell, base));
+        colors = next;
+    }
+    shapes.push(cell_sizes(&colors));
+    let first_label = colors;
+    let first_inv = inverse(&first_label);
+    let first_matrix = permute_matrix(matrix, &first_label);
+
+    // 깊은 단계부터 처리하여 더 작은 안정자의 생성원을 얕은 단계의 가지치기에 재사용
+    let mut generators: Vec<Vec<usize>> = Vec::new();
+    let mut base_orbit_sizes = Vec::with_capacity(levels.len());
+    for (depth, (level_colors, cell, base)) in levels.iter().enumerate().rev() {
+        let prefix: Vec<usize> = levels[..depth].iter().map(|(_, _, b)| *b).collect();
+        for &c in cell.iter().filter(|&&c| c != *base) {
+            let fixing: Vec<Vec<usize>> = generators
+                .iter()
+                .filter(|g| prefix.iter().all(|&p| g[p] == p))
+                .cloned()
+                .collect();
+            let orbit = orbit_representatives(n, &fixing);
+            if orbit[c] == orbit[*base] {
+                continue;
+            }
+            let next = individualize(matrix, level_colors, c);
+            if let Some(label) = find_matching_leaf(matrix, next, depth + 1, &shapes, &first_matrix)
+            {
+                // γ(i) = first⁻¹(label(i)) 는 기저 앞부분을 고정하고 c를 b_k로 보냄
+                generators.push(label.iter().map(|&l| first_inv[l]).collect());
+            }
+        }
+
+        let fixing: Vec<Vec<usize>> = generators
+            .iter()
+            .filter(|g| prefix.iter().all(|&p| g[p] == p))
+            .cloned()
+            .collect();
+        let orbit = orbit_representatives(n, &fixing);
+        base_orbit_sizes.push((0..n).filter(|&v| orbit[v] == orbit[*base]).count());
+    }
+    base_orbit_sizes.reverse();
+    let order = base_orbit_sizes
+        .iter()
+        .try_fold(1u128, |order, &size| order.checked_mul(size as u128));
+
+    let representatives = orbit_representatives(n, &generators);
+    let mut orbits: Vec<Vec<usize>> = Vec::new();
+    for v in 0..n {
+        if representatives[v] == v {
+            orbits.push((0..n).filter(|&u| representatives[u] == v).collect());
+        }
+    }
+
+    AutomorphismGroup {
+        generators,
+        order,
+        base_orbit_sizes,
+        orbits,
+    }
+}
+
+/// 순열을 순환 표기법 (1 2 3)(4 5) 문자열로 변환하는 함수 (1부터 시작하는 번호 사용)
+pub fn format_cycles(permutation: &[usize]) -> String {
+    let n = permutation.len();
+    let mut seen = vec![false; n];
+    let mut text = String::new();
+    for start in 0..n {
+        if seen[start] || permutation[start] == start {
+            continue;
+        }
+        let mut cycle = Vec::new();
+        let mut current = start;
+        while !seen[current] {
+            seen[current] = true;
+            cycle.push((current + 1).to_string());
+            current = permutation[current];
+        }
+        text.push_str(&format!("({})", cycle.join(" ")));
+    }
+    if text.is_empty() {
+        "()".to_string()
+    } else {
+        text
+    }
+}
+
+/// 자기 동형군의 위수, 생성원, 궤도를 출력하는 함수
+/// 동치 관계일 경우 각 궤도 옆에 같은 행에 동치류를 함께 표시
+pub fn print_automorphism_group(matrix: &Matrix) {
+    println!("\n=== {} ===", t(Msg::AutomorphismTitle));
+
+    let group = automorphism_group(matrix);
+    match group.order {
+        Some(order) => println!("{}", tf(Msg::GroupOrder, &[&order])),
+        None => {
+            let factors: Vec<String> = group
+                .base_orbit_sizes
+                .iter()
+                .map(|size| size.to_string())
+                .collect();
+            println!("{}", tf(Msg::GroupOrderOverflow, &[&factors.join(" × ")]));
+        }
+    }
+    if group.generators.is_empty() {
+        println!("{}", t(Msg::NoGenerators));
+    } else {
+        let generators = group
+            .generators
+            .iter()
+            .map(|g| format_cycles(g))
+            .collect::<Vec<String>>()
+            .join(", ");
+        println!("{}", tf(Msg::Generators, &[&generators]));
+    }
+
+    let orbit_texts: Vec<String> = group
+        .orbits
+        .iter()
+        .map(|orbit| super::order::format_set(orbit))
+        .collect();
+
+    if !super::equivalence::is_equivalence_relation(matrix) {
+        println!("{}", t(Msg::OrbitsHeader));
+        for text in orbit_texts {
+            println!("  {}", text);
+        }
+        return;
+    }
+
+    let class_texts: Vec<String> = super::visualize::equivalence_classes(matrix)
+        .iter()
+        .map(|class| format!("[{}] = {}", class[0] + 1, super::order::format_set(class)))
+        .collect();
+    let width = orbit_texts
+        .iter()
+        .map(|t| t.chars().count())
+        .max()
+        .unwrap_or(0)
+        .max(t(Msg::OrbitColumn).chars().count());
+    println!(
+        "{:<width$}    {}",
+        t(Msg::OrbitColumn),
+        t(Msg::ClassColumn),
+        width = width
+    );
+    for row in 0..orbit_texts.len().max(class_texts.len()) {
+        println!(
+            "{:<width$}    {}",
+            orbit_texts.get(row).map(String::as_str).unwrap_or(""),
+            class_texts.get(row).map(String::as_str).unwrap_or(""),
+            width = width
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::order::{SimpleRng, random_relation};
+
+    /// {0, ..., n-1}의 모든 순열을 나열하는 헬퍼 함수
+    fn permutations(n: usize) -> Vec<Vec<usize>> {
+        if n == 0 {
+            return vec![Vec::new()];
+        }
+        let mut result = Vec::new();
+        for rest in permutations(n - 1) {
+            for slot in 0..n {
+                let mut perm = rest.clone();
+                perm.insert(slot, n - 1);
+                result.push(perm);
+            }
+        }
+        result
+    }
+
+    #[test]
+    fn canonical_form_is_invariant_under_permutation() {
+        for seed in 0..20 {
+            let n = 3 + (seed as usize % 3);
+            let matrix = random_relation(&mut SimpleRng::new(seed), n, 2);
+            let form = canonical_form(&matrix);
+            for perm in permutations(n) {
+                assert_eq!(canonical_form(&permute_matrix(&matrix, &perm)), form);
+            }
+        }
+    }
+
+    #[test]
+    fn canonical_labeling_is_a_permutation() {
+        let matrix = random_relation(&mut SimpleRng::new(7), 6, 2);
+        let mut label = canonical_labeling(&matrix);
+        label.sort_unstable();
+        assert_eq!(label, (0..6).collect::<Vec<usize>>());
+        assert!(canonical_labeling(&Vec::new()).is_empty());
+    }
+
+    #[test]
+    fn isomorphism_witness_maps_relation() {
+        let r = random_relation(&mut SimpleRng::new(3), 5, 2);
+        let s = permute_matrix(&r, &[2, 4, 0, 1, 3]);
+        let p = are_isomorphic(&r, &s).expect("동형이어야 함");
+        for i in 0..5 {
+            for j in 0..5 {
+                assert_eq!(r[i][j], s[p[i]][p[j]]);
+            }
+        }
+    }
+
+    #[test]
+    fn non_isomorphic_relations_are_separated() {
+        // 같은 간선 수의 경로 0 → 1 → 2 와 갈래 0 → 1, 0 → 2
+        let path = vec![vec![0, 1, 0], vec![0, 0, 1], vec![0, 0, 0]];
+        let fork = vec![vec![0, 1, 1], vec![0, 0, 0], vec![0, 0, 0]];
+        let reversed = vec![vec![0, 0, 0], vec![1, 0, 0], vec![0, 1, 0]];
+        assert_eq!(are_isomorphic(&path, &fork), None);
+        assert_eq!(are_isomorphic(&path, &vec![vec![1]]), None);
+        assert_eq!(
+            isomorphism_classes(&[path.clone(), fork, reversed, path]),
+            vec![vec![0, 2, 3], vec![1]]
+        );
+    }
+
+    /// 관계를 보존하는 순열을 모두 세어 자기 동형군의 위수를 구하는 헬퍼 함수
+    fn brute_force_order(matrix: &Matrix) -> u128 {
+        permutations(matrix.len())
+            .iter()
+            .filter(|perm| permute_matrix(matrix, perm) == *matrix)
+            .count() as u128
+    }
+
+    #[test]
+    fn directed_three_cycle_has_rotation_group() {
+        let cycle = vec![vec![0, 1, 0], vec![0, 0, 1], vec![1, 0, 0]];
+        let group = automorphism_group(&cycle);
+        assert_eq!(group.order, Some(3));
+        assert_eq!(group.orbits, vec![vec![0, 1, 2]]);
+        for g in &group.generators {
+            assert_eq!(permute_matrix(&cycle, g), cycle);
+        }
+    }
+
+    #[test]
+    fn group_order_matches_brute_force() {
+        for seed in 0..30 {
+            let matrix = random_relation(&mut SimpleRng::new(seed), 2 + (seed as usize % 4), 2);
+            let group = automorphism_group(&matrix);
+            assert_eq!(group.order, Some(brute_force_order(&matrix)), "{matrix:?}");
+            assert_eq!(
+                group.base_orbit_sizes.iter().product::<usize>() as u128,
+                brute_force_order(&matrix)
+            );
+        }
+    }
+
+    #[test]
+    fn equivalence_relation_group_and_orbits() {
+        // 동치류 {0, 1}, {2, 3}, {4}: 각 동치류 안의 교환과 두 동치류의 교환 → 2 × 2 × 2
+        let classes = [0, 0, 1, 1, 2];
+        let matrix: Matrix = (0..5)
+            .map(|a| (0..5).map(|b| u8::from(classes[a] == classes[b])).collect())
+            .collect();
+        let group = automorphism_group(&matrix);
+        assert_eq!(group.order, Some(8));
+        assert_eq!(group.orbits, vec![vec![0, 1, 2, 3], vec![4]]);
+    }
+
+    #[test]
+    fn large_symmetric_group_order() {
+        // 30개 원소의 반사슬(대각선만): 30! 은 u128 범위 안
+        let identity: Matrix = (0..30)
+            .map(|a| (0..30).map(|b| u8::from(a == b)).collect())
+            .collect();
+        let expected = (1..=30u128).product::<u128>();
+        assert_eq!(automorphism_group(&identity).order, Some(expected));
+        let identity: Matrix = (0..40)
+            .map(|a| (0..40).map(|b| u8::from(a == b)).collect())
+            .collect();
+        assert_eq!(automorphism_group(&identity).order, None);
+    }
+
+    #[test]
+    fn cycles_are_formatted_one_based() {
+        assert_eq!(format_cycles(&[1, 2, 0, 3]), "(1 2 3)");
+        assert_eq!(format_cycles(&[1, 0, 3, 2]), "(1 2)(3 4)");
+        assert_eq!(format_cycles(&[0, 1]), "()");
+    }
+}
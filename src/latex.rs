@@ -0,0 +1,641 @@
+/// 강의 자료용 LaTeX 내보내기 모듈
+/// 행렬(bmatrix), 순서쌍 집합과 동치류(집합 표기), 방향 그래프와 Hasse 도표(TikZ), 속성별 증명 스케치를 생성
+use crate::Matrix;
+use crate::closure::Justification;
+use crate::i18n::{Lang, Msg, lang, t, tf};
+use crate::render::Renderer;
+use crate::report::{MAX_DIMENSION_SIZE, MAX_LATTICE_SIZE, OrderReport, RelationReport};
+
+/// 증명 스케치의 반례 표에 표시하는 최대 행 수
+pub const MAX_PROOF_ROWS: usize = 20;
+
+/// 관계 행렬을 bmatrix 환경으로 변환하는 함수
+pub fn latex_matrix(matrix: &Matrix) -> String {
+    let rows: Vec<String> = matrix
+        .iter()
+        .map(|row| {
+            let cells: Vec<String> = row.iter().map(|v| v.to_string()).collect();
+            format!("  {}", cells.join(" & "))
+        })
+        .collect();
+    format!(
+        "\\begin{{bmatrix}}\n{}\n\\end{{bmatrix}}",
+        rows.join(" \\\\\n")
+    )
+}
+
+/// 원소 집합을 \{1, 2\} 형식으로 변환하는 함수 (1부터 시작하는 번호 사용)
+pub fn latex_set(elements: &[usize]) -> String {
+    let items: Vec<String> = elements.iter().map(|x| (x + 1).to_string()).collect();
+    format!("\\{{{}\\}}", items.join(", "))
+}
+
+/// 순서쌍 목록을 \{(1, 2), (2, 3)\} 형식으로 변환하는 헬퍼 함수
+fn latex_pairs(pairs: &[(usize, usize)]) -> String {
+    let items: Vec<String> = pairs
+        .iter()
+        .map(|&(i, j)| format!("({}, {})", i + 1, j + 1))
+        .collect();
+    format!("\\{{{}\\}}", items.join(", "))
+}
+
+/// 관계를 순서쌍의 집합 R = \{(a, b), ...\}으로 변환하는 함수 (수식 모드 내용)
+pub fn latex_relation_set(matrix: &Matrix, name: &str) -> String {
+    let n = matrix.len();
+    let pairs: Vec<(usize, usize)> = (0..n)
+        .flat_map(|i| (0..n).map(move |j| (i, j)))
+        .filter(|&(i, j)| matrix[i][j] == 1)
+        .collect();
+    format!("{} = {}", name, latex_pairs(&pairs))
+}
+
+/// 동치류와 몫집합을 align* 환경으로 변환하는 함수
+/// 동치 관계가 아니면 None
+pub fn latex_equivalence_classes(matrix: &Matrix) -> Option<String> {
+    if !super::equivalence::is_equivalence_relation(matrix) {
+        return None;
+    }
+    let classes = super::visualize::equivalence_classes(matrix);
+    let mut lines: Vec<String> = classes
+        .iter()
+        .map(|class| format!("  [{}] &= {}", class[0] + 1, latex_set(class)))
+        .collect();
+    let quotient: Vec<String> = classes.iter().map(|class| latex_set(class)).collect();
+    lines.push(format!("  A/R &= \\{{{}\\}}", quotient.join(", ")));
+    Some(format!(
+        "\\begin{{align*}}\n{}\n\\end{{align*}}",
+        lines.join(" \\\\\n")
+    ))
+}
+
+/// 관계의 방향 그래프를 TikZ 그림으로 변환하는 함수
+/// 원소를 원 위에 배치하고, 양방향 쌍은 휜 화살표, R(a,a)는 고리로 표시
+pub fn tikz_digraph(matrix: &Matrix) -> String {
+    let n = matrix.len();
+    let angle = |k: usize| 90.0 - 360.0 * k as f64 / n as f64;
+    let mut out = String::from(
+        "\\begin{tikzpicture}[>=stealth, every node/.style={circle, draw, minimum size=6mm}]\n",
+    );
+    for k in 0..n {
+        out.push_str(&format!(
+            "  \\node (v{}) at ({:.1}:2.5cm) {{{}}};\n",
+            k + 1,
+            angle(k),
+            k + 1
+        ));
+    }
+    for (i, row) in matrix.iter().enumerate() {
+        for (j, &cell) in row.iter().enumerate() {
+            if cell != 1 {
+                continue;
+            }
+            if i == j {
+                out.push_str(&format!(
+                    "  \\draw[->] (v{}) to[out={:.1}, in={:.1}, looseness=6] (v{});\n",
+                    i + 1,
+                    angle(i) + 20.0,
+                    angle(i) - 20.0,
+                    i + 1
+                ));
+            } else if matrix[j][i] == 1 {
+                out.push_str(&format!(
+                    "  \\draw[->] (v{}) to[bend left=15] (v{});\n",
+                    i + 1,
+                    j + 1
+                ));
+            } else {
+                out.push_str(&format!("  \\draw[->] (v{}) -- (v{});\n", i + 1, j + 1));
+            }
+        }
+    }
+    out.push_str("\\end{tikzpicture}");
+    out
+}
+
+/// 부분 순서의 Hasse 도표를 TikZ 그림으로 변환하는 함수
+/// Mirsky 분해의 층을 아래에서 위로 배치하고 덮개 관계만 선으로 연결, 부분 순서가 아니면 None
+pub fn tikz_hasse(matrix: &Matrix) -> Option<String> {
+    if !super::equivalence::is_partial_order(matrix) {
+        return None;
+    }
+    let levels = super::order::mirsky_decomposition(matrix);
+    let mut out =
+        String::from("\\begin{tikzpicture}[every node/.style={circle, draw, minimum size=6mm}]\n");
+    for (level, elements) in levels.iter().enumerate() {
+        let offset = (elements.len() as f64 - 1.0) / 2.0;
+        for (position, &v) in elements.iter().enumerate() {
+            out.push_str(&format!(
+                "  \\node (v{}) at ({:.2}, {:.2}) {{{}}};\n",
+                v + 1,
+                (position as f64 - offset) * 1.5,
+                level as f64 * 1.5,
+                v + 1
+            ));
+        }
+    }
+    for (a, b) in super::order::covering_pairs(matrix) {
+        out.push_str(&format!("  \\draw (v{}) -- (v{});\n", a + 1, b + 1));
+    }
+    out.push_str("\\end{tikzpicture}");
+    Some(out)
+}
+
+/// 속성 하나의 증명 스케치에 필요한 정보
+/// statement: 속성의 정의 (수식), header: 반례 표의 열 머리글, rows: 반례 (수식 조각),
+/// omitted: 분석 단계에서 이미 잘려 rows에 없는 반례의 개수
+struct ProofSketch {
+    name: Msg,
+    statement: &'static str,
+    header: Vec<&'static str>,
+    rows: Vec<Vec<String>>,
+    omitted: usize,
+}
+
+/// 여섯 가지 속성 각각의 정의와 반례를 모으는 헬퍼 함수
+fn proof_sketches(matrix: &Matrix) -> Vec<ProofSketch> {
+    let pair = |a: usize, b: usize| format!("({}, {})", a + 1, b + 1);
+    let violations = super::report::find_violations(matrix);
+
+    vec![
+        ProofSketch {
+            name: Msg::Reflexivity,
+            statement: "\\forall a \\in A,\\ (a, a) \\in R",
+            header: vec!["(a, a) \\notin R"],
+            rows: violations
+                .reflexive
+                .iter()
+                .map(|&a| vec![pair(a, a)])
+                .collect(),
+            omitted: 0,
+        },
+        ProofSketch {
+            name: Msg::Symmetry,
+            statement: "\\forall a, b \\in A,\\ (a, b) \\in R \\Rightarrow (b, a) \\in R",
+            header: vec!["(a, b) \\in R", "(b, a) \\notin R"],
+            rows: violations
+                .symmetric
+                .iter()
+                .filter(|&&(a, b)| matrix[a][b] == 1)
+                .map(|&(a, b)| vec![pair(a, b), pair(b, a)])
+                .collect(),
+            omitted: 0,
+        },
+        ProofSketch {
+            name: Msg::Transitivity,
+            statement: "\\forall a, b, c \\in A,\\ (a, b) \\in R \\land (b, c) \\in R \\Rightarrow (a, c) \\in R",
+            header: vec!["(a, b) \\in R", "(b, c) \\in R", "(a, c) \\notin R"],
+            rows: violations
+                .transitive
+                .iter()
+                .map(|&(a, b, c)| vec![pair(a, b), pair(b, c), pair(a, c)])
+                .collect(),
+            omitted: violations.transitive_count - violations.transitive.len(),
+        },
+        ProofSketch {
+            name: Msg::Antisymmetry,
+            statement: "\\forall a, b \\in A,\\ (a, b) \\in R \\land (b, a) \\in R \\Rightarrow a = b",
+            header: vec!["(a, b) \\in R", "(b, a) \\in R", "a \\neq b"],
+            rows: violations
+                .antisymmetric
+                .iter()
+                .map(|&(a, b)| vec![pair(a, b), pair(b, a), format!("{} \\neq {}", a + 1, b + 1)])
+                .collect(),
+            omitted: 0,
+        },
+        ProofSketch {
+            name: Msg::Irreflexivity,
+            statement: "\\forall a \\in A,\\ (a, a) \\notin R",
+            header: vec!["(a, a) \\in R"],
+            rows: violations
+                .irreflexive
+                .iter()
+                .map(|&a| vec![pair(a, a)])
+                .collect(),
+            omitted: 0,
+        },
+        ProofSketch {
+            name: Msg::Connectedness,
+            statement: "\\forall a \\neq b \\in A,\\ (a, b) \\in R \\lor (b, a) \\in R",
+            header: vec!["(a, b) \\notin R", "(b, a) \\notin R"],
+            rows: violations
+                .connected
+                .iter()
+                .map(|&(a, b)| vec![pair(a, b), pair(b, a)])
+                .collect(),
+            omitted: 0,
+        },
+    ]
+}
+
+/// 각 속성의 증명 스케치를 LaTeX로 변환하는 함수
+/// 성립하면 정의와 함께 모든 경우를 확인했음을, 성립하지 않으면 반례 표(최대 MAX_PROOF_ROWS행)를 출력
+pub fn latex_property_proofs(matrix: &Matrix) -> String {
+    let mut out = String::new();
+    for sketch in proof_sketches(matrix) {
+        out.push_str(&format!(
+            "\\paragraph{{{}}} $ {} $\n\n",
+            t(sketch.name),
+            sketch.statement
+        ));
+        if sketch.rows.is_empty() {
+            out.push_str(&format!("{}\n\n", t(Msg::ProofHolds)));
+            continue;
+        }
+        out.push_str(&format!("{}\n\n", t(Msg::ProofFails)));
+        out.push_str(&format!(
+            "\\begin{{tabular}}{{{}}}\n\\hline\n",
+            "|c".repeat(sketch.header.len()) + "|"
+        ));
+        let header: Vec<String> = sketch.header.iter().map(|h| format!("$ {} $", h)).collect();
+        out.push_str(&format!("{} \\\\\n\\hline\n", header.join(" & ")));
+        for row in sketch.rows.iter().take(MAX_PROOF_ROWS) {
+            let cells: Vec<String> = row.iter().map(|c| format!("$ {} $", c)).collect();
+            out.push_str(&format!("{} \\\\\n", cells.join(" & ")));
+        }
+        out.push_str("\\hline\n\\end{tabular}\n\n");
+        let more = sketch.rows.len().saturating_sub(MAX_PROOF_ROWS) + sketch.omitted;
+        if more > 0 {
+            out.push_str(&format!("{}\n\n", tf(Msg::ProofMoreRows, &[&more])));
+        }
+    }
+    out
+}
+
+/// 추가된 쌍의 근거를 수식으로 변환하는 헬퍼 함수
+/// 대각선은 Δ_A, 대칭은 역쌍, 경로는 → (방향 무시 경로는 ∼)로 표시
+fn latex_justification(justification: &Justification) -> String {
+    let path = |path: &[usize], separator: &str| -> String {
+        let items: Vec<String> = path.iter().map(|x| (x + 1).to_string()).collect();
+        items.join(separator)
+    };
+    match justification {
+        Justification::Reflexive => "\\Delta_A".to_string(),
+        Justification::SymmetricOf(j, i) => format!("({}, {})^{{-1}}", j + 1, i + 1),
+        Justification::Path(p) => path(p, " \\to "),
+        Justification::UndirectedPath(p) => path(p, " \\sim "),
+    }
+}
+
+/// 선형 순서(사슬)를 $ 1 < 2 < 3 $ 형식의 수식으로 변환하는 헬퍼 함수
+fn latex_chain(chain: &[usize]) -> String {
+    let items: Vec<String> = chain.iter().map(|x| (x + 1).to_string()).collect();
+    format!("$ {} $", items.join(" < "))
+}
+
+/// 메시지 문장 안의 유니코드 수학 기호를 LaTeX 수식으로 바꾸는 헬퍼 함수
+fn latex_text(text: &str) -> String {
+    [
+        ('≤', "$\\le$"),
+        ('≥', "$\\ge$"),
+        ('≠', "$\\neq$"),
+        ('∧', "$\\wedge$"),
+        ('∨', "$\\vee$"),
+    ]
+    .iter()
+    .fold(text.to_string(), |text, (symbol, command)| {
+        text.replace(*symbol, command)
+    })
+}
+
+/// 부분 순서의 구조(극소/극대, 높이/폭, 사슬 분해, 차원)와 격자 판별을 LaTeX로 변환하는 함수
+pub fn latex_order_structure(order: &OrderReport) -> String {
+    let set = |elements: &[usize]| format!("$ {} $", latex_set(elements));
+    let element =
+        |x: Option<usize>| x.map_or(t(Msg::ReportNone).to_string(), |x| format!("$ {} $", x + 1));
+    let mut items = vec![
+        tf(Msg::MinimalElements, &[&set(&order.minimal)]),
+        tf(Msg::MaximalElements, &[&set(&order.maximal)]),
+        tf(Msg::LeastElement, &[&element(order.least)]),
+        tf(Msg::GreatestElement, &[&element(order.greatest)]),
+        tf(
+            Msg::PosetHeight,
+            &[
+                &order.longest_chain.len(),
+                &latex_chain(&order.longest_chain),
+            ],
+        ),
+        tf(
+            Msg::PosetWidth,
+            &[
+                &order.maximum_antichain.len(),
+                &set(&order.maximum_antichain),
+            ],
+        ),
+        format!(
+            "{} {}",
+            t(Msg::DilworthHeader),
+            order
+                .chain_cover
+                .iter()
+                .map(|chain| latex_chain(chain))
+                .collect::<Vec<String>>()
+                .join(", ")
+        ),
+        format!(
+            "{} {}",
+            t(Msg::MirskyHeader),
+            order
+                .antichain_partition
+                .iter()
+                .map(|antichain| set(antichain))
+                .collect::<Vec<String>>()
+                .join(", ")
+        ),
+    ];
+    match &order.dimension {
+        Some(dimension) => {
+            items.push(match &dimension.realizer {
+                Some((first, second)) => format!(
+                    "{} $L_1$: {}, $L_2$: {}",
+                    latex_text(t(Msg::DimensionAtMostTwo)),
+                    latex_chain(first),
+                    latex_chain(second)
+                ),
+                None => latex_text(t(Msg::DimensionAtLeastThree)),
+            });
+            items.push(match &dimension.chain_product {
+                Some(sizes) => {
+                    let sizes: Vec<String> = sizes.iter().map(|s| s.to_string()).collect();
+                    tf(
+                        Msg::ChainProduct,
+                        &[&format!("$ {} $", sizes.join(" \\times "))],
+                    )
+                }
+                None => t(Msg::NotChainProduct).to_string(),
+            });
+        }
+        None => items.push(tf(Msg::ReportDimensionSkipped, &[&MAX_DIMENSION_SIZE])),
+    }
+
+    let mut out = String::from("\\begin{itemize}\n");
+    for item in items {
+        out.push_str(&format!("  \\item {}\n", item));
+    }
+    out.push_str("\\end{itemize}\n\n");
+
+    out.push_str(&format!("\\subsection*{{{}}}\n\n", t(Msg::LatticeTitle)));
+    let lattice = match &order.lattice {
+        Some(lattice) if lattice.lattice => lattice,
+        Some(_) => {
+            out.push_str(&format!("{}\n\n", t(Msg::NotLattice)));
+            return out;
+        }
+        None => {
+            out.push_str(&format!(
+                "{}\n\n",
+                tf(Msg::ReportLatticeSkipped, &[&MAX_LATTICE_SIZE])
+            ));
+            return out;
+        }
+    };
+    let plain = |ok: bool| {
+        t(if ok {
+            Msg::SatisfiedPlain
+        } else {
+            Msg::NotSatisfiedPlain
+        })
+    };
+    out.push_str("\\begin{itemize}\n");
+    out.push_str(&format!(
+        "  \\item {}: {}\n",
+        t(Msg::BoundedLattice),
+        plain(lattice.bounded)
+    ));
+    out.push_str(&format!(
+        "  \\item {}: {}\n",
+        t(Msg::DistributiveLattice),
+        plain(lattice.distributive())
+    ));
+    if let Some((a, b, c)) = lattice.distributive_counterexample {
+        out.push_str(&format!(
+            "  \\item[] {}\n",
+            latex_text(
+                tf(
+                    Msg::DistributiveCounterexample,
+                    &[&(a + 1), &(b + 1), &(c + 1)]
+                )
+                .trim()
+            )
+        ));
+    }
+    out.push_str(&format!(
+        "  \\item {}: {}\n",
+        t(Msg::ModularLattice),
+        plain(lattice.modular())
+    ));
+    if let Some((a, x, b)) = lattice.modular_counterexample {
+        out.push_str(&format!(
+            "  \\item[] {}\n",
+            latex_text(tf(Msg::ModularCounterexample, &[&(a + 1), &(b + 1), &(x + 1)]).trim())
+        ));
+    }
+    out.push_str(&format!(
+        "  \\item {}: {}\n",
+        t(Msg::ComplementedLattice),
+        plain(lattice.complemented)
+    ));
+    out.push_str("\\end{itemize}\n\n");
+    out
+}
+
+/// 분석 보고서 전체를 독립적으로 컴파일 가능한 LaTeX 문서로 출력
+/// 한국어 출력일 때는 kotex 패키지를 사용
+pub struct LatexRenderer;
+
+impl Renderer for LatexRenderer {
+    fn render(&self, report: &RelationReport) -> String {
+        let matrix = &report.matrix;
+        let mut out = String::from("\\documentclass{article}\n\\usepackage{amsmath, amssymb}\n");
+        if lang() == Lang::Ko {
+            out.push_str("\\usepackage{kotex}\n");
+        }
+        out.push_str("\\usepackage{tikz}\n\\begin{document}\n\n");
+        out.push_str(&format!("\\section*{{{}}}\n\n", t(Msg::ReportTitle)));
+
+        out.push_str(&format!("\\subsection*{{{}}}\n\n", t(Msg::ReportMatrix)));
+        out.push_str(&format!("\\[\nM_R = {}\n\\]\n\n", latex_matrix(matrix)));
+        out.push_str(&format!(
+            "\\[\n{}\n\\]\n\n",
+            latex_relation_set(matrix, "R")
+        ));
+
+        out.push_str(&format!("\\subsection*{{{}}}\n\n", t(Msg::ProofTitle)));
+        out.push_str(&latex_property_proofs(matrix));
+
+        out.push_str(&format!(
+            "\\subsection*{{{}}}\n\n",
+            t(Msg::ReportClassification)
+        ));
+        if report.classifications.is_empty() {
+            out.push_str(&format!("{}\n\n", t(Msg::ReportNone)));
+        } else {
+            out.push_str("\\begin{itemize}\n");
+            for class in &report.classifications {
+                out.push_str(&format!("  \\item {}\n", class.label()));
+            }
+            out.push_str("\\end{itemize}\n\n");
+        }
+
+        out.push_str(&format!(
+            "\\subsection*{{{}}}\n\n",
+            t(Msg::EquivalenceClassesTitle)
+        ));
+        match latex_equivalence_classes(matrix) {
+            Some(classes) => out.push_str(&format!("{}\n\n", classes)),
+            None => out.push_str(&format!("{}\n\n", t(Msg::IsNotEquivalence))),
+        }
+
+        out.push_str(&format!("\\subsection*{{{}}}\n\n", t(Msg::DigraphTitle)));
+        out.push_str(&format!(
+            "\\begin{{center}}\n{}\n\\end{{center}}\n\n",
+            tikz_digraph(matrix)
+        ));
+
+        if let Some(hasse) = tikz_hasse(matrix) {
+            out.push_str(&format!("\\subsection*{{{}}}\n\n", t(Msg::HasseTitle)));
+            out.push_str(&format!(
+                "\\begin{{center}}\n{}\n\\end{{center}}\n\n",
+                hasse
+            ));
+        }
+
+        out.push_str(&format!("\\subsection*{{{}}}\n\n", t(Msg::ReportClosures)));
+        for closure in &report.closures {
+            out.push_str(&format!(
+                "\\paragraph{{{}}} {}\n\n",
+                closure.kind.label(),
+                tf(Msg::ReportAddedCount, &[&closure.trace.added.len()])
+            ));
+            out.push_str(&format!(
+                "\\[\n{}\n\\]\n\n",
+                latex_matrix(&closure.trace.matrix)
+            ));
+            if !closure.trace.added.is_empty() {
+                out.push_str("\\begin{tabular}{|c|c|}\n\\hline\n");
+                out.push_str(&format!(
+                    "{} & {} \\\\\n\\hline\n",
+                    t(Msg::JustificationPair),
+                    t(Msg::JustificationReason)
+                ));
+                for ((i, j), justification) in &closure.trace.added {
+                    out.push_str(&format!(
+                        "$ ({}, {}) $ & $ {} $ \\\\\n",
+                        i + 1,
+                        j + 1,
+                        latex_justification(justification)
+                    ));
+                }
+                out.push_str("\\hline\n\\end{tabular}\n\n");
+            }
+        }
+
+        if let Some(order) = &report.order {
+            out.push_str(&format!("\\subsection*{{{}}}\n\n", t(Msg::ReportOrder)));
+            out.push_str(&latex_order_structure(order));
+        }
+
+        out.push_str("\\end{document}\n");
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::report::analyze_relation;
+
+    /// 1 ≤ 2, 1 ≤ 3, 2 ≤ 4, 3 ≤ 4 인 다이아몬드 부분 순서
+    fn diamond() -> Matrix {
+        vec![
+            vec![1, 1, 1, 1],
+            vec![0, 1, 0, 1],
+            vec![0, 0, 1, 1],
+            vec![0, 0, 0, 1],
+        ]
+    }
+
+    /// 중괄호와 \begin/\end 환경의 짝이 맞는지 확인하는 헬퍼 함수
+    fn assert_balanced(latex: &str) {
+        let mut depth = 0i64;
+        let mut previous = ' ';
+        for c in latex.chars() {
+            if previous != '\\' {
+                match c {
+                    '{' => depth += 1,
+                    '}' => depth -= 1,
+                    _ => {}
+                }
+            }
+            assert!(depth >= 0, "닫는 중괄호가 먼저 나옴");
+            previous = if previous == '\\' { ' ' } else { c };
+        }
+        assert_eq!(depth, 0);
+        assert_eq!(
+            latex.matches("\\begin{").count(),
+            latex.matches("\\end{").count()
+        );
+    }
+
+    #[test]
+    fn matrices_and_sets() {
+        assert_eq!(
+            latex_matrix(&vec![vec![1, 0], vec![0, 1]]),
+            "\\begin{bmatrix}\n  1 & 0 \\\\\n  0 & 1\n\\end{bmatrix}"
+        );
+        assert_eq!(latex_set(&[0, 2]), "\\{1, 3\\}");
+        assert_eq!(
+            latex_relation_set(&vec![vec![0, 1], vec![0, 0]], "R"),
+            "R = \\{(1, 2)\\}"
+        );
+    }
+
+    #[test]
+    fn equivalence_classes_as_quotient_set() {
+        let matrix = vec![vec![1, 0, 1], vec![0, 1, 0], vec![1, 0, 1]];
+        let classes = latex_equivalence_classes(&matrix).unwrap();
+        assert!(classes.contains("[1] &= \\{1, 3\\}"));
+        assert!(classes.contains("[2] &= \\{2\\}"));
+        assert!(classes.contains("A/R &= \\{\\{1, 3\\}, \\{2\\}\\}"));
+        assert_eq!(latex_equivalence_classes(&diamond()), None);
+    }
+
+    #[test]
+    fn tikz_pictures_draw_every_edge() {
+        // 1 ↔ 2 (휜 화살표 2개), 2 → 3, 3의 고리
+        let matrix = vec![vec![0, 1, 0], vec![1, 0, 1], vec![0, 0, 1]];
+        let digraph = tikz_digraph(&matrix);
+        assert_eq!(digraph.matches("\\node").count(), 3);
+        assert_eq!(digraph.matches("bend left").count(), 2);
+        assert_eq!(digraph.matches("looseness").count(), 1);
+        assert!(digraph.contains("(v2) -- (v3)"));
+
+        let hasse = tikz_hasse(&diamond()).unwrap();
+        assert_eq!(hasse.matches("\\draw").count(), 4);
+        assert!(!hasse.contains("(v1) -- (v4)"));
+        assert_eq!(tikz_hasse(&matrix), None);
+    }
+
+    #[test]
+    fn order_structure_uses_math_symbols() {
+        let order = crate::report::analyze_order(&diamond()).unwrap();
+        let latex = latex_order_structure(&order);
+        assert_balanced(&latex);
+        assert!(!latex.contains(['≤', '≥', '≠', '∧', '∨']));
+        assert!(latex.contains("$ 1 < 2 < 4 $") || latex.contains("$ 1 < 3 < 4 $"));
+        assert_eq!(latex_text("a ≤ b ∧ c"), "a $\\le$ b $\\wedge$ c");
+    }
+
+    #[test]
+    fn documents_are_balanced() {
+        for matrix in [
+            diamond(),
+            vec![vec![1, 1], vec![1, 1]],
+            vec![vec![0, 1, 0], vec![0, 0, 1], vec![1, 0, 0]],
+        ] {
+            let document = LatexRenderer.render(&analyze_relation(&matrix));
+            assert!(document.starts_with("\\documentclass{article}"));
+            assert!(document.trim_end().ends_with("\\end{document}"));
+            assert_balanced(&document);
+        }
+    }
+}
@@ -1,6 +1,27 @@
 /// 라이브러리 진입점 및 공통 타입 정의 모듈
 pub type Matrix = Vec<Vec<u8>>;
 
+pub mod capi;
 pub mod closure;
 pub mod equivalence;
+pub mod html_report;
+pub mod i18n;
+pub mod interior;
+pub mod isomorphism;
+pub mod latex;
+pub mod nearest;
+pub mod order;
+pub mod parse;
+pub mod partition;
+#[cfg(feature = "python")]
+pub mod python;
+pub mod quotient;
+pub mod render;
+pub mod repl;
+pub mod report;
+pub mod rules;
+pub mod terminal;
+pub mod tui;
 pub mod visualize;
+pub mod warshall;
+pub mod wasm;
@@ -1,25 +1,199 @@
 /// 애플리케이션의 메인 진입점 및 사용자 인터페이스 모듈
 use std::io::{self, Write};
+use std::path::PathBuf;
 
-use discrete_mathematics_equivalence::closure::perform_closure_analysis;
+use discrete_mathematics_equivalence::closure::{equivalence_closure, perform_closure_analysis};
 use discrete_mathematics_equivalence::{
     Matrix,
-    equivalence::print_equivalence_result,
+    equivalence::{is_transitive, print_equivalence_result, print_row_kernel},
+    html_report::InteractiveHtmlRenderer,
+    i18n::{Lang, Msg, set_lang, t, tf},
+    interior::perform_interior_analysis,
+    isomorphism::{print_automorphism_group, print_canonical_form},
+    order::{is_acyclic, print_topological_analysis},
+    parse::parse_relation,
+    partition::print_partition_comparison,
+    quotient::{mutual_reachability, print_quotient_analysis},
+    render::{Renderer, TerminalRenderer, renderer_for},
+    repl::run_repl,
+    report::analyze_relation,
+    rules::print_rule_closure_analysis,
+    terminal::{ColorMode, print_violation_highlight, set_color_mode, violation_cells},
+    tui::run_tui,
     visualize::{
         analyze_individual_properties, analyze_relationship_properties,
         demonstrate_equivalence_classes, print_matrix, print_text_visualization,
     },
+    warshall::{FrameFormat, export_frames, print_warshall_steps},
 };
 
+/// 명령행 옵션
+/// --step: Warshall 단계마다 Enter 입력을 기다림
+/// --frames <디렉터리>: Warshall 단계를 프레임 파일로 저장 (--svg를 함께 주면 SVG, 아니면 텍스트)
+/// --format <terminal|markdown|html|json|latex>: 대화형 분석 대신 분석 보고서 하나를 해당 형식으로 출력
+///   (보고서 모드에서는 행렬 대신 {(1, 2)} on 3 같은 관계 표기를 한 줄로 입력할 수도 있음)
+/// --output <파일>: 보고서를 파일로 저장 (--format이 없으면 확장자 .md/.html/.json/.tex로 형식 결정)
+/// repl: 여러 관계를 이름으로 정의하고 명령으로 분석하는 대화형 셸 실행 (행렬 입력 없이 시작)
+/// tui: 행렬 입력 후 커서로 칸을 토글하며 속성을 실시간으로 확인하는 터미널 UI 실행
+/// report --html <파일>: 행렬/그래프 강조, 접이식 반례, 폐포 전후 비교를 담은 단일 HTML 보고서 저장
+/// --color <auto|always|never>: 행렬 출력의 ANSI 색상 (auto는 표준 출력이 터미널일 때만 사용)
+/// --highlight <속성>: 입력 행렬에서 해당 속성(reflexive, symmetric, transitive, ...)을 위반하는 칸을 강조
+/// --lang <ko|en>: 출력 언어 (생략시 LC_ALL/LC_MESSAGES/LANG 환경 변수, 없으면 한국어)
+struct Options {
+    step: bool,
+    frames: Option<PathBuf>,
+    frame_format: FrameFormat,
+    renderer: Option<Box<dyn Renderer>>,
+    output: Option<PathBuf>,
+    highlight: Option<String>,
+    tui: bool,
+    repl: bool,
+}
+
+/// 명령행 인자를 해석하는 함수
+/// --lang은 바로 적용되므로 이후의 오류 메시지도 선택한 언어로 출력됨
+fn parse_options() -> Result<Options, String> {
+    if let Some(lang) = Lang::from_env() {
+        set_lang(lang);
+    }
+
+    let mut options = Options {
+        step: false,
+        frames: None,
+        frame_format: FrameFormat::Text,
+        renderer: None,
+        output: None,
+        highlight: None,
+        tui: false,
+        repl: false,
+    };
+    let mut report = false;
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "report" => report = true,
+            "tui" => options.tui = true,
+            "repl" => options.repl = true,
+            "--html" => match args.next() {
+                Some(path) => {
+                    options.renderer = Some(Box::new(InteractiveHtmlRenderer));
+                    options.output = Some(PathBuf::from(path));
+                }
+                None => return Err(t(Msg::MissingReportHtml).to_string()),
+            },
+            "--step" => options.step = true,
+            "--svg" => options.frame_format = FrameFormat::Svg,
+            "--frames" => match args.next() {
+                Some(dir) => options.frames = Some(PathBuf::from(dir)),
+                None => return Err(t(Msg::MissingFramesDir).to_string()),
+            },
+            "--format" => match args.next() {
+                Some(format) => match renderer_for(&format) {
+                    Some(renderer) => options.renderer = Some(renderer),
+                    None => return Err(tf(Msg::UnknownFormat, &[&format])),
+                },
+                None => return Err(t(Msg::MissingFormat).to_string()),
+            },
+            "--output" => match args.next() {
+                Some(path) => options.output = Some(PathBuf::from(path)),
+                None => return Err(t(Msg::MissingOutput).to_string()),
+            },
+            "--color" => match args.next() {
+                Some(name) => match ColorMode::parse(&name) {
+                    Some(mode) => set_color_mode(mode),
+                    None => return Err(tf(Msg::UnknownColor, &[&name])),
+                },
+                None => return Err(t(Msg::MissingColor).to_string()),
+            },
+            "--highlight" => match args.next() {
+                Some(key) if violation_cells(&Vec::new(), &key).is_some() => {
+                    options.highlight = Some(key)
+                }
+                Some(key) => return Err(tf(Msg::UnknownHighlight, &[&key])),
+                None => return Err(t(Msg::MissingHighlight).to_string()),
+            },
+            "--lang" => match args.next() {
+                Some(code) => match Lang::parse(&code) {
+                    Some(lang) => set_lang(lang),
+                    None => return Err(tf(Msg::UnknownLang, &[&code])),
+                },
+                None => return Err(t(Msg::MissingLang).to_string()),
+            },
+            _ => return Err(tf(Msg::UnknownOption, &[&arg])),
+        }
+    }
+    if report && options.renderer.is_none() {
+        return Err(t(Msg::MissingReportHtml).to_string());
+    }
+    if options.renderer.is_none()
+        && let Some(path) = &options.output
+    {
+        let extension = path.extension().and_then(|e| e.to_str()).unwrap_or("");
+        options.renderer =
+            Some(renderer_for(extension).unwrap_or_else(|| Box::new(TerminalRenderer)));
+    }
+    Ok(options)
+}
+
+/// 분석 보고서를 선택한 형식으로 만들어 파일 또는 표준 출력에 쓰는 함수
+fn write_report(matrix: &Matrix, renderer: &dyn Renderer, output: Option<&PathBuf>) {
+    let document = renderer.render(&analyze_relation(matrix));
+    match output {
+        Some(path) => match std::fs::write(path, document) {
+            Ok(()) => eprintln!("{}", tf(Msg::ReportSaved, &[&path.display()])),
+            Err(e) => eprintln!("{}", tf(Msg::ErrorPrefix, &[&tf(Msg::ReportFailed, &[&e])])),
+        },
+        None => print!("{}", document),
+    }
+}
+
 /// 프로그램의 메인 함수
 /// 관계 행렬 입력, 동치 관계 판별, 시각화, 폐포 분석을 순차적으로 수행
 fn main() {
-    println!("=== 관계행렬 입력 및 동치 관계 판별 ===\n");
+    let options = match parse_options() {
+        Ok(options) => options,
+        Err(e) => {
+            eprintln!("{}", tf(Msg::ErrorPrefix, &[&e]));
+            return;
+        }
+    };
+
+    if options.repl {
+        run_repl();
+        return;
+    }
+
+    // 보고서 모드에서는 표준 출력에 보고서만 남도록 안내 문구를 표준 오류로 출력
+    let mut prompt: Box<dyn Write> = if options.renderer.is_some() {
+        Box::new(io::stderr())
+    } else {
+        Box::new(io::stdout())
+    };
+    writeln!(prompt, "{}\n", t(Msg::Banner)).unwrap();
 
     // 동적 크기의 관계행렬 입력받기 (2×2 ~ 10×10)
-    match read_relation_matrix() {
+    match read_relation_matrix(prompt.as_mut(), options.renderer.is_some()) {
         Ok(matrix) => {
-            print_matrix(&matrix, "입력된 관계행렬");
+            if let Some(renderer) = &options.renderer {
+                write_report(&matrix, renderer.as_ref(), options.output.as_ref());
+                return;
+            }
+
+            if options.tui {
+                match run_tui(&matrix) {
+                    Ok(edited) => {
+                        print_matrix(&edited, t(Msg::TuiFinalMatrix));
+                        print_equivalence_result(&edited);
+                    }
+                    Err(e) => eprintln!("{}", tf(Msg::ErrorPrefix, &[&e])),
+                }
+                return;
+            }
+
+            match &options.highlight {
+                Some(key) => print_violation_highlight(&matrix, key, t(Msg::InputMatrixTitle)),
+                None => print_matrix(&matrix, t(Msg::InputMatrixTitle)),
+            }
 
             // 동치 관계 판별
             print_equivalence_result(&matrix);
@@ -33,85 +207,162 @@ fn main() {
             // 관계 속성 종합 분석
             analyze_relationship_properties(&matrix);
 
+            // 사이클이 없는 관계의 위상 정렬 및 선형 확장
+            if is_acyclic(&matrix) {
+                print_topological_analysis(&matrix);
+            }
+
+            // 행 함수의 핵과 표준 분해
+            print_row_kernel(&matrix);
+
+            // 강연결 요소로 묶은 몫 관계
+            print_quotient_analysis(
+                &matrix,
+                &mutual_reachability(&matrix),
+                t(Msg::StronglyConnectedComponents),
+            );
+
+            // 강연결 요소 분할과 동치 폐포 분할의 비교
+            print_partition_comparison(
+                &mutual_reachability(&matrix),
+                t(Msg::StronglyConnectedComponents),
+                &equivalence_closure(&matrix),
+                t(Msg::EquivalenceClosureName),
+            );
+
             // 텍스트 기반 시각화 (인접 리스트, 연결 요소)
             print_text_visualization(&matrix);
 
+            // 동형 판별용 정규형
+            print_canonical_form(&matrix);
+
+            // 자기 동형군과 궤도 (동치류와 나란히 표시)
+            print_automorphism_group(&matrix);
+
             // 폐포 분석 (동치 관계가 아닐 경우)
             perform_closure_analysis(&matrix);
+
+            // 추이 폐포를 만드는 Warshall 알고리즘의 단계별 과정
+            if !is_transitive(&matrix) || options.step {
+                print_warshall_steps(&matrix, options.step);
+            }
+            if let Some(dir) = &options.frames {
+                match export_frames(&matrix, dir, options.frame_format) {
+                    Ok(paths) => println!(
+                        "\n{}",
+                        tf(Msg::FramesSaved, &[&paths.len(), &dir.display()])
+                    ),
+                    Err(e) => {
+                        eprintln!("{}", tf(Msg::ErrorPrefix, &[&tf(Msg::FramesFailed, &[&e])]))
+                    }
+                }
+            }
+
+            // 일반 폐포 엔진으로 각 속성의 폐포와 유도 과정 분석
+            print_rule_closure_analysis(&matrix);
+
+            // 내부 분석 (속성을 만족하는 가장 큰 부분 관계)
+            perform_interior_analysis(&matrix);
         }
         Err(e) => {
-            eprintln!("오류: {}", e);
-            return;
+            eprintln!("{}", tf(Msg::ErrorPrefix, &[&e]));
         }
     }
 }
 
+/// 대화형으로 입력받을 수 있는 행렬 크기의 범위 (한 행씩 직접 입력하므로 작게 제한)
+const MIN_INPUT_SIZE: usize = 2;
+const MAX_INPUT_SIZE: usize = 10;
+
+/// 표준 입력에서 한 줄을 읽는 헬퍼 함수 - 입력이 끝나면(EOF) Err
+fn read_input_line() -> Result<String, String> {
+    let mut input = String::new();
+    match io::stdin().read_line(&mut input) {
+        Ok(0) => Err(t(Msg::InputEnded).to_string()),
+        Ok(_) => Ok(input),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
 /// 동적 크기의 관계행렬을 사용자로부터 입력받는 함수
-/// 행렬 크기는 2×2부터 10×10까지 지원하며, 유효성 검사를 수행
-/// 잘못된 입력시 재입력을 요청하며, 성공시 Matrix를 반환
-fn read_relation_matrix() -> Result<Matrix, String> {
+/// 행렬 크기는 MIN_INPUT_SIZE부터 MAX_INPUT_SIZE까지 지원하며, 유효성 검사를 수행
+/// 잘못된 입력시 재입력을 요청하며, 성공시 Matrix를 반환하고 입력이 끝나면 Err
+/// notation이 참이면 크기 대신 parse::parse_relation 표기({…}, […])를 한 줄로 입력할 수도 있음
+/// 안내 문구는 out에 출력 (보고서 모드에서는 표준 출력을 비워 두기 위해 표준 오류 사용)
+fn read_relation_matrix(out: &mut dyn Write, notation: bool) -> Result<Matrix, String> {
     // 행렬 크기 입력받기
     let size = loop {
-        println!("관계행렬의 크기를 입력하세요 (2-10 사이의 정수): ");
-        print!("크기: ");
-        io::stdout().flush().unwrap();
+        writeln!(
+            out,
+            "{}",
+            tf(Msg::SizePrompt, &[&MIN_INPUT_SIZE, &MAX_INPUT_SIZE])
+        )
+        .unwrap();
+        if notation {
+            writeln!(out, "{}", t(Msg::NotationHint)).unwrap();
+        }
+        write!(out, "{}", t(Msg::SizeLabel)).unwrap();
+        out.flush().unwrap();
 
-        let mut input = String::new();
-        io::stdin().read_line(&mut input).unwrap();
+        let input = read_input_line()?;
+        if notation && input.trim_start().starts_with(['{', '[']) {
+            match parse_relation(&input) {
+                Ok(matrix) => return Ok(matrix),
+                Err(e) => {
+                    writeln!(out, "{}", tf(Msg::ErrorPrefix, &[&e])).unwrap();
+                    continue;
+                }
+            }
+        }
 
         match input.trim().parse::<usize>() {
             Ok(size) => {
-                if size >= 2 && size <= 10 {
+                if (MIN_INPUT_SIZE..=MAX_INPUT_SIZE).contains(&size) {
                     break size;
                 } else {
-                    println!("오류: 크기는 2에서 10 사이여야 합니다. 다시 입력하세요.");
+                    writeln!(
+                        out,
+                        "{}",
+                        tf(Msg::SizeOutOfRange, &[&MIN_INPUT_SIZE, &MAX_INPUT_SIZE])
+                    )
+                    .unwrap();
                     continue;
                 }
             }
             Err(_) => {
-                println!("오류: 유효한 정수를 입력하세요. 다시 입력하세요.");
+                writeln!(out, "{}", t(Msg::SizeNotInteger)).unwrap();
                 continue;
             }
         }
     };
 
-    println!("\n{}×{} 관계행렬을 행 단위로 입력하세요.", size, size);
-    println!(
-        "각 행은 공백으로 구분된 {}개의 숫자(0 또는 1)를 입력하세요.\n",
-        size
-    );
+    writeln!(out, "\n{}", tf(Msg::RowsPrompt, &[&size, &size])).unwrap();
+    writeln!(out, "{}\n", tf(Msg::RowsHint, &[&size])).unwrap();
 
     let mut matrix = Vec::new();
 
     for i in 0..size {
         loop {
-            print!("행 {}: ", i + 1);
-            io::stdout().flush().unwrap();
-            let mut input = String::new();
-            io::stdin().read_line(&mut input).unwrap();
-            let row: Result<Vec<u8>, _> = input
-                .trim()
-                .split_whitespace()
-                .map(|s| s.parse::<u8>())
-                .collect();
+            write!(out, "{}", tf(Msg::RowLabel, &[&(i + 1)])).unwrap();
+            out.flush().unwrap();
+            let input = read_input_line()?;
+            let row: Result<Vec<u8>, _> =
+                input.split_whitespace().map(|s| s.parse::<u8>()).collect();
             match row {
                 Ok(row) => {
                     if row.len() != size {
-                        println!(
-                            "오류: 각 행은 정확히 {}개의 요소를 가져야 합니다. 다시 입력하세요.",
-                            size
-                        );
+                        writeln!(out, "{}", tf(Msg::RowLength, &[&size])).unwrap();
                         continue;
                     }
                     if row.iter().any(|&x| x != 0 && x != 1) {
-                        println!("오류: 관계행렬의 요소는 0 또는 1이어야 합니다. 다시 입력하세요.");
+                        writeln!(out, "{}", t(Msg::RowNotBinary)).unwrap();
                         continue;
                     }
                     matrix.push(row);
                     break;
                 }
                 Err(_) => {
-                    println!("오류: 숫자가 아닌 값이 입력되었습니다. 다시 입력하세요.");
+                    writeln!(out, "{}", t(Msg::RowNotNumber)).unwrap();
                     continue;
                 }
             }
@@ -0,0 +1,361 @@
+/// 대칭차가 가장 작은 동치 관계(상관 군집화, correlation clustering)를 찾는 모듈
+/// 동치 폐포가 쌍을 추가하기만 하는 것과 달리, 쌍의 추가와 제거를 모두 허용하여 가장 가까운 동치 관계를 구함
+use crate::Matrix;
+use crate::i18n::{Msg, t, tf};
+
+/// 분기 한정법(branch and bound)으로 정확한 해를 구하는 최대 원소 개수
+/// 이보다 크면 지역 탐색 휴리스틱을 사용
+pub const EXACT_LIMIT: usize = 12;
+
+/// 가장 가까운 동치 관계의 계산 결과
+/// matrix: 찾은 동치 관계, distance: 원래 관계와의 대칭차 크기 |R Δ E|
+/// added/removed: 추가/제거해야 하는 순서쌍, exact: 정확한 해인지 여부
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NearestEquivalence {
+    pub matrix: Matrix,
+    pub distance: usize,
+    pub added: Vec<(usize, usize)>,
+    pub removed: Vec<(usize, usize)>,
+    pub exact: bool,
+}
+
+/// 두 원소를 같은 동치류에 넣을 때와 다른 동치류에 넣을 때의 비용을 계산하는 헬퍼 함수
+/// 같은 동치류: 없는 순서쌍 (i,j), (j,i)를 추가해야 하는 개수
+/// 다른 동치류: 있는 순서쌍 (i,j), (j,i)를 제거해야 하는 개수
+fn pair_costs(matrix: &Matrix, i: usize, j: usize) -> (usize, usize) {
+    let present = (matrix[i][j] + matrix[j][i]) as usize;
+    (2 - present, present)
+}
+
+/// 대각선에서 추가해야 하는 순서쌍의 개수 (동치 관계는 반사적이어야 하므로 항상 필요)
+fn diagonal_cost(matrix: &Matrix) -> usize {
+    (0..matrix.len()).filter(|&i| matrix[i][i] == 0).count()
+}
+
+/// 동치류 번호 배정으로부터 전체 대칭차 크기를 계산하는 헬퍼 함수
+fn assignment_cost(matrix: &Matrix, cluster: &[usize]) -> usize {
+    let n = matrix.len();
+    let mut cost = diagonal_cost(matrix);
+    for i in 0..n {
+        for j in (i + 1)..n {
+            let (same, different) = pair_costs(matrix, i, j);
+            cost += if cluster[i] == cluster[j] {
+                same
+            } else {
+                different
+            };
+        }
+    }
+    cost
+}
+
+/// 동치류 번호 배정을 결과 구조체로 변환하는 헬퍼 함수
+fn build_result(matrix: &Matrix, cluster: &[usize], exact: bool) -> NearestEquivalence {
+    let n = matrix.len();
+    let mut result = vec![vec![0; n]; n];
+    let mut added = Vec::new();
+    let mut removed = Vec::new();
+    for i in 0..n {
+        for j in 0..n {
+            if cluster[i] == cluster[j] {
+                result[i][j] = 1;
+            }
+            match (matrix[i][j], result[i][j]) {
+                (0, 1) => added.push((i, j)),
+                (1, 0) => removed.push((i, j)),
+                _ => {}
+            }
+        }
+    }
+    NearestEquivalence {
+        matrix: result,
+        distance: added.len() + removed.len(),
+        added,
+        removed,
+        exact,
+    }
+}
+
+/// 지역 탐색 휴리스틱으로 가까운 동치 관계를 구하는 함수
+/// 피벗 기반 초기 군집(번호가 작은 원소부터, 같이 묶는 편이 싼 원소들을 모음)에서 시작하여
+/// 한 원소를 다른 동치류 또는 새 동치류로 옮겨 비용이 줄어드는 동안 반복
+pub fn nearest_equivalence_heuristic(matrix: &Matrix) -> NearestEquivalence {
+    let n = matrix.len();
+    let mut cluster = vec![usize::MAX; n];
+    let mut next_cluster = 0;
+    for pivot in 0..n {
+        if cluster[pivot] != usize::MAX {
+            continue;
+        }
+        cluster[pivot] = next_cluster;
+        for (j, slot) in cluster.iter_mut().enumerate().skip(pivot + 1) {
+            let (same, different) = pair_costs(matrix, pivot, j);
+            if *slot == usize::MAX && same < different {
+                *slot = next_cluster;
+            }
+        }
+        next_cluster += 1;
+    }
+
+    loop {
+        let mut improved = false;
+        for v in 0..n {
+            // 원소 v를 동치류 c에 둘 때 v와 관련된 비용
+            let cost_in = |cluster: &[usize], c: usize| -> usize {
+                (0..n)
+                    .filter(|&u| u != v)
+                    .map(|u| {
+                        let (same, different) = pair_costs(matrix, v, u);
+                        if cluster[u] == c { same } else { different }
+                    })
+                    .sum()
+            };
+            let current = cost_in(&cluster, cluster[v]);
+            let mut best = (current, cluster[v]);
+            for c in 0..=next_cluster {
+                let cost = cost_in(&cluster, c);
+                if cost < best.0 {
+                    best = (cost, c);
+                }
+            }
+            if best.1 != cluster[v] {
+                cluster[v] = best.1;
+                if best.1 == next_cluster {
+                    next_cluster += 1;
+                }
+                improved = true;
+            }
+        }
+        if !improved {
+            break;
+        }
+    }
+
+    build_result(matrix, &cluster, false)
+}
+
+/// 분기 한정법 탐색 상태
+/// 원소를 0번부터 차례로 기존 동치류 또는 새 동치류에 배정하며,
+/// 남은 쌍마다 두 비용 중 작은 값을 더한 하한이 현재 최선 이상이면 가지치기
+struct BranchAndBound<'a> {
+    matrix: &'a Matrix,
+    remaining_bound: Vec<usize>,
+    best_cost: usize,
+    best_cluster: Vec<usize>,
+}
+
+impl BranchAndBound<'_> {
+    fn search(&mut self, cluster: &mut Vec<usize>, clusters: usize, cost: usize) {
+        let k = cluster.len();
+        let n = self.matrix.len();
+        if cost + self.remaining_bound[k] >= self.best_cost {
+            return;
+        }
+        if k == n {
+            self.best_cost = cost;
+            self.best_cluster = cluster.clone();
+            return;
+        }
+        for c in 0..=clusters {
+            let added: usize = (0..k)
+                .map(|j| {
+                    let (same, different) = pair_costs(self.matrix, k, j);
+                    if cluster[j] == c { same } else { different }
+                })
+                .sum();
+            cluster.push(c);
+            let next_clusters = if c == clusters {
+                clusters + 1
+            } else {
+                clusters
+            };
+            self.search(cluster, next_clusters, cost + added);
+            cluster.pop();
+        }
+    }
+}
+
+/// 분기 한정법으로 대칭차가 가장 작은 동치 관계를 정확히 구하는 함수
+/// 원소 수에 대해 지수 시간이 걸리므로 EXACT_LIMIT 이하의 크기에 사용
+pub fn nearest_equivalence_exact(matrix: &Matrix) -> NearestEquivalence {
+    let n = matrix.len();
+    let initial = nearest_equivalence_heuristic(matrix);
+    let initial_cluster: Vec<usize> = (0..n)
+        .map(|i| (0..n).find(|&j| initial.matrix[i][j] == 1).unwrap_or(i))
+        .collect();
+
+    // remaining_bound[k] = 원소 k 이상이 포함된 쌍들의 최소 비용 합
+    let mut remaining_bound = vec![0usize; n + 1];
+    for k in (0..n).rev() {
+        let pairs: usize = (0..k)
+            .map(|j| {
+                let (same, different) = pair_costs(matrix, k, j);
+                same.min(different)
+            })
+            .sum();
+        remaining_bound[k] = remaining_bound[k + 1] + pairs;
+    }
+
+    let diagonal = diagonal_cost(matrix);
+    let mut search = BranchAndBound {
+        matrix,
+        remaining_bound,
+        best_cost: assignment_cost(matrix, &initial_cluster) - diagonal + 1,
+        best_cluster: initial_cluster,
+    };
+    search.search(&mut Vec::with_capacity(n), 0, 0);
+    build_result(matrix, &search.best_cluster, true)
+}
+
+/// 크기에 따라 정확한 방법 또는 휴리스틱으로 가장 가까운 동치 관계를 구하는 함수
+pub fn nearest_equivalence(matrix: &Matrix) -> NearestEquivalence {
+    if matrix.len() <= EXACT_LIMIT {
+        nearest_equivalence_exact(matrix)
+    } else {
+        nearest_equivalence_heuristic(matrix)
+    }
+}
+
+/// 동치 폐포와 비교하여 가장 가까운 동치 관계 및 추가/제거할 쌍을 출력하는 함수
+pub fn print_nearest_equivalence(matrix: &Matrix) {
+    println!("\n{}", t(Msg::NearestSection));
+
+    let nearest = nearest_equivalence(matrix);
+    let closure = super::closure::equivalence_closure(matrix);
+    let closure_added = (0..matrix.len())
+        .flat_map(|i| (0..matrix.len()).map(move |j| (i, j)))
+        .filter(|&(i, j)| matrix[i][j] == 0 && closure[i][j] == 1)
+        .count();
+
+    super::visualize::print_matrix(&nearest.matrix, t(Msg::NearestMatrix));
+    let method = t(if nearest.exact {
+        Msg::NearestExact
+    } else {
+        Msg::NearestHeuristic
+    });
+    println!("{}", tf(Msg::NearestMethod, &[&method]));
+
+    let format_pairs = |pairs: &[(usize, usize)]| -> String {
+        if pairs.is_empty() {
+            return t(Msg::ReportNone).to_string();
+        }
+        pairs
+            .iter()
+            .map(|&(i, j)| format!("({}, {})", i + 1, j + 1))
+            .collect::<Vec<String>>()
+            .join(", ")
+    };
+    println!("{}", tf(Msg::PairsToAdd, &[&format_pairs(&nearest.added)]));
+    println!(
+        "{}",
+        tf(Msg::PairsToRemove, &[&format_pairs(&nearest.removed)])
+    );
+    println!(
+        "{}",
+        tf(Msg::NearestDistance, &[&nearest.distance, &closure_added])
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::order::{SimpleRng, random_relation};
+
+    /// 제한 성장 문자열로 {0, ..., n-1}의 모든 분할(동치류 번호 배정)을 나열하는 헬퍼 함수
+    fn all_partitions(n: usize) -> Vec<Vec<usize>> {
+        let mut result = vec![Vec::new()];
+        for _ in 0..n {
+            let mut next = Vec::new();
+            for labels in result {
+                let used = labels.iter().max().map_or(0, |&m| m + 1);
+                for c in 0..=used {
+                    let mut extended = labels.clone();
+                    extended.push(c);
+                    next.push(extended);
+                }
+            }
+            result = next;
+        }
+        result
+    }
+
+    /// 모든 동치 관계와 비교하여 가장 작은 대칭차 크기를 구하는 헬퍼 함수
+    fn brute_force_distance(matrix: &Matrix) -> usize {
+        let n = matrix.len();
+        all_partitions(n)
+            .iter()
+            .map(|labels| {
+                let candidate = crate::equivalence::kernel_relation(labels);
+                (0..n)
+                    .flat_map(|i| (0..n).map(move |j| (i, j)))
+                    .filter(|&(i, j)| matrix[i][j] != candidate[i][j])
+                    .count()
+            })
+            .min()
+            .unwrap_or(0)
+    }
+
+    #[test]
+    fn exact_matches_brute_force_up_to_five() {
+        assert_eq!(all_partitions(5).len(), 52);
+        for seed in 0..100 {
+            let matrix = random_relation(&mut SimpleRng::new(seed), 1 + (seed as usize % 5), 2);
+            let nearest = nearest_equivalence_exact(&matrix);
+            assert!(nearest.exact);
+            assert!(crate::equivalence::is_equivalence_relation(&nearest.matrix));
+            assert_eq!(
+                nearest.distance,
+                brute_force_distance(&matrix),
+                "{matrix:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn reported_pairs_explain_the_distance() {
+        for seed in 0..20 {
+            let matrix = random_relation(&mut SimpleRng::new(seed), 6, 2);
+            for nearest in [
+                nearest_equivalence_exact(&matrix),
+                nearest_equivalence_heuristic(&matrix),
+            ] {
+                let mut rebuilt = matrix.clone();
+                for &(i, j) in &nearest.added {
+                    assert_eq!(rebuilt[i][j], 0);
+                    rebuilt[i][j] = 1;
+                }
+                for &(i, j) in &nearest.removed {
+                    assert_eq!(rebuilt[i][j], 1);
+                    rebuilt[i][j] = 0;
+                }
+                assert_eq!(rebuilt, nearest.matrix);
+                assert_eq!(
+                    nearest.distance,
+                    nearest.added.len() + nearest.removed.len()
+                );
+                assert!(crate::equivalence::is_equivalence_relation(&nearest.matrix));
+            }
+            assert!(
+                nearest_equivalence_heuristic(&matrix).distance
+                    >= nearest_equivalence_exact(&matrix).distance
+            );
+        }
+    }
+
+    #[test]
+    fn equivalence_relation_is_its_own_nearest() {
+        let matrix = crate::equivalence::kernel_relation(&[0, 1, 0, 2, 1]);
+        let nearest = nearest_equivalence(&matrix);
+        assert_eq!(nearest.matrix, matrix);
+        assert_eq!(nearest.distance, 0);
+    }
+
+    #[test]
+    fn removing_can_beat_the_closure() {
+        // 대각선과 0 → 1 하나뿐: 동치 폐포는 (1, 0)을 추가하지만 (0, 1)을 제거하는 편도 거리 1
+        let matrix = vec![vec![1, 1, 0], vec![0, 1, 0], vec![0, 0, 1]];
+        assert_eq!(nearest_equivalence(&matrix).distance, 1);
+        let large = random_relation(&mut SimpleRng::new(5), EXACT_LIMIT + 1, 2);
+        assert!(!nearest_equivalence(&large).exact);
+    }
+}
@@ -0,0 +1,1345 @@
+/// 부분 순서 집합(poset)의 원소 및 부분집합에 대한 연산을 수행하는 모듈
+/// 관계 R이 반대칭적이고 추이적일 때, a ≤ b ⇔ a = b ∨ R(a,b) 로 순서를 해석
+use crate::Matrix;
+use crate::i18n::{Msg, t, tf};
+
+/// a ≤ b 인지 판별하는 함수
+/// 대각선 요소가 비어 있는 엄밀 순서도 처리할 수 있도록 a = b는 항상 참으로 간주
+pub fn leq(matrix: &Matrix, a: usize, b: usize) -> bool {
+    a == b || matrix[a][b] == 1
+}
+
+/// 두 원소가 비교 가능한지 판별하는 함수
+/// a ≤ b 또는 b ≤ a 중 하나라도 성립하면 비교 가능
+pub fn are_comparable(matrix: &Matrix, a: usize, b: usize) -> bool {
+    leq(matrix, a, b) || leq(matrix, b, a)
+}
+
+/// 비교 불가능한 모든 원소 쌍 (a, b) (a < b)을 반환하는 함수
+pub fn incomparable_pairs(matrix: &Matrix) -> Vec<(usize, usize)> {
+    let n = matrix.len();
+    let mut pairs = Vec::new();
+    for a in 0..n {
+        for b in (a + 1)..n {
+            if !are_comparable(matrix, a, b) {
+                pairs.push((a, b));
+            }
+        }
+    }
+    pairs
+}
+
+/// 극소 원소(minimal element)들을 반환하는 함수
+/// 자신보다 엄밀히 작은 원소가 없는 원소들
+pub fn minimal_elements(matrix: &Matrix) -> Vec<usize> {
+    let n = matrix.len();
+    (0..n)
+        .filter(|&a| (0..n).all(|b| b == a || !leq(matrix, b, a)))
+        .collect()
+}
+
+/// 극대 원소(maximal element)들을 반환하는 함수
+/// 자신보다 엄밀히 큰 원소가 없는 원소들
+pub fn maximal_elements(matrix: &Matrix) -> Vec<usize> {
+    let n = matrix.len();
+    (0..n)
+        .filter(|&a| (0..n).all(|b| b == a || !leq(matrix, a, b)))
+        .collect()
+}
+
+/// 최소 원소(least element)를 반환하는 함수
+/// 모든 원소보다 작거나 같은 원소가 있으면 Some, 없으면 None
+pub fn least_element(matrix: &Matrix) -> Option<usize> {
+    let n = matrix.len();
+    (0..n).find(|&a| (0..n).all(|b| leq(matrix, a, b)))
+}
+
+/// 최대 원소(greatest element)를 반환하는 함수
+/// 모든 원소보다 크거나 같은 원소가 있으면 Some, 없으면 None
+pub fn greatest_element(matrix: &Matrix) -> Option<usize> {
+    let n = matrix.len();
+    (0..n).find(|&a| (0..n).all(|b| leq(matrix, b, a)))
+}
+
+/// 부분집합의 상계(upper bound)들을 반환하는 함수
+/// 부분집합의 모든 원소보다 크거나 같은 원소들
+pub fn upper_bounds(matrix: &Matrix, subset: &[usize]) -> Vec<usize> {
+    (0..matrix.len())
+        .filter(|&u| subset.iter().all(|&s| leq(matrix, s, u)))
+        .collect()
+}
+
+/// 부분집합의 하계(lower bound)들을 반환하는 함수
+/// 부분집합의 모든 원소보다 작거나 같은 원소들
+pub fn lower_bounds(matrix: &Matrix, subset: &[usize]) -> Vec<usize> {
+    (0..matrix.len())
+        .filter(|&l| subset.iter().all(|&s| leq(matrix, l, s)))
+        .collect()
+}
+
+/// 부분집합의 상한(supremum, 최소 상계)을 반환하는 함수
+/// 상계들 중 최소 원소가 존재할 때만 Some
+pub fn supremum(matrix: &Matrix, subset: &[usize]) -> Option<usize> {
+    let bounds = upper_bounds(matrix, subset);
+    bounds
+        .iter()
+        .copied()
+        .find(|&u| bounds.iter().all(|&v| leq(matrix, u, v)))
+}
+
+/// 부분집합의 하한(infimum, 최대 하계)을 반환하는 함수
+/// 하계들 중 최대 원소가 존재할 때만 Some
+pub fn infimum(matrix: &Matrix, subset: &[usize]) -> Option<usize> {
+    let bounds = lower_bounds(matrix, subset);
+    bounds
+        .iter()
+        .copied()
+        .find(|&l| bounds.iter().all(|&v| leq(matrix, v, l)))
+}
+
+/// 부분집합이 사슬(chain)인지 판별하는 함수
+/// 부분집합의 모든 두 원소가 비교 가능해야 함
+pub fn is_chain(matrix: &Matrix, subset: &[usize]) -> bool {
+    subset
+        .iter()
+        .all(|&a| subset.iter().all(|&b| are_comparable(matrix, a, b)))
+}
+
+/// 부분집합이 반사슬(antichain)인지 판별하는 함수
+/// 부분집합의 서로 다른 두 원소가 모두 비교 불가능해야 함
+pub fn is_antichain(matrix: &Matrix, subset: &[usize]) -> bool {
+    subset.iter().all(|&a| {
+        subset
+            .iter()
+            .all(|&b| a == b || !are_comparable(matrix, a, b))
+    })
+}
+
+/// 원소 목록을 {1, 2, 3} 형식의 문자열로 변환하는 헬퍼 함수 (1부터 시작하는 번호 사용)
+pub(crate) fn format_set(elements: &[usize]) -> String {
+    let text = elements
+        .iter()
+        .map(|&x| (x + 1).to_string())
+        .collect::<Vec<String>>()
+        .join(", ");
+    format!("{{{}}}", text)
+}
+
+/// Option 원소를 번호 또는 "없음"으로 변환하는 헬퍼 함수
+pub(crate) fn format_option(element: Option<usize>) -> String {
+    match element {
+        Some(x) => (x + 1).to_string(),
+        None => t(Msg::ReportNone).to_string(),
+    }
+}
+
+/// 부분 순서 집합의 구조를 설명과 함께 출력하는 함수
+/// 극소/극대, 최소/최대 원소, 전체 집합의 상계/하계, 비교 불가능한 쌍의 상한/하한을 표시
+pub fn print_poset_analysis(matrix: &Matrix) {
+    println!("\n=== {} ===", t(Msg::PosetTitle));
+
+    let n = matrix.len();
+    let all: Vec<usize> = (0..n).collect();
+
+    let minimal = minimal_elements(matrix);
+    let maximal = maximal_elements(matrix);
+    println!("{}", tf(Msg::MinimalElements, &[&format_set(&minimal)]));
+    println!("{}", tf(Msg::MaximalElements, &[&format_set(&maximal)]));
+
+    let least = least_element(matrix);
+    let greatest = greatest_element(matrix);
+    println!(
+        "{}{}",
+        tf(Msg::LeastElement, &[&format_option(least)]),
+        if least.is_none() && minimal.len() > 1 {
+            t(Msg::NoLeastElement)
+        } else {
+            ""
+        }
+    );
+    println!(
+        "{}{}",
+        tf(Msg::GreatestElement, &[&format_option(greatest)]),
+        if greatest.is_none() && maximal.len() > 1 {
+            t(Msg::NoGreatestElement)
+        } else {
+            ""
+        }
+    );
+
+    println!(
+        "\n{}",
+        tf(
+            Msg::UpperBoundsOfAll,
+            &[&format_set(&upper_bounds(matrix, &all))]
+        )
+    );
+    println!(
+        "{}",
+        tf(
+            Msg::LowerBoundsOfAll,
+            &[&format_set(&lower_bounds(matrix, &all))]
+        )
+    );
+
+    if is_chain(matrix, &all) {
+        println!("\n{}", t(Msg::WholeSetChain));
+        return;
+    }
+    if is_antichain(matrix, &all) {
+        println!("\n{}", t(Msg::WholeSetAntichain));
+    }
+
+    println!("\n{}", t(Msg::IncomparableBoundsHeader));
+    for (a, b) in incomparable_pairs(matrix) {
+        let pair = [a, b];
+        let sup = supremum(matrix, &pair);
+        let inf = infimum(matrix, &pair);
+        println!(
+            "{}",
+            tf(
+                Msg::IncomparableBounds,
+                &[
+                    &(a + 1),
+                    &(b + 1),
+                    &format_set(&upper_bounds(matrix, &pair)),
+                    &format_option(sup),
+                    &format_set(&lower_bounds(matrix, &pair)),
+                    &format_option(inf),
+                ]
+            )
+        );
+    }
+}
+
+/// 선형 확장의 개수를 부분집합 동적 계획법으로 셀 수 있는 최대 원소 개수
+pub const MAX_COUNT_SIZE: usize = 20;
+
+/// 자기 자신으로의 간선(대각선)을 제외한 방향 그래프에서 사이클을 찾아 반환하는 함수
+/// 사이클이 있으면 [v0, v1, ..., vk] (vk → v0 간선으로 닫힘), 없으면 None
+pub fn find_cycle(matrix: &Matrix) -> Option<Vec<usize>> {
+    let n = matrix.len();
+    // 0: 미방문, 1: 탐색 중, 2: 탐색 완료
+    let mut state = vec![0u8; n];
+    let mut parent = vec![usize::MAX; n];
+
+    for start in 0..n {
+        if state[start] != 0 {
+            continue;
+        }
+        // (정점, 다음에 검사할 이웃) 스택으로 재귀 없이 깊이 우선 탐색
+        let mut stack = vec![(start, 0usize)];
+        state[start] = 1;
+        while let Some(&mut (u, ref mut next)) = stack.last_mut() {
+            if *next == n {
+                state[u] = 2;
+                stack.pop();
+                continue;
+            }
+            let v = *next;
+            *next += 1;
+            if v == u || matrix[u][v] != 1 {
+                continue;
+            }
+            match state[v] {
+                0 => {
+                    state[v] = 1;
+                    parent[v] = u;
+                    stack.push((v, 0));
+                }
+                1 => {
+                    // v → ... → u → v 사이클 복원
+                    let mut cycle = vec![u];
+                    let mut w = u;
+                    while w != v {
+                        w = parent[w];
+                        cycle.push(w);
+                    }
+                    cycle.reverse();
+                    return Some(cycle);
+                }
+                _ => {}
+            }
+        }
+    }
+    None
+}
+
+/// 대각선을 제외한 관계가 사이클이 없는(acyclic) 관계인지 판별하는 함수
+pub fn is_acyclic(matrix: &Matrix) -> bool {
+    find_cycle(matrix).is_none()
+}
+
+/// 각 원소로 들어오는 간선 수 (자기 자신으로의 간선 제외)를 구하는 헬퍼 함수
+fn in_degrees(matrix: &Matrix) -> Vec<usize> {
+    let n = matrix.len();
+    (0..n)
+        .map(|j| (0..n).filter(|&i| i != j && matrix[i][j] == 1).count())
+        .collect()
+}
+
+/// 위상 정렬을 수행하는 함수
+/// 가능하면 Ok(정렬 순서) - 여러 후보가 있으면 번호가 작은 원소를 먼저 배치
+/// 사이클이 있으면 Err(사이클 증거)
+pub fn topological_sort(matrix: &Matrix) -> Result<Vec<usize>, Vec<usize>> {
+    let n = matrix.len();
+    let mut in_degree = in_degrees(matrix);
+
+    let mut placed = vec![false; n];
+    let mut order = Vec::with_capacity(n);
+    while order.len() < n {
+        let next = (0..n).find(|&v| !placed[v] && in_degree[v] == 0);
+        match next {
+            Some(u) => {
+                placed[u] = true;
+                order.push(u);
+                for v in 0..n {
+                    if v != u && matrix[u][v] == 1 {
+                        in_degree[v] -= 1;
+                    }
+                }
+            }
+            None => return Err(find_cycle(matrix).unwrap_or_default()),
+        }
+    }
+    Ok(order)
+}
+
+/// 각 원소의 직접 선행 원소들을 비트마스크로 반환하는 헬퍼 함수
+fn predecessor_masks(matrix: &Matrix) -> Vec<u64> {
+    let n = matrix.len();
+    (0..n)
+        .map(|v| {
+            (0..n)
+                .filter(|&u| u != v && matrix[u][v] == 1)
+                .fold(0u64, |mask, u| mask | (1 << u))
+        })
+        .collect()
+}
+
+/// 선형 확장(linear extension)을 최대 limit개까지 사전순으로 나열하는 함수
+/// 선형 확장은 관계의 모든 쌍 (a, b)에서 a가 b보다 앞에 오는 원소들의 전체 순서
+/// 사이클이 있으면 빈 벡터를 반환
+pub fn linear_extensions(matrix: &Matrix, limit: usize) -> Vec<Vec<usize>> {
+    let n = matrix.len();
+    let mut result = Vec::new();
+    if !is_acyclic(matrix) || limit == 0 {
+        return result;
+    }
+
+    let mut in_degree = in_degrees(matrix);
+    let mut placed = vec![false; n];
+    let mut current = Vec::with_capacity(n);
+    extend_linear(
+        matrix,
+        &mut in_degree,
+        &mut placed,
+        &mut current,
+        &mut result,
+        limit,
+    );
+    result
+}
+
+/// 선형 확장을 되추적(backtracking)으로 생성하는 재귀 헬퍼 함수
+fn extend_linear(
+    matrix: &Matrix,
+    in_degree: &mut [usize],
+    placed: &mut [bool],
+    current: &mut Vec<usize>,
+    result: &mut Vec<Vec<usize>>,
+    limit: usize,
+) {
+    let n = matrix.len();
+    if current.len() == n {
+        result.push(current.clone());
+        return;
+    }
+    for u in 0..n {
+        if placed[u] || in_degree[u] != 0 {
+            continue;
+        }
+        placed[u] = true;
+        current.push(u);
+        for v in 0..n {
+            if v != u && matrix[u][v] == 1 {
+                in_degree[v] -= 1;
+            }
+        }
+
+        extend_linear(matrix, in_degree, placed, current, result, limit);
+
+        for v in 0..n {
+            if v != u && matrix[u][v] == 1 {
+                in_degree[v] += 1;
+            }
+        }
+        current.pop();
+        placed[u] = false;
+        if result.len() >= limit {
+            return;
+        }
+    }
+}
+
+/// 배치된 원소 집합(비트마스크)마다 나머지를 배치하는 방법의 수를 계산하는 헬퍼 함수
+/// ways[mask] = mask가 이미 앞쪽에 배치되었을 때 남은 원소들의 선형 확장 개수
+fn completion_counts(matrix: &Matrix) -> Option<Vec<u64>> {
+    let n = matrix.len();
+    if n > MAX_COUNT_SIZE || !is_acyclic(matrix) {
+        return None;
+    }
+    let preds = predecessor_masks(matrix);
+    let full = (1u64 << n) - 1;
+    let mut ways = vec![0u64; 1 << n];
+    ways[full as usize] = 1;
+    for mask in (0..full).rev() {
+        let mut total = 0u64;
+        for v in 0..n {
+            if mask & (1 << v) == 0 && preds[v] & !mask == 0 {
+                total += ways[(mask | (1 << v)) as usize];
+            }
+        }
+        ways[mask as usize] = total;
+    }
+    Some(ways)
+}
+
+/// 선형 확장의 개수를 세는 함수
+/// 사이클이 있거나 원소가 MAX_COUNT_SIZE개보다 많으면 None
+pub fn count_linear_extensions(matrix: &Matrix) -> Option<u64> {
+    completion_counts(matrix).map(|ways| ways[0])
+}
+
+/// 시드로부터 결정적인 난수열을 만드는 간단한 xorshift64* 생성기
+pub(crate) struct SimpleRng(u64);
+
+impl SimpleRng {
+    pub(crate) fn new(seed: u64) -> Self {
+        // 0 상태에서는 xorshift가 멈추므로 상수와 섞어서 시작
+        SimpleRng(seed ^ 0x9E37_79B9_7F4A_7C15 | 1)
+    }
+
+    pub(crate) fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        self.0.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+
+    /// 0 이상 bound 미만의 난수를 반환
+    pub(crate) fn below(&mut self, bound: u64) -> u64 {
+        ((self.next_u64() as u128 * bound as u128) >> 64) as u64
+    }
+}
+
+/// 각 쌍이 1/one_in 확률로 들어 있는 n × n 무작위 관계를 만드는 테스트용 헬퍼 함수
+#[cfg(test)]
+pub(crate) fn random_relation(rng: &mut SimpleRng, n: usize, one_in: u64) -> Matrix {
+    (0..n)
+        .map(|_| (0..n).map(|_| u8::from(rng.below(one_in) == 0)).collect())
+        .collect()
+}
+
+/// 모든 선형 확장 중 하나를 균등한 확률로 뽑는 함수
+/// 각 단계에서 후보 원소를 그 뒤에 가능한 완성 개수에 비례한 확률로 선택
+/// 사이클이 있거나 원소가 MAX_COUNT_SIZE개보다 많으면 None
+pub fn random_linear_extension(matrix: &Matrix, seed: u64) -> Option<Vec<usize>> {
+    let ways = completion_counts(matrix)?;
+    let n = matrix.len();
+    let preds = predecessor_masks(matrix);
+    let mut rng = SimpleRng::new(seed);
+
+    let mut mask = 0u64;
+    let mut order = Vec::with_capacity(n);
+    while order.len() < n {
+        let mut pick = rng.below(ways[mask as usize]);
+        for v in 0..n {
+            if mask & (1 << v) != 0 || preds[v] & !mask != 0 {
+                continue;
+            }
+            let count = ways[(mask | (1 << v)) as usize];
+            if pick < count {
+                mask |= 1 << v;
+                order.push(v);
+                break;
+            }
+            pick -= count;
+        }
+    }
+    Some(order)
+}
+
+/// 분석 출력에서 무작위 선형 확장을 뽑을 때 쓰는 고정 시드
+/// 같은 입력에 대해 항상 같은 출력이 나오도록 고정
+const SAMPLE_SEED: u64 = 2024;
+
+/// 원소 순서를 1 < 2 < 3 형식의 문자열로 변환하는 헬퍼 함수
+pub(crate) fn format_order(order: &[usize]) -> String {
+    order
+        .iter()
+        .map(|&x| (x + 1).to_string())
+        .collect::<Vec<String>>()
+        .join(" < ")
+}
+
+/// 사이클이 없는 관계의 위상 정렬과 선형 확장 정보를 출력하는 함수
+pub fn print_topological_analysis(matrix: &Matrix) {
+    println!("\n=== {} ===", t(Msg::TopologicalTitle));
+
+    match topological_sort(matrix) {
+        Ok(order) => println!("{}", tf(Msg::TopologicalOrder, &[&format_order(&order)])),
+        Err(cycle) => {
+            let mut text = cycle
+                .iter()
+                .map(|&x| (x + 1).to_string())
+                .collect::<Vec<String>>();
+            if let Some(&first) = cycle.first() {
+                text.push((first + 1).to_string());
+            }
+            println!("{}", tf(Msg::TopologicalCycle, &[&text.join(" → ")]));
+            return;
+        }
+    }
+
+    const SHOWN: usize = 10;
+    match count_linear_extensions(matrix) {
+        Some(count) => println!("{}", tf(Msg::LinearExtensionCount, &[&count])),
+        None => println!("{}", t(Msg::LinearExtensionUncountable)),
+    }
+    println!("{}", tf(Msg::LinearExtensionList, &[&SHOWN]));
+    for extension in linear_extensions(matrix, SHOWN) {
+        println!("  {}", format_order(&extension));
+    }
+
+    if let Some(sample) = random_linear_extension(matrix, SAMPLE_SEED) {
+        println!(
+            "{}",
+            tf(Msg::RandomLinearExtension, &[&format_order(&sample)])
+        );
+    }
+}
+
+/// a < b (a ≠ b 이고 R(a,b)) 인지 판별하는 헬퍼 함수
+fn less(matrix: &Matrix, a: usize, b: usize) -> bool {
+    a != b && matrix[a][b] == 1
+}
+
+/// 각 원소에서 끝나는 가장 긴 사슬의 길이와 직전 원소를 계산하는 헬퍼 함수
+/// 부분 순서가 아니어서 위상 정렬이 불가능하면 None
+fn chain_levels(matrix: &Matrix) -> Option<(Vec<usize>, Vec<Option<usize>>)> {
+    let order = topological_sort(matrix).ok()?;
+    let n = matrix.len();
+    let mut level = vec![1usize; n];
+    let mut previous = vec![None; n];
+    for (idx, &v) in order.iter().enumerate() {
+        for &u in &order[..idx] {
+            if less(matrix, u, v) && level[u] + 1 > level[v] {
+                level[v] = level[u] + 1;
+                previous[v] = Some(u);
+            }
+        }
+    }
+    Some((level, previous))
+}
+
+/// 가장 긴 사슬(longest chain)을 작은 원소부터 순서대로 반환하는 함수
+/// 사슬의 길이가 부분 순서의 높이(height), 사이클이 있으면 빈 벡터
+pub fn longest_chain(matrix: &Matrix) -> Vec<usize> {
+    let Some((level, previous)) = chain_levels(matrix) else {
+        return Vec::new();
+    };
+    let Some(top) = (0..matrix.len()).max_by_key(|&v| (level[v], std::cmp::Reverse(v))) else {
+        return Vec::new();
+    };
+    let mut chain = vec![top];
+    let mut current = top;
+    while let Some(u) = previous[current] {
+        chain.push(u);
+        current = u;
+    }
+    chain.reverse();
+    chain
+}
+
+/// Mirsky 정리에 따른 반사슬 분해를 반환하는 함수
+/// 각 원소를 그 원소에서 끝나는 가장 긴 사슬의 길이로 묶으며, 반사슬의 개수는 높이와 같음
+pub fn mirsky_decomposition(matrix: &Matrix) -> Vec<Vec<usize>> {
+    let Some((level, _)) = chain_levels(matrix) else {
+        return Vec::new();
+    };
+    let height = level.iter().copied().max().unwrap_or(0);
+    let mut antichains = vec![Vec::new(); height];
+    for (v, &l) in level.iter().enumerate() {
+        antichains[l - 1].push(v);
+    }
+    antichains
+}
+
+/// 덮개 관계(Hasse 도표의 간선)를 반환하는 함수
+/// a < b 이면서 a < c < b 인 c가 없는 쌍 (a, b), 부분 순서가 아니면 빈 벡터
+pub fn covering_pairs(matrix: &Matrix) -> Vec<(usize, usize)> {
+    if !super::equivalence::is_partial_order(matrix) {
+        return Vec::new();
+    }
+    let n = matrix.len();
+    let mut covers = Vec::new();
+    for a in 0..n {
+        for b in 0..n {
+            if less(matrix, a, b) && !(0..n).any(|c| less(matrix, a, c) && less(matrix, c, b)) {
+                covers.push((a, b));
+            }
+        }
+    }
+    covers
+}
+
+/// u < v 간선으로 만든 이분 그래프에서 최대 매칭을 구하는 함수 (Kuhn 알고리즘)
+/// 반환값: (왼쪽 u에 매칭된 오른쪽 v, 오른쪽 v에 매칭된 왼쪽 u)
+fn comparability_matching(matrix: &Matrix) -> (Vec<Option<usize>>, Vec<Option<usize>>) {
+    let n = matrix.len();
+    let mut match_left = vec![None; n];
+    let mut match_right = vec![None; n];
+
+    fn augment(
+        matrix: &Matrix,
+        u: usize,
+        seen: &mut [bool],
+        match_left: &mut [Option<usize>],
+        match_right: &mut [Option<usize>],
+    ) -> bool {
+        for v in 0..matrix.len() {
+            if !less(matrix, u, v) || seen[v] {
+                continue;
+            }
+            seen[v] = true;
+            let free = match match_right[v] {
+                None => true,
+                Some(w) => augment(matrix, w, seen, match_left, match_right),
+            };
+            if free {
+                match_left[u] = Some(v);
+                match_right[v] = Some(u);
+                return true;
+            }
+        }
+        false
+    }
+
+    for u in 0..n {
+        let mut seen = vec![false; n];
+        augment(matrix, u, &mut seen, &mut match_left, &mut match_right);
+    }
+    (match_left, match_right)
+}
+
+/// Dilworth 정리에 따른 최소 사슬 덮개(minimum chain cover)를 반환하는 함수
+/// 이분 매칭의 각 간선 u → v를 사슬의 연결로 사용하며, 사슬의 개수는 폭(width)과 같음
+/// 부분 순서가 아니면 빈 벡터
+pub fn minimum_chain_cover(matrix: &Matrix) -> Vec<Vec<usize>> {
+    if !super::equivalence::is_partial_order(matrix) {
+        return Vec::new();
+    }
+    let (match_left, match_right) = comparability_matching(matrix);
+    let mut chains = Vec::new();
+    for (start, incoming) in match_right.iter().enumerate() {
+        // 매칭으로 들어오는 간선이 없는 원소가 사슬의 시작점
+        if incoming.is_some() {
+            continue;
+        }
+        let mut chain = vec![start];
+        let mut current = start;
+        while let Some(next) = match_left[current] {
+            chain.push(next);
+            current = next;
+        }
+        chains.push(chain);
+    }
+    chains
+}
+
+/// 가장 큰 반사슬(maximum antichain)을 반환하는 함수
+/// 최대 매칭에서 König 정리로 최소 정점 덮개를 구하고, 양쪽 모두 덮이지 않은 원소를 선택
+/// 부분 순서가 아니면 빈 벡터
+pub fn maximum_antichain(matrix: &Matrix) -> Vec<usize> {
+    if !super::equivalence::is_partial_order(matrix) {
+        return Vec::new();
+    }
+    let n = matrix.len();
+    let (match_left, match_right) = comparability_matching(matrix);
+
+    // 매칭되지 않은 왼쪽 정점에서 교대 경로로 도달 가능한 정점 표시
+    let mut left_reached = vec![false; n];
+    let mut right_reached = vec![false; n];
+    let mut stack: Vec<usize> = (0..n).filter(|&u| match_left[u].is_none()).collect();
+    for &u in &stack {
+        left_reached[u] = true;
+    }
+    while let Some(u) = stack.pop() {
+        for v in 0..n {
+            if less(matrix, u, v) && !right_reached[v] && match_left[u] != Some(v) {
+                right_reached[v] = true;
+                if let Some(w) = match_right[v]
+                    && !left_reached[w]
+                {
+                    left_reached[w] = true;
+                    stack.push(w);
+                }
+            }
+        }
+    }
+
+    // 최소 정점 덮개 = (도달하지 못한 왼쪽) ∪ (도달한 오른쪽)
+    (0..n)
+        .filter(|&x| left_reached[x] && !right_reached[x])
+        .collect()
+}
+
+/// 부분 순서의 높이(height)와 폭(width), 사슬/반사슬 분해를 출력하는 함수
+pub fn print_chain_decomposition(matrix: &Matrix) {
+    println!("\n=== {} ===", t(Msg::ChainDecompositionTitle));
+
+    let chain = longest_chain(matrix);
+    let antichain = maximum_antichain(matrix);
+    println!(
+        "{}",
+        tf(Msg::PosetHeight, &[&chain.len(), &format_order(&chain)])
+    );
+    println!(
+        "{}",
+        tf(
+            Msg::PosetWidth,
+            &[&antichain.len(), &format_set(&antichain)]
+        )
+    );
+
+    println!("\n{}", t(Msg::DilworthHeader));
+    for chain in minimum_chain_cover(matrix) {
+        println!("  {}", format_order(&chain));
+    }
+
+    println!("\n{}", t(Msg::MirskyHeader));
+    for (level, antichain) in mirsky_decomposition(matrix).iter().enumerate() {
+        println!(
+            "{}",
+            tf(Msg::MirskyLevel, &[&(level + 1), &format_set(antichain)])
+        );
+    }
+}
+
+/// 비교 가능성 그래프(comparability graph)를 관계 행렬로 반환하는 함수
+/// 서로 다른 두 원소가 비교 가능하면 양방향으로 1 (대칭, 비반사 관계)
+pub fn comparability_graph(matrix: &Matrix) -> Matrix {
+    let n = matrix.len();
+    (0..n)
+        .map(|a| {
+            (0..n)
+                .map(|b| u8::from(a != b && are_comparable(matrix, a, b)))
+                .collect()
+        })
+        .collect()
+}
+
+/// 비교 불가능성 그래프(incomparability graph)를 관계 행렬로 반환하는 함수
+/// 서로 다른 두 원소가 비교 불가능하면 양방향으로 1 (대칭, 비반사 관계)
+pub fn incomparability_graph(matrix: &Matrix) -> Matrix {
+    let n = matrix.len();
+    (0..n)
+        .map(|a| {
+            (0..n)
+                .map(|b| u8::from(a != b && !are_comparable(matrix, a, b)))
+                .collect()
+        })
+        .collect()
+}
+
+/// 원소보다 작은 원소의 개수와 큰 원소의 개수를 반환하는 헬퍼 함수
+fn down_up_degrees(matrix: &Matrix) -> Vec<(usize, usize)> {
+    let n = matrix.len();
+    (0..n)
+        .map(|a| {
+            let down = (0..n).filter(|&b| less(matrix, b, a)).count();
+            let up = (0..n).filter(|&b| less(matrix, a, b)).count();
+            (down, up)
+        })
+        .collect()
+}
+
+/// 순서 매장(order embedding) 되추적 탐색에 필요한 입력을 묶은 구조체
+/// bijective가 참이면 작은/큰 원소 개수가 같은 후보만 허용하여 동형 사상을 탐색
+struct EmbeddingSearch<'a> {
+    source: &'a Matrix,
+    target: &'a Matrix,
+    order: Vec<usize>,
+    bijective: bool,
+    source_degrees: Vec<(usize, usize)>,
+    target_degrees: Vec<(usize, usize)>,
+}
+
+impl EmbeddingSearch<'_> {
+    /// order의 depth번째 원소부터 상을 배정하는 재귀 함수
+    fn search(&self, depth: usize, mapping: &mut [usize], used: &mut [bool]) -> bool {
+        if depth == self.order.len() {
+            return true;
+        }
+        let a = self.order[depth];
+        let (sd, su) = self.source_degrees[a];
+
+        for c in 0..self.target.len() {
+            if used[c] {
+                continue;
+            }
+            let (td, tu) = self.target_degrees[c];
+            let degree_ok = if self.bijective {
+                sd == td && su == tu
+            } else {
+                sd <= td && su <= tu
+            };
+            if !degree_ok {
+                continue;
+            }
+            // 이미 배치된 원소들과의 순서 관계가 그대로 보존되는지 확인
+            let consistent = self.order[..depth].iter().all(|&b| {
+                let d = mapping[b];
+                leq(self.source, a, b) == leq(self.target, c, d)
+                    && leq(self.source, b, a) == leq(self.target, d, c)
+            });
+            if !consistent {
+                continue;
+            }
+
+            mapping[a] = c;
+            used[c] = true;
+            if self.search(depth + 1, mapping, used) {
+                return true;
+            }
+            used[c] = false;
+        }
+        false
+    }
+}
+
+/// 두 부분 순서 사이의 순서 보존 단사 함수를 찾는 헬퍼 함수
+fn find_order_map(source: &Matrix, target: &Matrix, bijective: bool) -> Option<Vec<usize>> {
+    let is_order = super::equivalence::is_partial_order;
+    if !is_order(source) || !is_order(target) || source.len() > target.len() {
+        return None;
+    }
+    if bijective && source.len() != target.len() {
+        return None;
+    }
+    let search = EmbeddingSearch {
+        source,
+        target,
+        order: topological_sort(source).ok()?,
+        bijective,
+        source_degrees: down_up_degrees(source),
+        target_degrees: down_up_degrees(target),
+    };
+    let mut mapping = vec![0; source.len()];
+    let mut used = vec![false; target.len()];
+    search.search(0, &mut mapping, &mut used).then_some(mapping)
+}
+
+/// 부분 순서 source를 target 안에 순서 매장하는 단사 함수 f를 찾는 함수
+/// a ≤ b ⇔ f(a) ≤ f(b) 를 만족하며, 반환값 f[a]는 a의 상(image)
+/// 두 관계 중 하나라도 부분 순서가 아니거나 매장이 없으면 None
+pub fn find_order_embedding(source: &Matrix, target: &Matrix) -> Option<Vec<usize>> {
+    find_order_map(source, target, false)
+}
+
+/// 두 부분 순서 사이의 순서 동형 사상(order isomorphism)을 찾는 함수
+/// 크기가 같고 순서를 보존하는 전단사 함수가 있으면 Some(f)
+pub fn find_order_isomorphism(source: &Matrix, target: &Matrix) -> Option<Vec<usize>> {
+    find_order_map(source, target, true)
+}
+
+/// 부분 순서가 두 선형 확장의 교집합으로 표현되는지(차원 ≤ 2) 판별하여 그 두 선형 확장을 반환하는 함수
+/// L1 ∩ L2 = P 이려면 비교 불가능한 쌍은 L2에서 L1과 반대 순서여야 하므로,
+/// L1을 만들면서 이로부터 정해지는 L2가 추이적인지 확인하는 되추적 탐색을 수행
+pub fn two_dimensional_realizer(matrix: &Matrix) -> Option<(Vec<usize>, Vec<usize>)> {
+    if !super::equivalence::is_partial_order(matrix) {
+        return None;
+    }
+    let n = matrix.len();
+    let mut first = Vec::with_capacity(n);
+    let mut placed = vec![false; n];
+    if !extend_realizer(matrix, &mut first, &mut placed) {
+        return None;
+    }
+
+    // L2에서 x가 y보다 앞: P에서 x < y 이거나, 비교 불가능하고 L1에서 y가 x보다 앞
+    let position: Vec<usize> = {
+        let mut pos = vec![0; n];
+        for (idx, &x) in first.iter().enumerate() {
+            pos[x] = idx;
+        }
+        pos
+    };
+    let mut second = first.clone();
+    second.sort_by(|&x, &y| {
+        let x_first = if are_comparable(matrix, x, y) {
+            leq(matrix, x, y)
+        } else {
+            position[x] > position[y]
+        };
+        if x == y {
+            std::cmp::Ordering::Equal
+        } else if x_first {
+            std::cmp::Ordering::Less
+        } else {
+            std::cmp::Ordering::Greater
+        }
+    });
+    Some((first, second))
+}
+
+/// 두 번째 선형 확장 L2가 추이적으로 유지되도록 첫 번째 선형 확장 L1을 구성하는 재귀 헬퍼 함수
+fn extend_realizer(matrix: &Matrix, first: &mut Vec<usize>, placed: &mut [bool]) -> bool {
+    let n = matrix.len();
+    if first.len() == n {
+        return true;
+    }
+    // 배치된 원소들 사이의 L2 순서: L1에서 y가 x보다 앞이면, P에서 y < x 일 때만 L2에서도 y가 앞
+    let second_before = |first: &[usize], y_idx: usize, x_idx: usize| -> bool {
+        let (y, x) = (first[y_idx], first[x_idx]);
+        if y_idx < x_idx {
+            less(matrix, y, x)
+        } else {
+            !less(matrix, x, y)
+        }
+    };
+
+    for x in 0..n {
+        if placed[x] || (0..n).any(|y| !placed[y] && less(matrix, y, x)) {
+            continue;
+        }
+        first.push(x);
+        placed[x] = true;
+
+        // 새 원소가 포함된 세 쌍에 대해 L2의 추이성을 확인
+        let last = first.len() - 1;
+        let mut transitive = true;
+        'check: for a in 0..=last {
+            for b in 0..=last {
+                if a == b || !second_before(first, a, b) {
+                    continue;
+                }
+                for c in 0..=last {
+                    if c == a || c == b || (a != last && b != last && c != last) {
+                        continue;
+                    }
+                    if second_before(first, b, c) && !second_before(first, a, c) {
+                        transitive = false;
+                        break 'check;
+                    }
+                }
+            }
+        }
+
+        if transitive && extend_realizer(matrix, first, placed) {
+            return true;
+        }
+        first.pop();
+        placed[x] = false;
+    }
+    false
+}
+
+/// 부분 순서의 차원이 2 이하인지 판별하는 함수
+pub fn is_two_dimensional(matrix: &Matrix) -> bool {
+    two_dimensional_realizer(matrix).is_some()
+}
+
+/// 크기가 sizes인 사슬들의 곱 순서(product order)를 관계 행렬로 만드는 함수
+/// 원소는 좌표 (x1, ..., xk)를 혼합 기수법으로 번호 매기며, 좌표별로 모두 작거나 같을 때 ≤
+pub fn chain_product(sizes: &[usize]) -> Matrix {
+    let total: usize = sizes.iter().product();
+    let coordinates = |mut index: usize| -> Vec<usize> {
+        let mut coords = vec![0; sizes.len()];
+        for (slot, &size) in coords.iter_mut().zip(sizes).rev() {
+            *slot = index % size;
+            index /= size;
+        }
+        coords
+    };
+    let mut matrix = vec![vec![0; total]; total];
+    for (a, row) in matrix.iter_mut().enumerate() {
+        let ca = coordinates(a);
+        for (b, cell) in row.iter_mut().enumerate() {
+            let cb = coordinates(b);
+            if ca.iter().zip(&cb).all(|(x, y)| x <= y) {
+                *cell = 1;
+            }
+        }
+    }
+    matrix
+}
+
+/// n을 2 이상인 인수들의 내림차순 곱으로 나타내는 모든 방법을 구하는 헬퍼 함수
+fn factorizations(n: usize, max_factor: usize) -> Vec<Vec<usize>> {
+    if n == 1 {
+        return vec![Vec::new()];
+    }
+    let mut result = Vec::new();
+    for factor in (2..=max_factor.min(n)).rev() {
+        if !n.is_multiple_of(factor) {
+            continue;
+        }
+        for mut rest in factorizations(n / factor, factor) {
+            rest.insert(0, factor);
+            result.push(rest);
+        }
+    }
+    result
+}
+
+/// 부분 순서가 사슬들의 곱과 순서 동형이면 그 사슬들의 크기를 반환하는 함수
+/// 원소 개수의 모든 인수분해에 대해 사슬 곱을 만들어 동형 여부를 확인
+pub fn product_of_chains_factors(matrix: &Matrix) -> Option<Vec<usize>> {
+    let n = matrix.len();
+    if n == 0 || !super::equivalence::is_partial_order(matrix) {
+        return None;
+    }
+    factorizations(n, n)
+        .into_iter()
+        .find(|sizes| find_order_isomorphism(matrix, &chain_product(sizes)).is_some())
+}
+
+/// 비교 가능성/비교 불가능성 그래프와 차원, 사슬 곱 구조를 출력하는 함수
+pub fn print_order_dimension_analysis(matrix: &Matrix) {
+    println!("\n=== {} ===", t(Msg::DimensionTitle));
+
+    let graph_text = |graph: &Matrix| -> String {
+        let n = graph.len();
+        let edges: Vec<String> = (0..n)
+            .flat_map(|a| ((a + 1)..n).map(move |b| (a, b)))
+            .filter(|&(a, b)| graph[a][b] == 1)
+            .map(|(a, b)| format!("{}-{}", a + 1, b + 1))
+            .collect();
+        format!("{{{}}}", edges.join(", "))
+    };
+    println!(
+        "{}",
+        tf(
+            Msg::ComparabilityEdges,
+            &[&graph_text(&comparability_graph(matrix))]
+        )
+    );
+    println!(
+        "{}",
+        tf(
+            Msg::IncomparabilityEdges,
+            &[&graph_text(&incomparability_graph(matrix))]
+        )
+    );
+
+    match two_dimensional_realizer(matrix) {
+        Some((first, second)) => {
+            println!("\n{}", t(Msg::DimensionAtMostTwo));
+            println!("  L1: {}", format_order(&first));
+            println!("  L2: {}", format_order(&second));
+        }
+        None => println!("\n{}", t(Msg::DimensionAtLeastThree)),
+    }
+
+    match product_of_chains_factors(matrix) {
+        Some(sizes) => {
+            let text = sizes
+                .iter()
+                .map(|s| s.to_string())
+                .collect::<Vec<String>>()
+                .join(" × ");
+            println!("{}", tf(Msg::ChainProduct, &[&text]));
+        }
+        None => println!("{}", t(Msg::NotChainProduct)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 주어진 순서쌍(0부터 시작)에 대각선을 더한 관계 행렬을 만드는 헬퍼 함수
+    fn relation(n: usize, pairs: &[(usize, usize)]) -> Matrix {
+        let mut matrix = vec![vec![0; n]; n];
+        for (i, row) in matrix.iter_mut().enumerate() {
+            row[i] = 1;
+        }
+        for &(a, b) in pairs {
+            matrix[a][b] = 1;
+        }
+        matrix
+    }
+
+    /// 0 < 1, 0 < 2, 1 < 3, 2 < 3 인 다이아몬드 (1과 2는 비교 불가능)
+    fn diamond() -> Matrix {
+        relation(4, &[(0, 1), (0, 2), (0, 3), (1, 3), (2, 3)])
+    }
+
+    #[test]
+    fn extrema_of_diamond() {
+        let matrix = diamond();
+        assert_eq!(minimal_elements(&matrix), vec![0]);
+        assert_eq!(maximal_elements(&matrix), vec![3]);
+        assert_eq!(least_element(&matrix), Some(0));
+        assert_eq!(greatest_element(&matrix), Some(3));
+        assert_eq!(incomparable_pairs(&matrix), vec![(1, 2)]);
+    }
+
+    #[test]
+    fn extrema_of_antichain() {
+        let matrix = relation(3, &[]);
+        assert_eq!(minimal_elements(&matrix), vec![0, 1, 2]);
+        assert_eq!(maximal_elements(&matrix), vec![0, 1, 2]);
+        assert_eq!(least_element(&matrix), None);
+        assert_eq!(greatest_element(&matrix), None);
+    }
+
+    #[test]
+    fn bounds_supremum_and_infimum() {
+        let matrix = diamond();
+        assert_eq!(upper_bounds(&matrix, &[1, 2]), vec![3]);
+        assert_eq!(lower_bounds(&matrix, &[1, 2]), vec![0]);
+        assert_eq!(supremum(&matrix, &[1, 2]), Some(3));
+        assert_eq!(infimum(&matrix, &[1, 2]), Some(0));
+        assert_eq!(supremum(&matrix, &[]), Some(0));
+
+        // 1, 2 위에 비교 불가능한 상계 3, 4가 있으면 상한이 없음
+        let bowtie = relation(4, &[(0, 2), (0, 3), (1, 2), (1, 3)]);
+        assert_eq!(upper_bounds(&bowtie, &[0, 1]), vec![2, 3]);
+        assert_eq!(supremum(&bowtie, &[0, 1]), None);
+    }
+
+    #[test]
+    fn chains_and_antichains() {
+        let matrix = diamond();
+        assert!(is_chain(&matrix, &[0, 1, 3]));
+        assert!(!is_chain(&matrix, &[1, 2]));
+        assert!(is_antichain(&matrix, &[1, 2]));
+        assert!(!is_antichain(&matrix, &[0, 1]));
+        assert!(is_chain(&matrix, &[]) && is_antichain(&matrix, &[]));
+    }
+
+    #[test]
+    fn strict_order_is_read_reflexively() {
+        let strict = vec![vec![0, 1], vec![0, 0]];
+        assert!(leq(&strict, 0, 0) && leq(&strict, 0, 1) && !leq(&strict, 1, 0));
+        assert_eq!(least_element(&strict), Some(0));
+    }
+
+    #[test]
+    fn formats_elements_one_based() {
+        assert_eq!(format_set(&[0, 2]), "{1, 3}");
+        assert_eq!(format_set(&[]), "{}");
+        assert_eq!(format_option(Some(4)), "5");
+    }
+
+    /// 1 × 2 × ... × n
+    fn factorial(n: u64) -> u64 {
+        (1..=n).product()
+    }
+
+    #[test]
+    fn antichain_has_factorial_linear_extensions() {
+        for n in 1..=6 {
+            let matrix = relation(n, &[]);
+            assert_eq!(count_linear_extensions(&matrix), Some(factorial(n as u64)));
+            assert_eq!(
+                linear_extensions(&matrix, usize::MAX).len() as u64,
+                factorial(n as u64)
+            );
+        }
+        assert_eq!(
+            count_linear_extensions(&relation(MAX_COUNT_SIZE, &[])),
+            Some(factorial(MAX_COUNT_SIZE as u64))
+        );
+        assert_eq!(
+            count_linear_extensions(&relation(MAX_COUNT_SIZE + 1, &[])),
+            None
+        );
+    }
+
+    #[test]
+    fn linear_extensions_of_diamond() {
+        let matrix = diamond();
+        assert_eq!(topological_sort(&matrix), Ok(vec![0, 1, 2, 3]));
+        assert_eq!(
+            linear_extensions(&matrix, 10),
+            vec![vec![0, 1, 2, 3], vec![0, 2, 1, 3]]
+        );
+        assert_eq!(linear_extensions(&matrix, 1), vec![vec![0, 1, 2, 3]]);
+        assert_eq!(count_linear_extensions(&matrix), Some(2));
+    }
+
+    #[test]
+    fn cycles_block_topological_sort() {
+        let cycle = relation(3, &[(0, 1), (1, 2), (2, 0)]);
+        let witness = find_cycle(&cycle).expect("사이클이 있어야 함");
+        assert_eq!(witness.len(), 3);
+        for (idx, &u) in witness.iter().enumerate() {
+            assert_eq!(cycle[u][witness[(idx + 1) % witness.len()]], 1);
+        }
+        assert!(!is_acyclic(&cycle));
+        assert!(topological_sort(&cycle).is_err());
+        assert!(linear_extensions(&cycle, 10).is_empty());
+        assert_eq!(count_linear_extensions(&cycle), None);
+        assert_eq!(random_linear_extension(&cycle, 1), None);
+        // 대각선만 있는 관계는 사이클로 보지 않음
+        assert!(is_acyclic(&relation(3, &[])));
+    }
+
+    #[test]
+    fn random_linear_extension_is_valid_and_deterministic() {
+        let matrix = relation(5, &[(0, 3), (1, 3), (3, 4), (2, 4)]);
+        let all = linear_extensions(&matrix, usize::MAX);
+        assert_eq!(count_linear_extensions(&matrix), Some(all.len() as u64));
+        let mut seen = std::collections::HashSet::new();
+        for seed in 0..200 {
+            let order = random_linear_extension(&matrix, seed).unwrap();
+            assert!(all.contains(&order));
+            assert_eq!(random_linear_extension(&matrix, seed), Some(order.clone()));
+            seen.insert(order);
+        }
+        assert_eq!(seen.len(), all.len());
+    }
+
+    /// 약수 관계 순서: 원소 k는 정수 k + 1을 나타냄
+    fn divisibility(n: usize) -> Matrix {
+        (1..=n)
+            .map(|a| (1..=n).map(|b| u8::from(b % a == 0)).collect())
+            .collect()
+    }
+
+    /// 분해가 원소 전체를 정확히 한 번씩 덮는지 확인하는 헬퍼 함수
+    fn assert_partition(parts: &[Vec<usize>], n: usize) {
+        let mut all: Vec<usize> = parts.iter().flatten().copied().collect();
+        all.sort_unstable();
+        assert_eq!(all, (0..n).collect::<Vec<usize>>());
+    }
+
+    #[test]
+    fn dilworth_width_matches_antichain_and_chain_cover() {
+        for matrix in [
+            diamond(),
+            relation(4, &[]),
+            divisibility(12),
+            divisibility(16),
+        ] {
+            let n = matrix.len();
+            let antichain = maximum_antichain(&matrix);
+            let cover = minimum_chain_cover(&matrix);
+            assert!(is_antichain(&matrix, &antichain));
+            assert_eq!(antichain.len(), cover.len());
+            assert_partition(&cover, n);
+            for chain in &cover {
+                assert!(chain.windows(2).all(|w| less(&matrix, w[0], w[1])));
+            }
+        }
+        // 1..12의 약수 관계에서 7..12는 서로 나누지 않음
+        assert_eq!(maximum_antichain(&divisibility(12)).len(), 6);
+    }
+
+    #[test]
+    fn mirsky_height_matches_longest_chain() {
+        for matrix in [
+            diamond(),
+            relation(4, &[]),
+            divisibility(12),
+            divisibility(16),
+        ] {
+            let chain = longest_chain(&matrix);
+            let levels = mirsky_decomposition(&matrix);
+            assert!(chain.windows(2).all(|w| less(&matrix, w[0], w[1])));
+            assert_eq!(chain.len(), levels.len());
+            assert_partition(&levels, matrix.len());
+            assert!(levels.iter().all(|level| is_antichain(&matrix, level)));
+        }
+        // 1 | 2 | 4 | 8 | 16
+        assert_eq!(longest_chain(&divisibility(16)), vec![0, 1, 3, 7, 15]);
+    }
+
+    #[test]
+    fn covering_pairs_form_hasse_diagram() {
+        assert_eq!(
+            covering_pairs(&diamond()),
+            vec![(0, 1), (0, 2), (1, 3), (2, 3)]
+        );
+        let not_order = relation(2, &[(0, 1), (1, 0)]);
+        assert!(covering_pairs(&not_order).is_empty());
+        assert!(minimum_chain_cover(&not_order).is_empty());
+        assert!(maximum_antichain(&not_order).is_empty());
+    }
+
+    /// 표준 예제 S3: a_i(0..3) < b_j(3..6) ⇔ i ≠ j, 차원이 3인 가장 작은 부분 순서
+    fn standard_example() -> Matrix {
+        let pairs: Vec<(usize, usize)> = (0..3)
+            .flat_map(|i| (0..3).filter(move |&j| j != i).map(move |j| (i, 3 + j)))
+            .collect();
+        relation(6, &pairs)
+    }
+
+    #[test]
+    fn realizer_intersects_to_the_order() {
+        for matrix in [diamond(), relation(3, &[]), divisibility(6)] {
+            let (first, second) = two_dimensional_realizer(&matrix).expect("차원 ≤ 2");
+            let n = matrix.len();
+            let position = |order: &[usize], x: usize| order.iter().position(|&y| y == x);
+            for a in 0..n {
+                for b in 0..n {
+                    let both = position(&first, a) <= position(&first, b)
+                        && position(&second, a) <= position(&second, b);
+                    assert_eq!(both, leq(&matrix, a, b), "({a}, {b})");
+                }
+            }
+        }
+        assert!(!is_two_dimensional(&standard_example()));
+    }
+
+    #[test]
+    fn comparability_graphs_are_complementary() {
+        let matrix = diamond();
+        let comparability = comparability_graph(&matrix);
+        let incomparability = incomparability_graph(&matrix);
+        assert_eq!(incomparability[1][2], 1);
+        assert_eq!(incomparability[2][1], 1);
+        for a in 0..4 {
+            for b in 0..4 {
+                let expected = u8::from(a != b);
+                assert_eq!(comparability[a][b] + incomparability[a][b], expected);
+            }
+        }
+    }
+
+    #[test]
+    fn embeddings_and_isomorphisms() {
+        let chain = relation(3, &[(0, 1), (0, 2), (1, 2)]);
+        let embedding = find_order_embedding(&chain, &divisibility(8)).expect("1 | 2 | 4");
+        assert!(embedding.windows(2).all(|w| w[0] != w[1]));
+        for a in 0..3 {
+            for b in 0..3 {
+                assert_eq!(
+                    leq(&chain, a, b),
+                    leq(&divisibility(8), embedding[a], embedding[b])
+                );
+            }
+        }
+        assert_eq!(find_order_embedding(&relation(4, &[]), &diamond()), None);
+        assert_eq!(find_order_isomorphism(&chain, &diamond()), None);
+        assert!(find_order_isomorphism(&chain_product(&[2, 2]), &diamond()).is_some());
+    }
+
+    #[test]
+    fn chain_products_are_recognized() {
+        assert_eq!(chain_product(&[3]), relation(3, &[(0, 1), (0, 2), (1, 2)]));
+        assert_eq!(product_of_chains_factors(&diamond()), Some(vec![2, 2]));
+        // 12의 약수 {1, 2, 3, 4, 6, 12}는 3 × 2 사슬 곱
+        let divisors: Vec<usize> = vec![1, 2, 3, 4, 6, 12];
+        let matrix: Matrix = divisors
+            .iter()
+            .map(|&a| divisors.iter().map(|&b| u8::from(b % a == 0)).collect())
+            .collect();
+        assert_eq!(product_of_chains_factors(&matrix), Some(vec![3, 2]));
+        assert_eq!(product_of_chains_factors(&standard_example()), None);
+    }
+}
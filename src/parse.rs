@@ -0,0 +1,370 @@
+/// 관계 표기 해석 모듈 - 집합 표기, 행 단위 행렬, CSV, JSON 문자열을 관계 행렬로 변환
+/// 원소 번호는 1부터 시작하는 표기를 사용하고 내부 행렬은 0부터 시작
+use crate::Matrix;
+use crate::i18n::{Msg, t, tf};
+
+/// 표기나 API로 만들 수 있는 관계의 최대 원소 개수 (행렬을 할당하기 전에 검사)
+pub const MAX_SIZE: usize = 1024;
+
+/// 원소 개수 n이 1 이상 MAX_SIZE 이하인지 검사하는 함수
+pub fn check_size(n: usize) -> Result<(), String> {
+    if n == 0 {
+        return Err(t(Msg::ParseEmpty).to_string());
+    }
+    if n > MAX_SIZE {
+        return Err(tf(Msg::ParseTooLarge, &[&n, &MAX_SIZE]));
+    }
+    Ok(())
+}
+
+/// 행렬이 비어 있지 않은 정사각 0/1 행렬인지 검사하는 함수
+pub fn validate_matrix(matrix: &Matrix) -> Result<(), String> {
+    let n = matrix.len();
+    check_size(n)?;
+    for (i, row) in matrix.iter().enumerate() {
+        if row.len() != n {
+            return Err(tf(Msg::ParseNotSquare, &[&(i + 1), &row.len(), &n]));
+        }
+        if let Some(value) = row.iter().find(|&&v| v > 1) {
+            return Err(tf(Msg::ParseNotBinary, &[value]));
+        }
+    }
+    Ok(())
+}
+
+/// 숫자 하나를 해석하는 헬퍼 함수
+fn parse_number(text: &str) -> Result<usize, String> {
+    text.trim()
+        .parse::<usize>()
+        .map_err(|_| tf(Msg::ParseBadNumber, &[&text.trim()]))
+}
+
+/// {(1, 2), (2, 3)} 형식의 순서쌍 집합을 관계 행렬로 변환하는 함수
+/// size가 None이면 등장한 가장 큰 원소를 크기로 사용
+pub fn parse_pair_set(text: &str, size: Option<usize>) -> Result<Matrix, String> {
+    let inner = text
+        .trim()
+        .strip_prefix('{')
+        .and_then(|rest| rest.strip_suffix('}'))
+        .ok_or_else(|| t(Msg::ParseBadSet).to_string())?;
+
+    let mut pairs = Vec::new();
+    let mut rest = inner.trim();
+    while !rest.is_empty() {
+        let body = rest
+            .strip_prefix('(')
+            .ok_or_else(|| t(Msg::ParseBadSet).to_string())?;
+        let close = body
+            .find(')')
+            .ok_or_else(|| t(Msg::ParseBadSet).to_string())?;
+        let (a, b) = body[..close]
+            .split_once(',')
+            .ok_or_else(|| t(Msg::ParseBadSet).to_string())?;
+        pairs.push((parse_number(a)?, parse_number(b)?));
+        rest = body[close + 1..].trim_start();
+        rest = rest.strip_prefix(',').unwrap_or(rest).trim_start();
+    }
+
+    let largest = pairs.iter().map(|&(a, b)| a.max(b)).max().unwrap_or(0);
+    let n = size.unwrap_or(largest);
+    check_size(n)?;
+    if let Some(&(a, b)) = pairs
+        .iter()
+        .find(|&&(a, b)| a == 0 || b == 0 || a > n || b > n)
+    {
+        return Err(tf(Msg::ParseOutOfRange, &[&a, &b, &n]));
+    }
+    let mut matrix = vec![vec![0; n]; n];
+    for (a, b) in pairs {
+        matrix[a - 1][b - 1] = 1;
+    }
+    validate_matrix(&matrix)?;
+    Ok(matrix)
+}
+
+/// [1 0 1; 0 1 0; 1 0 1] 형식 (행은 ;, 칸은 공백 또는 ,로 구분)의 행렬을 변환하는 함수
+pub fn parse_matrix_rows(text: &str) -> Result<Matrix, String> {
+    let inner = text
+        .trim()
+        .strip_prefix('[')
+        .and_then(|rest| rest.strip_suffix(']'))
+        .ok_or_else(|| t(Msg::ParseBadMatrix).to_string())?;
+    let matrix = parse_rows(inner.split(';'))?;
+    validate_matrix(&matrix)?;
+    Ok(matrix)
+}
+
+/// 여러 줄의 0/1 행을 행렬로 모으는 헬퍼 함수 (빈 줄은 무시)
+fn parse_rows<'a>(rows: impl Iterator<Item = &'a str>) -> Result<Matrix, String> {
+    rows.filter(|row| !row.trim().is_empty())
+        .map(|row| {
+            row.split(|c: char| c == ',' || c.is_whitespace())
+                .filter(|cell| !cell.is_empty())
+                .map(|cell| {
+                    cell.parse::<u8>()
+                        .map_err(|_| tf(Msg::ParseBadNumber, &[&cell]))
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// 한 줄에 한 행씩 쉼표로 구분된 CSV를 관계 행렬로 변환하는 함수
+pub fn parse_csv(text: &str) -> Result<Matrix, String> {
+    let matrix = parse_rows(text.lines())?;
+    validate_matrix(&matrix)?;
+    Ok(matrix)
+}
+
+/// 관계 행렬을 CSV 문자열로 변환하는 함수
+pub fn to_csv(matrix: &Matrix) -> String {
+    matrix
+        .iter()
+        .map(|row| {
+            let cells: Vec<String> = row.iter().map(|v| v.to_string()).collect();
+            format!("{}\n", cells.join(","))
+        })
+        .collect()
+}
+
+/// [[1, 0], [0, 1]] 형식의 JSON 배열을 관계 행렬로 변환하는 함수
+/// JSON 보고서처럼 객체가 주어지면 "matrix" 항목의 배열을 사용
+pub fn parse_json_matrix(text: &str) -> Result<Matrix, String> {
+    let text = text.trim();
+    let array = if text.starts_with('{') {
+        let key = text
+            .find("\"matrix\"")
+            .ok_or_else(|| t(Msg::ParseBadJson).to_string())?;
+        let start = text[key..]
+            .find('[')
+            .map(|offset| key + offset)
+            .ok_or_else(|| t(Msg::ParseBadJson).to_string())?;
+        // 바깥 배열이 닫히는 위치까지 자름
+        let mut depth = 0;
+        let end = text[start..]
+            .char_indices()
+            .find(|&(_, c)| {
+                match c {
+                    '[' => depth += 1,
+                    ']' => depth -= 1,
+                    _ => {}
+                }
+                depth == 0
+            })
+            .map(|(offset, _)| start + offset)
+            .ok_or_else(|| t(Msg::ParseBadJson).to_string())?;
+        &text[start..=end]
+    } else {
+        text
+    };
+
+    let inner = array
+        .strip_prefix('[')
+        .and_then(|rest| rest.strip_suffix(']'))
+        .ok_or_else(|| t(Msg::ParseBadJson).to_string())?;
+    let mut rows = Vec::new();
+    for part in inner.split(']') {
+        let part = part.trim().trim_start_matches(',').trim();
+        if part.is_empty() {
+            continue;
+        }
+        let row = part
+            .strip_prefix('[')
+            .ok_or_else(|| t(Msg::ParseBadJson).to_string())?;
+        rows.push(row);
+    }
+    let matrix = parse_rows(rows.into_iter())?;
+    validate_matrix(&matrix)?;
+    Ok(matrix)
+}
+
+/// 표기 형식을 첫 글자로 판단하여 관계 행렬로 변환하는 함수
+/// {…}는 순서쌍 집합 (뒤에 "on N"을 붙이면 원소 개수 지정), [[…]]는 JSON, […]는 행 단위 행렬
+pub fn parse_relation(text: &str) -> Result<Matrix, String> {
+    let text = text.trim();
+    if text.starts_with('{') {
+        match text.rsplit_once("on") {
+            Some((set, size)) if set.trim_end().ends_with('}') => {
+                parse_pair_set(set, Some(parse_number(size)?))
+            }
+            _ => parse_pair_set(text, None),
+        }
+    } else if text.starts_with("[[") || text.starts_with("[ [") {
+        parse_json_matrix(text)
+    } else if text.starts_with('[') {
+        parse_matrix_rows(text)
+    } else {
+        Err(tf(Msg::ParseUnknownNotation, &[&text]))
+    }
+}
+
+/// 파일 확장자(.json 또는 그 외 CSV)에 따라 관계 행렬 파일을 읽는 함수
+pub fn load_relation(path: &std::path::Path) -> Result<Matrix, String> {
+    let text = std::fs::read_to_string(path).map_err(|e| e.to_string())?;
+    match path.extension().and_then(|e| e.to_str()) {
+        Some("json") => parse_json_matrix(&text),
+        _ => parse_csv(&text),
+    }
+}
+
+/// 파일 확장자(.json 또는 그 외 CSV)에 따라 관계 행렬을 파일로 저장하는 함수
+pub fn save_relation(matrix: &Matrix, path: &std::path::Path) -> Result<(), String> {
+    let text = match path.extension().and_then(|e| e.to_str()) {
+        Some("json") => format!("{}\n", super::render::json_matrix(matrix)),
+        _ => to_csv(matrix),
+    };
+    std::fs::write(path, text).map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::order::{SimpleRng, random_relation};
+
+    /// 크기가 1부터 8까지인 임의 관계 행렬 목록을 만드는 헬퍼 함수
+    fn random_matrices() -> Vec<Matrix> {
+        let mut rng = SimpleRng::new(47);
+        (0..40)
+            .map(|k| random_relation(&mut rng, k % 8 + 1, 2))
+            .collect()
+    }
+
+    /// 관계 행렬을 {(1, 2), (2, 3)} on n 형식으로 쓰는 헬퍼 함수
+    fn to_pair_set(matrix: &Matrix) -> String {
+        let n = matrix.len();
+        let pairs: Vec<String> = (0..n)
+            .flat_map(|i| (0..n).map(move |j| (i, j)))
+            .filter(|&(i, j)| matrix[i][j] == 1)
+            .map(|(i, j)| format!("({}, {})", i + 1, j + 1))
+            .collect();
+        format!("{{{}}} on {}", pairs.join(", "), n)
+    }
+
+    #[test]
+    fn csv_round_trip() {
+        for matrix in random_matrices() {
+            assert_eq!(parse_csv(&to_csv(&matrix)), Ok(matrix));
+        }
+    }
+
+    #[test]
+    fn json_round_trip() {
+        for matrix in random_matrices() {
+            let json = crate::render::json_matrix(&matrix);
+            assert_eq!(parse_json_matrix(&json).as_ref(), Ok(&matrix));
+            assert_eq!(parse_relation(&json).as_ref(), Ok(&matrix));
+            // 보고서 객체 안의 "matrix" 항목도 읽음
+            let report = format!(
+                "{{\"size\": {}, \"matrix\": {}, \"ok\": true}}",
+                matrix.len(),
+                json
+            );
+            assert_eq!(parse_json_matrix(&report), Ok(matrix));
+        }
+    }
+
+    #[test]
+    fn set_notation_round_trip() {
+        for matrix in random_matrices() {
+            assert_eq!(parse_relation(&to_pair_set(&matrix)), Ok(matrix));
+        }
+    }
+
+    #[test]
+    fn notations_agree() {
+        let expected = vec![vec![1, 1, 0], vec![0, 0, 1], vec![0, 0, 0]];
+        for text in [
+            "{(1, 1), (1, 2), (2, 3)}",
+            "{(1,1),(1,2),(2,3),}",
+            "[1 1 0; 0 0 1; 0 0 0]",
+            "[1,1,0; 0,0,1; 0,0,0]",
+            "[[1, 1, 0], [0, 0, 1], [0, 0, 0]]",
+            "[ [1,1,0],[0,0,1],[0,0,0] ]",
+        ] {
+            assert_eq!(parse_relation(text).as_ref(), Ok(&expected), "{}", text);
+        }
+        assert_eq!(parse_csv("1,1,0\n\n0,0,1\n0,0,0\n"), Ok(expected));
+    }
+
+    #[test]
+    fn set_size_is_largest_element_unless_given() {
+        assert_eq!(parse_relation("{(2, 2)}"), Ok(vec![vec![0, 0], vec![0, 1]]));
+        assert_eq!(parse_relation("{} on 2"), Ok(vec![vec![0; 2]; 2]));
+        assert_eq!(
+            parse_relation("{(3, 1)} on 2"),
+            Err(tf(Msg::ParseOutOfRange, &[&3, &1, &2]))
+        );
+        assert_eq!(
+            parse_relation("{(0, 1)}"),
+            Err(tf(Msg::ParseOutOfRange, &[&0, &1, &1]))
+        );
+    }
+
+    #[test]
+    fn size_is_limited() {
+        assert!(check_size(MAX_SIZE).is_ok());
+        assert_eq!(check_size(0), Err(t(Msg::ParseEmpty).to_string()));
+        let too_large = MAX_SIZE + 1;
+        assert_eq!(
+            check_size(too_large),
+            Err(tf(Msg::ParseTooLarge, &[&too_large, &MAX_SIZE]))
+        );
+        // 행렬을 할당하기 전에 거부
+        assert_eq!(
+            parse_relation("{(1, 1000000000)}"),
+            Err(tf(Msg::ParseTooLarge, &[&1000000000, &MAX_SIZE]))
+        );
+    }
+
+    #[test]
+    fn malformed_input_is_rejected() {
+        assert_eq!(
+            parse_relation("{(1, 2)"),
+            Err(t(Msg::ParseBadSet).to_string())
+        );
+        assert_eq!(
+            parse_relation("{(1 2)}"),
+            Err(t(Msg::ParseBadSet).to_string())
+        );
+        assert_eq!(
+            parse_relation("{(1, x)}"),
+            Err(tf(Msg::ParseBadNumber, &[&"x"]))
+        );
+        assert_eq!(
+            parse_relation("[1 0; 0 1"),
+            Err(t(Msg::ParseBadMatrix).to_string())
+        );
+        assert_eq!(
+            parse_relation("[1 0; 0]"),
+            Err(tf(Msg::ParseNotSquare, &[&2, &1, &2]))
+        );
+        assert_eq!(
+            parse_csv("1,2\n0,1\n"),
+            Err(tf(Msg::ParseNotBinary, &[&2u8]))
+        );
+        assert_eq!(parse_csv(""), Err(t(Msg::ParseEmpty).to_string()));
+        assert_eq!(
+            parse_json_matrix("{\"size\": 2}"),
+            Err(t(Msg::ParseBadJson).to_string())
+        );
+        assert_eq!(
+            parse_relation("R"),
+            Err(tf(Msg::ParseUnknownNotation, &[&"R"]))
+        );
+    }
+
+    #[test]
+    fn files_round_trip_by_extension() {
+        let dir = std::env::temp_dir().join(format!("relation-parse-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let matrix = vec![vec![1, 0, 1], vec![0, 1, 0], vec![1, 0, 1]];
+        for name in ["r.csv", "r.json"] {
+            let path = dir.join(name);
+            save_relation(&matrix, &path).unwrap();
+            assert_eq!(load_relation(&path).as_ref(), Ok(&matrix), "{}", name);
+        }
+        let json = std::fs::read_to_string(dir.join("r.json")).unwrap();
+        assert!(json.starts_with("[["));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,261 @@
+/// 분할 격자(partition lattice) 연산과 두 분할 사이의 거리를 계산하는 모듈
+/// 동치 관계와 그 동치류 분할을 같은 것으로 보고 만남, 이음, 세분 관계를 다룸
+use crate::Matrix;
+use crate::i18n::{Msg, mark, t, tf};
+
+/// 두 동치 관계가 같은 집합 위의 동치 관계인지 확인하는 헬퍼 함수
+fn both_equivalences(first: &Matrix, second: &Matrix) -> bool {
+    first.len() == second.len()
+        && super::equivalence::is_equivalence_relation(first)
+        && super::equivalence::is_equivalence_relation(second)
+}
+
+/// 두 분할의 만남(meet, 공통 세분)을 반환하는 함수
+/// 두 동치 관계의 교집합이며, 두 동치 관계가 아니면 None
+pub fn partition_meet(first: &Matrix, second: &Matrix) -> Option<Matrix> {
+    if !both_equivalences(first, second) {
+        return None;
+    }
+    let n = first.len();
+    let mut result = vec![vec![0; n]; n];
+    for i in 0..n {
+        for j in 0..n {
+            result[i][j] = first[i][j] & second[i][j];
+        }
+    }
+    Some(result)
+}
+
+/// 두 분할의 이음(join, 가장 세밀한 공통 조대화)을 반환하는 함수
+/// 두 동치 관계의 합집합에 동치 폐포를 적용하며, 두 동치 관계가 아니면 None
+pub fn partition_join(first: &Matrix, second: &Matrix) -> Option<Matrix> {
+    if !both_equivalences(first, second) {
+        return None;
+    }
+    let n = first.len();
+    let mut union = vec![vec![0; n]; n];
+    for i in 0..n {
+        for j in 0..n {
+            union[i][j] = first[i][j] | second[i][j];
+        }
+    }
+    Some(super::closure::equivalence_closure(&union))
+}
+
+/// finer가 coarser의 세분(refinement)인지 판별하는 함수
+/// finer의 모든 동치류가 coarser의 어떤 동치류에 포함되어야 함 (finer ⊆ coarser)
+pub fn is_refinement(finer: &Matrix, coarser: &Matrix) -> bool {
+    if !both_equivalences(finer, coarser) {
+        return false;
+    }
+    let n = finer.len();
+    (0..n).all(|i| (0..n).all(|j| finer[i][j] == 0 || coarser[i][j] == 1))
+}
+
+/// 두 분할이 서로 다르게 판단하는 원소 쌍 {i, j} (i < j)의 목록을 반환하는 함수
+/// 한 분할에서는 같은 동치류, 다른 분할에서는 서로 다른 동치류에 속하는 쌍
+/// 두 동치 관계가 아니면 None
+pub fn pair_disagreements(first: &Matrix, second: &Matrix) -> Option<Vec<(usize, usize)>> {
+    if !both_equivalences(first, second) {
+        return None;
+    }
+    let n = first.len();
+    let mut pairs = Vec::new();
+    for i in 0..n {
+        for j in (i + 1)..n {
+            if first[i][j] != second[i][j] {
+                pairs.push((i, j));
+            }
+        }
+    }
+    Some(pairs)
+}
+
+/// 두 분할의 Rand 지수를 계산하는 함수
+/// 전체 C(n, 2)개의 쌍 중 두 분할이 같은 판단을 내리는 쌍의 비율 (0 ~ 1, 같은 분할이면 1)
+pub fn rand_index(first: &Matrix, second: &Matrix) -> Option<f64> {
+    let disagreements = pair_disagreements(first, second)?.len();
+    let n = first.len();
+    let total = n * n.saturating_sub(1) / 2;
+    if total == 0 {
+        return Some(1.0);
+    }
+    Some((total - disagreements) as f64 / total as f64)
+}
+
+/// 두 분할 사이의 정보 변화량(variation of information)을 비트 단위로 계산하는 함수
+/// VI = H(X) + H(Y) - 2 I(X; Y), 같은 분할이면 0
+pub fn variation_of_information(first: &Matrix, second: &Matrix) -> Option<f64> {
+    if !both_equivalences(first, second) {
+        return None;
+    }
+    let n = first.len();
+    if n == 0 {
+        return Some(0.0);
+    }
+    let xs = super::visualize::equivalence_classes(first);
+    let ys = super::visualize::equivalence_classes(second);
+    let total = n as f64;
+
+    let mut vi = 0.0;
+    for x in &xs {
+        for y in &ys {
+            let common = x.iter().filter(|e| y.contains(e)).count();
+            if common == 0 {
+                continue;
+            }
+            let r = common as f64 / total;
+            let p = x.len() as f64 / total;
+            let q = y.len() as f64 / total;
+            vi -= r * ((r / p).log2() + (r / q).log2());
+        }
+    }
+    Some(vi.max(0.0))
+}
+
+/// 동치류 분할을 {1, 2} | {3} 형식의 문자열로 변환하는 헬퍼 함수
+fn format_partition(matrix: &Matrix) -> String {
+    super::visualize::equivalence_classes(matrix)
+        .iter()
+        .map(|class| super::order::format_set(class))
+        .collect::<Vec<String>>()
+        .join(" | ")
+}
+
+/// 두 분할의 만남, 이음, 세분 관계와 거리를 출력하는 함수
+pub fn print_partition_comparison(
+    first: &Matrix,
+    first_name: &str,
+    second: &Matrix,
+    second_name: &str,
+) {
+    println!(
+        "\n=== {} ===",
+        tf(Msg::PartitionTitle, &[&first_name, &second_name])
+    );
+
+    if !both_equivalences(first, second) {
+        println!("{}", t(Msg::PartitionNeedsEquivalences));
+        return;
+    }
+
+    println!("{}: {}", first_name, format_partition(first));
+    println!("{}: {}", second_name, format_partition(second));
+
+    if let Some(meet) = partition_meet(first, second) {
+        println!("{}", tf(Msg::PartitionMeet, &[&format_partition(&meet)]));
+    }
+    if let Some(join) = partition_join(first, second) {
+        println!("{}", tf(Msg::PartitionJoin, &[&format_partition(&join)]));
+    }
+
+    println!(
+        "\n{}",
+        tf(
+            Msg::PartitionRefines,
+            &[
+                &first_name,
+                &second_name,
+                &mark(is_refinement(first, second))
+            ]
+        )
+    );
+    println!(
+        "{}",
+        tf(
+            Msg::PartitionRefines,
+            &[
+                &second_name,
+                &first_name,
+                &mark(is_refinement(second, first))
+            ]
+        )
+    );
+
+    let disagreements = pair_disagreements(first, second).unwrap_or_default();
+    let pairs = disagreements
+        .iter()
+        .map(|&(i, j)| format!("{{{}, {}}}", i + 1, j + 1))
+        .collect::<Vec<String>>()
+        .join(", ");
+    println!(
+        "\n{}",
+        tf(Msg::PartitionDisagreements, &[&disagreements.len(), &pairs])
+    );
+    if let Some(rand) = rand_index(first, second) {
+        println!("{}", tf(Msg::RandIndex, &[&format!("{:.4}", rand)]));
+    }
+    if let Some(vi) = variation_of_information(first, second) {
+        println!(
+            "{}",
+            tf(Msg::VariationOfInformation, &[&format!("{:.4}", vi)])
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::equivalence::kernel_relation;
+
+    #[test]
+    fn identical_partitions_have_no_distance() {
+        for labels in [vec![0, 0, 1, 1, 2], vec![0, 1, 2, 3], vec![0, 0, 0]] {
+            let matrix = kernel_relation(&labels);
+            assert_eq!(rand_index(&matrix, &matrix), Some(1.0));
+            assert_eq!(variation_of_information(&matrix, &matrix), Some(0.0));
+            assert_eq!(pair_disagreements(&matrix, &matrix), Some(Vec::new()));
+        }
+    }
+
+    #[test]
+    fn distances_between_coarse_and_fine() {
+        let coarse = kernel_relation(&[0, 0, 1, 1]);
+        let fine = kernel_relation(&[0, 1, 2, 3]);
+        assert_eq!(
+            pair_disagreements(&coarse, &fine),
+            Some(vec![(0, 1), (2, 3)])
+        );
+        assert_eq!(rand_index(&coarse, &fine), Some(4.0 / 6.0));
+        // H(fine) = 2, H(coarse) = 1, I = 1 → VI = 1
+        let vi = variation_of_information(&coarse, &fine).unwrap();
+        assert!((vi - 1.0).abs() < 1e-12);
+        assert_eq!(variation_of_information(&fine, &coarse), Some(vi));
+    }
+
+    #[test]
+    fn meet_and_join_in_partition_lattice() {
+        let first = kernel_relation(&[0, 0, 1, 1, 2]);
+        let second = kernel_relation(&[0, 1, 1, 2, 2]);
+        assert_eq!(
+            partition_meet(&first, &second),
+            Some(kernel_relation(&[0, 1, 2, 3, 4]))
+        );
+        assert_eq!(
+            partition_join(&first, &second),
+            Some(kernel_relation(&[0, 0, 0, 0, 0]))
+        );
+        let meet = partition_meet(&first, &second).unwrap();
+        assert!(is_refinement(&meet, &first) && is_refinement(&meet, &second));
+        assert!(!is_refinement(&first, &second));
+    }
+
+    #[test]
+    fn operations_require_equivalences() {
+        let equivalence = kernel_relation(&[0, 0]);
+        let not_symmetric = vec![vec![1, 1], vec![0, 1]];
+        assert_eq!(partition_meet(&equivalence, &not_symmetric), None);
+        assert_eq!(partition_join(&not_symmetric, &equivalence), None);
+        assert_eq!(rand_index(&equivalence, &not_symmetric), None);
+        assert_eq!(pair_disagreements(&not_symmetric, &equivalence), None);
+        assert_eq!(
+            pair_disagreements(&equivalence, &kernel_relation(&[0, 0, 1])),
+            None
+        );
+        assert_eq!(
+            variation_of_information(&equivalence, &kernel_relation(&[0])),
+            None
+        );
+        assert!(!is_refinement(&not_symmetric, &equivalence));
+    }
+}
@@ -0,0 +1,327 @@
+/// Python 확장 모듈 - Jupyter 등에서 Relation 클래스로 속성 판별, 폐포, 동치류를 사용
+/// python 기능을 켜고 maturin으로 빌드하며, 원소 번호는 Python/NumPy 관례에 맞춰 0부터 시작
+/// 중첩 리스트와 tolist()를 가진 NumPy 스타일 배열을 받고, numpy.asarray(relation)으로 변환 가능
+use pyo3::exceptions::{PyIndexError, PyValueError};
+use pyo3::prelude::*;
+
+use crate::Matrix;
+use crate::i18n::{Msg, t, tf};
+use crate::report::ClosureKind;
+
+/// 0/1 관계 행렬을 감싼 Python 클래스
+#[pyclass(name = "Relation", module = "discrete_mathematics_equivalence", eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PyRelation {
+    matrix: Matrix,
+}
+
+/// 0/1 값 하나를 해석하는 헬퍼 함수 - 정수, bool, 정확히 0.0 또는 1.0인 실수를 허용
+fn extract_cell(value: &Bound<'_, PyAny>) -> PyResult<u8> {
+    let cell = match value.extract::<i64>() {
+        Ok(number) => u8::try_from(number).ok(),
+        Err(_) => match value.extract::<f64>() {
+            Ok(0.0) => Some(0),
+            Ok(1.0) => Some(1),
+            _ => None,
+        },
+    };
+    match cell {
+        Some(cell @ (0 | 1)) => Ok(cell),
+        _ => Err(PyValueError::new_err(tf(
+            Msg::ParseNotBinary,
+            &[&value.repr()?],
+        ))),
+    }
+}
+
+/// 중첩 리스트 또는 tolist()를 가진 객체를 관계 행렬로 바꾸는 헬퍼 함수
+fn extract_matrix(data: &Bound<'_, PyAny>) -> PyResult<Matrix> {
+    let rows: Vec<Vec<Bound<'_, PyAny>>> = if data.hasattr("tolist")? {
+        data.call_method0("tolist")?.extract()?
+    } else {
+        data.extract()?
+    };
+    let matrix = rows
+        .iter()
+        .map(|row| row.iter().map(extract_cell).collect())
+        .collect::<PyResult<Matrix>>()?;
+    super::parse::validate_matrix(&matrix).map_err(PyValueError::new_err)?;
+    Ok(matrix)
+}
+
+impl PyRelation {
+    /// (i, j)가 범위 안인지 검사하는 헬퍼 함수
+    fn check_index(&self, (i, j): (usize, usize)) -> PyResult<()> {
+        let n = self.matrix.len();
+        if i >= n || j >= n {
+            return Err(PyIndexError::new_err(tf(
+                Msg::PyIndexOutOfRange,
+                &[&i, &j, &(n - 1)],
+            )));
+        }
+        Ok(())
+    }
+
+    /// 폐포를 새 Relation으로 반환하는 헬퍼 함수
+    fn closed(&self, kind: ClosureKind) -> PyRelation {
+        PyRelation {
+            matrix: kind.trace(&self.matrix).matrix,
+        }
+    }
+}
+
+#[pymethods]
+impl PyRelation {
+    /// 중첩 리스트 또는 NumPy 스타일 0/1 배열로 관계를 만드는 생성자
+    #[new]
+    fn new(data: &Bound<'_, PyAny>) -> PyResult<Self> {
+        Ok(PyRelation {
+            matrix: extract_matrix(data)?,
+        })
+    }
+
+    /// 원소 n개의 빈 관계 (n은 1 이상 1024 이하)
+    #[staticmethod]
+    fn empty(n: usize) -> PyResult<Self> {
+        super::parse::check_size(n).map_err(PyValueError::new_err)?;
+        Ok(PyRelation {
+            matrix: vec![vec![0; n]; n],
+        })
+    }
+
+    /// 순서쌍 목록과 원소 개수 n으로 관계를 만드는 함수
+    #[staticmethod]
+    fn from_pairs(pairs: Vec<(usize, usize)>, n: usize) -> PyResult<Self> {
+        let mut relation = PyRelation::empty(n)?;
+        for pair in pairs {
+            relation.check_index(pair)?;
+            relation.matrix[pair.0][pair.1] = 1;
+        }
+        Ok(relation)
+    }
+
+    /// 0/1 정수의 중첩 리스트로 변환
+    fn to_list(&self) -> Vec<Vec<i64>> {
+        self.matrix
+            .iter()
+            .map(|row| row.iter().map(|&v| i64::from(v)).collect())
+            .collect()
+    }
+
+    /// R(i, j) = 1인 순서쌍 목록
+    fn pairs(&self) -> Vec<(usize, usize)> {
+        let n = self.matrix.len();
+        (0..n)
+            .flat_map(|i| (0..n).map(move |j| (i, j)))
+            .filter(|&(i, j)| self.matrix[i][j] == 1)
+            .collect()
+    }
+
+    /// numpy.asarray(relation) 지원 - numpy가 설치되어 있을 때만 동작
+    #[pyo3(signature = (dtype=None, copy=None))]
+    fn __array__(
+        &self,
+        py: Python<'_>,
+        dtype: Option<Bound<'_, PyAny>>,
+        copy: Option<bool>,
+    ) -> PyResult<PyObject> {
+        let _ = copy;
+        let numpy = py.import("numpy")?;
+        let dtype = match dtype {
+            Some(dtype) => dtype,
+            None => numpy.getattr("uint8")?,
+        };
+        Ok(numpy
+            .call_method1("array", (self.to_list(), dtype))?
+            .unbind())
+    }
+
+    fn __len__(&self) -> usize {
+        self.matrix.len()
+    }
+
+    fn __getitem__(&self, index: (usize, usize)) -> PyResult<u8> {
+        self.check_index(index)?;
+        Ok(self.matrix[index.0][index.1])
+    }
+
+    /// 생성자와 같은 규칙으로 0/1 값을 받음 (r[0, 1] = 1, True, 1.0 모두 허용)
+    fn __setitem__(&mut self, index: (usize, usize), value: &Bound<'_, PyAny>) -> PyResult<()> {
+        self.check_index(index)?;
+        self.matrix[index.0][index.1] = extract_cell(value)?;
+        Ok(())
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Relation({})", super::render::json_matrix(&self.matrix))
+    }
+
+    fn is_reflexive(&self) -> bool {
+        super::equivalence::is_reflexive(&self.matrix)
+    }
+
+    fn is_symmetric(&self) -> bool {
+        super::equivalence::is_symmetric(&self.matrix)
+    }
+
+    fn is_transitive(&self) -> bool {
+        super::equivalence::is_transitive(&self.matrix)
+    }
+
+    fn is_equivalence_relation(&self) -> bool {
+        super::equivalence::is_equivalence_relation(&self.matrix)
+    }
+
+    fn is_antisymmetric(&self) -> bool {
+        super::equivalence::is_antisymmetric(&self.matrix)
+    }
+
+    fn is_irreflexive(&self) -> bool {
+        super::equivalence::is_irreflexive(&self.matrix)
+    }
+
+    fn is_connected_relation(&self) -> bool {
+        super::equivalence::is_connected_relation(&self.matrix)
+    }
+
+    fn is_partial_order(&self) -> bool {
+        super::equivalence::is_partial_order(&self.matrix)
+    }
+
+    fn is_lattice(&self) -> bool {
+        super::equivalence::is_lattice(&self.matrix)
+    }
+
+    fn reflexive_closure(&self) -> PyRelation {
+        self.closed(ClosureKind::Reflexive)
+    }
+
+    fn symmetric_closure(&self) -> PyRelation {
+        self.closed(ClosureKind::Symmetric)
+    }
+
+    fn transitive_closure(&self) -> PyRelation {
+        self.closed(ClosureKind::Transitive)
+    }
+
+    fn equivalence_closure(&self) -> PyRelation {
+        self.closed(ClosureKind::Equivalence)
+    }
+
+    /// 이름으로 폐포 계산 (refl, sym, trans, equiv)
+    fn closure(&self, kind: &str) -> PyResult<PyRelation> {
+        ClosureKind::parse(kind)
+            .map(|kind| self.closed(kind))
+            .ok_or_else(|| PyValueError::new_err(tf(Msg::UnknownClosure, &[&kind])))
+    }
+
+    /// 동치류 목록 (동치 관계가 아니면 ValueError)
+    fn equivalence_classes(&self) -> PyResult<Vec<Vec<usize>>> {
+        if !self.is_equivalence_relation() {
+            return Err(PyValueError::new_err(t(Msg::NoEquivalenceClasses)));
+        }
+        Ok(super::visualize::equivalence_classes(&self.matrix))
+    }
+
+    /// --format json과 같은 JSON 분석 보고서 (원소 번호는 1부터 시작)
+    fn report_json(&self) -> String {
+        let report = super::report::analyze_relation(&self.matrix);
+        super::render::Renderer::render(&super::render::JsonRenderer, &report)
+    }
+}
+
+/// 오류 메시지 언어 설정 ("ko" 또는 "en")
+#[pyfunction]
+fn set_language(code: &str) -> PyResult<()> {
+    let lang = super::i18n::Lang::parse(code)
+        .ok_or_else(|| PyValueError::new_err(tf(Msg::UnknownLang, &[&code])))?;
+    super::i18n::set_lang(lang);
+    Ok(())
+}
+
+/// Python 모듈 진입점
+/// 명령행과 같이 LC_ALL/LC_MESSAGES/LANG 환경 변수로 메시지 언어를 정함
+#[pymodule(name = "discrete_mathematics_equivalence")]
+fn python_module(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    if let Some(lang) = super::i18n::Lang::from_env() {
+        super::i18n::set_lang(lang);
+    }
+    module.add_class::<PyRelation>()?;
+    module.add_function(wrap_pyfunction!(set_language, module)?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pyo3::types::PyDict;
+    use std::ffi::CString;
+
+    /// Relation 클래스가 있는 내장 인터프리터에서 코드를 실행하고 result 변수의 repr을 반환하는 헬퍼 함수
+    /// 예외가 나면 예외 타입 이름을 Err로 반환
+    fn run(code: &str) -> Result<String, String> {
+        pyo3::prepare_freethreaded_python();
+        Python::with_gil(|py| {
+            let locals = PyDict::new(py);
+            locals
+                .set_item("Relation", py.get_type::<PyRelation>())
+                .unwrap();
+            let code = CString::new(code).unwrap();
+            let error = |e: PyErr| e.get_type(py).name().unwrap().to_string();
+            py.run(&code, None, Some(&locals)).map_err(error)?;
+            let result = locals
+                .get_item("result")
+                .unwrap()
+                .expect("result가 있어야 함");
+            Ok(result.repr().unwrap().to_string())
+        })
+    }
+
+    #[test]
+    fn relations_are_constructed_from_lists_and_pairs() {
+        assert_eq!(
+            run("result = Relation([[1, 0], [0, 1]])"),
+            Ok("Relation([[1, 0], [0, 1]])".to_string())
+        );
+        // bool과 0.0/1.0 실수도 0/1 값으로 받음
+        assert_eq!(
+            run("result = Relation([[1.0, False], [True, 1]]).to_list()"),
+            Ok("[[1, 0], [1, 1]]".to_string())
+        );
+        assert_eq!(
+            run("result = Relation.from_pairs([(0, 1), (1, 2)], 3).transitive_closure().pairs()"),
+            Ok("[(0, 1), (0, 2), (1, 2)]".to_string())
+        );
+        assert_eq!(run("result = len(Relation.empty(4))"), Ok("4".to_string()));
+    }
+
+    #[test]
+    fn cells_are_read_and_written_like_the_constructor() {
+        let code = "r = Relation.empty(2)
+r[0, 1] = 1
+r[1, 0] = True
+r[1, 1] = 1.0
+r[1, 1] = 0
+result = (r.to_list(), r[0, 1], r[0, 0])";
+        assert_eq!(run(code), Ok("([[0, 1], [1, 0]], 1, 0)".to_string()));
+    }
+
+    #[test]
+    fn invalid_input_raises_python_errors() {
+        let value_error = Err("ValueError".to_string());
+        let index_error = Err("IndexError".to_string());
+        assert_eq!(run("Relation([[0, 2], [0, 0]])"), value_error);
+        assert_eq!(run("Relation([[0, 1]])"), value_error);
+        assert_eq!(run("Relation([])"), value_error);
+        assert_eq!(run("Relation([[0.5]])"), value_error);
+        assert_eq!(run("Relation.empty(0)"), value_error);
+        assert_eq!(run("Relation.empty(1025)"), value_error);
+        assert_eq!(run("Relation.from_pairs([(0, 3)], 3)"), index_error);
+        assert_eq!(run("Relation.empty(2)[2, 0]"), index_error);
+        assert_eq!(run("r = Relation.empty(2)\nr[0, 1] = 2"), value_error);
+        assert_eq!(run("r = Relation.empty(2)\nr[0, 1] = 'x'"), value_error);
+        assert_eq!(run("r = Relation.empty(2)\nr[0, 2] = 1"), index_error);
+        assert_eq!(run("Relation.empty(2).closure('foo')"), value_error);
+        assert_eq!(run("Relation.empty(2).equivalence_classes()"), value_error);
+    }
+}
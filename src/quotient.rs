@@ -0,0 +1,195 @@
+/// 동치 관계에 의한 몫 관계(quotient relation)와 합동(congruence) 판별을 수행하는 모듈
+use crate::Matrix;
+use crate::i18n::{Msg, t, tf};
+
+/// 몫 관계 R/E의 계산 결과
+/// classes: E의 동치류들 (몫 관계의 k번째 원소는 classes[k])
+/// matrix: 동치류 사이의 관계 행렬
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Quotient {
+    pub classes: Vec<Vec<usize>>,
+    pub matrix: Matrix,
+}
+
+/// 몫 관계 R/E를 계산하는 함수
+/// [a] R/E [b] ⇔ 어떤 x ∈ [a], y ∈ [b]에 대해 R(x,y)
+/// E가 동치 관계가 아니거나 두 행렬의 크기가 다르면 None
+pub fn quotient_relation(relation: &Matrix, equivalence: &Matrix) -> Option<Quotient> {
+    if relation.len() != equivalence.len()
+        || !super::equivalence::is_equivalence_relation(equivalence)
+    {
+        return None;
+    }
+    let classes = super::visualize::equivalence_classes(equivalence);
+    let k = classes.len();
+    let mut matrix = vec![vec![0; k]; k];
+    for a in 0..k {
+        for b in 0..k {
+            let related = classes[a]
+                .iter()
+                .any(|&x| classes[b].iter().any(|&y| relation[x][y] == 1));
+            if related {
+                matrix[a][b] = 1;
+            }
+        }
+    }
+    Some(Quotient { classes, matrix })
+}
+
+/// E가 R에 대한 합동(congruence)이 아님을 보이는 반례를 찾는 함수
+/// R(x,y) 이고 x E x', y E y' 인데 R(x',y')가 아닌 (x, y, x', y')를 반환
+/// E가 동치 관계가 아니거나 반례가 없으면 None
+pub fn compatibility_violation(
+    relation: &Matrix,
+    equivalence: &Matrix,
+) -> Option<(usize, usize, usize, usize)> {
+    if relation.len() != equivalence.len()
+        || !super::equivalence::is_equivalence_relation(equivalence)
+    {
+        return None;
+    }
+    let n = relation.len();
+    for x in 0..n {
+        for y in 0..n {
+            if relation[x][y] != 1 {
+                continue;
+            }
+            for x2 in (0..n).filter(|&x2| equivalence[x][x2] == 1) {
+                for y2 in (0..n).filter(|&y2| equivalence[y][y2] == 1) {
+                    if relation[x2][y2] != 1 {
+                        return Some((x, y, x2, y2));
+                    }
+                }
+            }
+        }
+    }
+    None
+}
+
+/// E가 R과 양립(compatible)하는지, 즉 R에 대한 합동인지 판별하는 함수
+/// R(x,y)의 값이 x와 y의 동치류에만 의존해야 함
+pub fn is_compatible(relation: &Matrix, equivalence: &Matrix) -> bool {
+    super::equivalence::is_equivalence_relation(equivalence)
+        && relation.len() == equivalence.len()
+        && compatibility_violation(relation, equivalence).is_none()
+}
+
+/// 서로 도달 가능한 원소끼리 묶는 동치 관계(강연결 요소)를 반환하는 함수
+/// x ~ y ⇔ x에서 y로, y에서 x로 모두 도달 가능 (x = y 포함)
+pub fn mutual_reachability(relation: &Matrix) -> Matrix {
+    let reach = super::closure::transitive_closure(&super::closure::reflexive_closure(relation));
+    let n = relation.len();
+    let mut result = vec![vec![0; n]; n];
+    for x in 0..n {
+        for y in 0..n {
+            if reach[x][y] == 1 && reach[y][x] == 1 {
+                result[x][y] = 1;
+            }
+        }
+    }
+    result
+}
+
+/// 몫 관계 R/E와 합동 여부를 출력하는 함수
+/// 몫 관계의 각 원소는 동치류의 대표원 [x]로 표시
+pub fn print_quotient_analysis(relation: &Matrix, equivalence: &Matrix, title: &str) {
+    println!("\n=== {} ===", tf(Msg::QuotientTitle, &[&title]));
+
+    let Some(quotient) = quotient_relation(relation, equivalence) else {
+        println!("{}", t(Msg::QuotientNotEquivalence));
+        return;
+    };
+
+    println!("{}", t(Msg::QuotientClasses));
+    for (k, class) in quotient.classes.iter().enumerate() {
+        println!(
+            "  C{} = [{}] = {}",
+            k + 1,
+            class[0] + 1,
+            super::order::format_set(class)
+        );
+    }
+    super::visualize::print_matrix(&quotient.matrix, t(Msg::QuotientMatrix));
+
+    match compatibility_violation(relation, equivalence) {
+        None => println!("{}", t(Msg::QuotientCompatible)),
+        Some((x, y, x2, y2)) => println!(
+            "{}",
+            tf(
+                Msg::QuotientIncompatible,
+                &[
+                    &(x + 1),
+                    &(y + 1),
+                    &(x + 1),
+                    &(x2 + 1),
+                    &(y + 1),
+                    &(y2 + 1),
+                    &(x2 + 1),
+                    &(y2 + 1),
+                ]
+            )
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 동치류 번호 목록으로부터 동치 관계를 만드는 헬퍼 함수
+    fn partition(labels: &[usize]) -> Matrix {
+        labels
+            .iter()
+            .map(|a| labels.iter().map(|b| u8::from(a == b)).collect())
+            .collect()
+    }
+
+    #[test]
+    fn quotient_merges_related_classes() {
+        // 0 → 2, 3 → 1 과 동치류 {0, 1}, {2, 3}
+        let mut relation = vec![vec![0; 4]; 4];
+        relation[0][2] = 1;
+        relation[3][1] = 1;
+        let quotient = quotient_relation(&relation, &partition(&[0, 0, 1, 1])).unwrap();
+        assert_eq!(quotient.classes, vec![vec![0, 1], vec![2, 3]]);
+        assert_eq!(quotient.matrix, vec![vec![0, 1], vec![1, 0]]);
+    }
+
+    #[test]
+    fn quotient_requires_equivalence_of_same_size() {
+        let relation = vec![vec![1, 0], vec![0, 1]];
+        assert_eq!(
+            quotient_relation(&relation, &vec![vec![1, 1], vec![0, 1]]),
+            None
+        );
+        assert_eq!(quotient_relation(&relation, &partition(&[0, 0, 0])), None);
+        assert!(!is_compatible(&relation, &partition(&[0, 0, 0])));
+    }
+
+    #[test]
+    fn congruence_violation_is_a_real_counterexample() {
+        // 0 < 1 < 2 인 순서에서 {0, 2}, {1}은 합동이 아님
+        let chain = vec![vec![1, 1, 1], vec![0, 1, 1], vec![0, 0, 1]];
+        let equivalence = partition(&[0, 1, 0]);
+        let (x, y, x2, y2) = compatibility_violation(&chain, &equivalence).unwrap();
+        assert_eq!(chain[x][y], 1);
+        assert_eq!((equivalence[x][x2], equivalence[y][y2]), (1, 1));
+        assert_eq!(chain[x2][y2], 0);
+        assert!(!is_compatible(&chain, &equivalence));
+
+        // 핵 관계 {0, 1}, {2}는 "같은 동치류의 원소끼리만 관계" 인 관계와 양립
+        let blocks = vec![vec![1, 1, 0], vec![1, 1, 0], vec![0, 0, 0]];
+        assert!(is_compatible(&blocks, &partition(&[0, 0, 1])));
+        assert!(is_compatible(&chain, &partition(&[0, 1, 2])));
+    }
+
+    #[test]
+    fn mutual_reachability_groups_strong_components() {
+        // 0 → 1 → 0 사이클과 1 → 2
+        let relation = vec![vec![0, 1, 0], vec![1, 0, 1], vec![0, 0, 0]];
+        let components = mutual_reachability(&relation);
+        assert_eq!(components, partition(&[0, 0, 1]));
+        let condensed = quotient_relation(&relation, &components).unwrap();
+        assert_eq!(condensed.matrix, vec![vec![1, 1], vec![0, 0]]);
+    }
+}
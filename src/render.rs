@@ -0,0 +1,1016 @@
+/// 분석 보고서(report::RelationReport)를 여러 형식의 문자열로 출력하는 Renderer 트레이트와 구현 모듈
+/// 터미널, Markdown, HTML, JSON 형식을 지원하며 원소 번호는 다른 출력과 같이 1부터 시작
+use crate::Matrix;
+use crate::closure::{Justification, describe_justification};
+use crate::i18n::{Msg, mark, t, tf};
+use crate::order::{format_option, format_order, format_set};
+use crate::report::{
+    MAX_DIMENSION_SIZE, MAX_LATTICE_SIZE, OrderReport, RelationReport, Violations,
+};
+
+/// 분석 보고서를 특정 형식의 문자열로 변환하는 트레이트
+pub trait Renderer {
+    /// 보고서 전체를 하나의 문서로 변환
+    fn render(&self, report: &RelationReport) -> String;
+}
+
+/// 터미널용 일반 텍스트 출력
+pub struct TerminalRenderer;
+
+/// Markdown 문서 출력
+pub struct MarkdownRenderer;
+
+/// 독립 실행 가능한 HTML 문서 출력
+pub struct HtmlRenderer;
+
+/// 고정된 영어 키를 사용하는 JSON 출력 (언어 설정과 무관)
+pub struct JsonRenderer;
+
+/// 형식 이름으로 Renderer를 고르는 함수
+/// terminal/text, markdown/md, html, json, latex/tex를 지원하며 알 수 없는 이름은 None
+pub fn renderer_for(format: &str) -> Option<Box<dyn Renderer>> {
+    match format.to_ascii_lowercase().as_str() {
+        "terminal" | "text" => Some(Box::new(TerminalRenderer)),
+        "markdown" | "md" => Some(Box::new(MarkdownRenderer)),
+        "html" => Some(Box::new(HtmlRenderer)),
+        "json" => Some(Box::new(JsonRenderer)),
+        "latex" | "tex" => Some(Box::new(super::latex::LatexRenderer)),
+        _ => None,
+    }
+}
+
+/// 순서쌍을 (i, j) 형식으로 변환하는 헬퍼 함수
+fn format_pair(i: usize, j: usize) -> String {
+    format!("({}, {})", i + 1, j + 1)
+}
+
+/// 위반 사항을 (속성 이름, 설명 목록, 전체 위반 개수) 형태로 정리하는 헬퍼 함수 (Properties::entries와 같은 순서)
+/// 추이성은 설명 목록이 잘려 있을 수 있으므로 전체 개수가 목록 길이보다 클 수 있음
+pub(crate) fn violation_groups(
+    matrix: &Matrix,
+    violations: &Violations,
+) -> Vec<(Msg, Vec<String>, usize)> {
+    let reflexive: Vec<String> = violations
+        .reflexive
+        .iter()
+        .map(|&i| format!("R({}, {}) = 0", i + 1, i + 1))
+        .collect();
+    let symmetric: Vec<String> = violations
+        .symmetric
+        .iter()
+        .filter(|&&(i, j)| i < j)
+        .map(|&(i, j)| {
+            format!(
+                "R({}, {}) = {}, R({}, {}) = {}",
+                i + 1,
+                j + 1,
+                matrix[i][j],
+                j + 1,
+                i + 1,
+                matrix[j][i]
+            )
+        })
+        .collect();
+    let transitive = violations
+        .transitive
+        .iter()
+        .map(|&(i, j, k)| {
+            tf(
+                Msg::ReportTransitivityWitness,
+                &[&(i + 1), &(j + 1), &(j + 1), &(k + 1), &(i + 1), &(k + 1)],
+            )
+        })
+        .collect();
+    let antisymmetric: Vec<String> = violations
+        .antisymmetric
+        .iter()
+        .map(|&(i, j)| format!("R({}, {}) = R({}, {}) = 1", i + 1, j + 1, j + 1, i + 1))
+        .collect();
+    let irreflexive: Vec<String> = violations
+        .irreflexive
+        .iter()
+        .map(|&i| format!("R({}, {}) = 1", i + 1, i + 1))
+        .collect();
+    let connected: Vec<String> = violations
+        .connected
+        .iter()
+        .map(|&(i, j)| format!("R({}, {}) = R({}, {}) = 0", i + 1, j + 1, j + 1, i + 1))
+        .collect();
+    vec![
+        (Msg::Reflexivity, reflexive.len(), reflexive),
+        (Msg::Symmetry, symmetric.len(), symmetric),
+        (Msg::Transitivity, violations.transitive_count, transitive),
+        (Msg::Antisymmetry, antisymmetric.len(), antisymmetric),
+        (Msg::Irreflexivity, irreflexive.len(), irreflexive),
+        (Msg::Connectedness, connected.len(), connected),
+    ]
+    .into_iter()
+    .map(|(name, total, lines)| (name, lines, total))
+    .collect()
+}
+
+/// 동치류를 [대표원] = {원소들} 형식으로 변환하는 헬퍼 함수
+fn format_class(class: &[usize]) -> String {
+    format!("[{}] = {}", class[0] + 1, super::order::format_set(class))
+}
+
+/// 부분 순서 분석 결과를 현재 언어의 문장 목록으로 정리하는 헬퍼 함수 (격자 판별은 lattice_lines)
+pub(crate) fn order_lines(order: &OrderReport) -> Vec<String> {
+    let join = |parts: Vec<String>| parts.join(" | ");
+    let mut lines = vec![
+        tf(Msg::MinimalElements, &[&format_set(&order.minimal)]),
+        tf(Msg::MaximalElements, &[&format_set(&order.maximal)]),
+        tf(Msg::LeastElement, &[&format_option(order.least)]),
+        tf(Msg::GreatestElement, &[&format_option(order.greatest)]),
+        tf(
+            Msg::PosetHeight,
+            &[
+                &order.longest_chain.len(),
+                &format_order(&order.longest_chain),
+            ],
+        ),
+        tf(
+            Msg::PosetWidth,
+            &[
+                &order.maximum_antichain.len(),
+                &format_set(&order.maximum_antichain),
+            ],
+        ),
+        format!(
+            "{} {}",
+            t(Msg::DilworthHeader),
+            join(order.chain_cover.iter().map(|c| format_order(c)).collect())
+        ),
+        format!(
+            "{} {}",
+            t(Msg::MirskyHeader),
+            join(
+                order
+                    .antichain_partition
+                    .iter()
+                    .map(|a| format_set(a))
+                    .collect()
+            )
+        ),
+    ];
+    match &order.dimension {
+        Some(dimension) => {
+            lines.push(match &dimension.realizer {
+                Some((first, second)) => format!(
+                    "{} L1: {}, L2: {}",
+                    t(Msg::DimensionAtMostTwo),
+                    format_order(first),
+                    format_order(second)
+                ),
+                None => t(Msg::DimensionAtLeastThree).to_string(),
+            });
+            lines.push(match &dimension.chain_product {
+                Some(sizes) => {
+                    let sizes: Vec<String> = sizes.iter().map(|s| s.to_string()).collect();
+                    tf(Msg::ChainProduct, &[&sizes.join(" × ")])
+                }
+                None => t(Msg::NotChainProduct).to_string(),
+            });
+        }
+        None => lines.push(tf(Msg::ReportDimensionSkipped, &[&MAX_DIMENSION_SIZE])),
+    }
+    lines
+}
+
+/// 격자 판별 결과를 현재 언어의 문장 목록으로 정리하는 헬퍼 함수
+pub(crate) fn lattice_lines(order: &OrderReport) -> Vec<String> {
+    let Some(lattice) = &order.lattice else {
+        return vec![tf(Msg::ReportLatticeSkipped, &[&MAX_LATTICE_SIZE])];
+    };
+    if !lattice.lattice {
+        return vec![t(Msg::NotLattice).to_string()];
+    }
+    let mut lines = vec![
+        t(Msg::LatticeSatisfied).to_string(),
+        format!("{}: {}", t(Msg::BoundedLattice), mark(lattice.bounded)),
+        format!(
+            "{}: {}",
+            t(Msg::DistributiveLattice),
+            mark(lattice.distributive())
+        ),
+    ];
+    if let Some((a, b, c)) = lattice.distributive_counterexample {
+        lines.push(
+            tf(
+                Msg::DistributiveCounterexample,
+                &[&(a + 1), &(b + 1), &(c + 1)],
+            )
+            .trim()
+            .to_string(),
+        );
+    }
+    lines.push(format!(
+        "{}: {}",
+        t(Msg::ModularLattice),
+        mark(lattice.modular())
+    ));
+    if let Some((a, x, b)) = lattice.modular_counterexample {
+        lines.push(
+            tf(Msg::ModularCounterexample, &[&(a + 1), &(b + 1), &(x + 1)])
+                .trim()
+                .to_string(),
+        );
+    }
+    lines.push(format!(
+        "{}: {}",
+        t(Msg::ComplementedLattice),
+        mark(lattice.complemented)
+    ));
+    lines
+}
+
+impl Renderer for TerminalRenderer {
+    fn render(&self, report: &RelationReport) -> String {
+        let mut out = format!("=== {} ===\n", t(Msg::ReportTitle));
+        let section = |out: &mut String, title: Msg| {
+            out.push_str(&format!("\n--- {} ---\n", t(title)));
+        };
+        let push_matrix = |out: &mut String, matrix: &Matrix, indent: &str| {
+            for row in matrix {
+                let cells: Vec<String> = row.iter().map(|v| v.to_string()).collect();
+                out.push_str(&format!("{}{}\n", indent, cells.join(" ")));
+            }
+        };
+
+        section(&mut out, Msg::ReportMatrix);
+        push_matrix(&mut out, &report.matrix, "");
+
+        section(&mut out, Msg::ReportProperties);
+        for (_, name, ok) in report.properties.entries() {
+            out.push_str(&format!("{}: {}\n", t(name), mark(ok)));
+        }
+
+        section(&mut out, Msg::ReportClassification);
+        if report.classifications.is_empty() {
+            out.push_str(&format!("{}\n", t(Msg::ReportNone)));
+        }
+        for class in &report.classifications {
+            out.push_str(&format!("{}\n", tf(Msg::ClassMatched, &[&class.label()])));
+        }
+
+        section(&mut out, Msg::ReportViolations);
+        for (name, lines, total) in violation_groups(&report.matrix, &report.violations) {
+            if lines.is_empty() {
+                out.push_str(&format!("{}: {}\n", t(name), t(Msg::ReportNone)));
+            } else {
+                out.push_str(&format!("{}:\n", t(name)));
+                for line in &lines {
+                    out.push_str(&format!("  {}\n", line));
+                }
+                if total > lines.len() {
+                    let more = tf(Msg::ReportMoreViolations, &[&(total - lines.len())]);
+                    out.push_str(&format!("  {}\n", more));
+                }
+            }
+        }
+
+        section(&mut out, Msg::EquivalenceClassesTitle);
+        match &report.classes {
+            Some(classes) => {
+                for class in classes {
+                    out.push_str(&format!("{}\n", format_class(class)));
+                }
+            }
+            None => out.push_str(&format!("{}\n", t(Msg::IsNotEquivalence))),
+        }
+
+        section(&mut out, Msg::ReportClosures);
+        for closure in &report.closures {
+            out.push_str(&format!(
+                "{} - {}\n",
+                closure.kind.label(),
+                tf(Msg::ReportAddedCount, &[&closure.trace.added.len()])
+            ));
+            for ((i, j), justification) in &closure.trace.added {
+                out.push_str(&format!(
+                    "  {:<10} | {}\n",
+                    format_pair(*i, *j),
+                    describe_justification(justification)
+                ));
+            }
+            push_matrix(&mut out, &closure.trace.matrix, "    ");
+        }
+
+        if let Some(order) = &report.order {
+            section(&mut out, Msg::ReportOrder);
+            for line in order_lines(order) {
+                out.push_str(&format!("{}\n", line));
+            }
+            section(&mut out, Msg::LatticeTitle);
+            for line in lattice_lines(order) {
+                out.push_str(&format!("{}\n", line));
+            }
+        }
+        out
+    }
+}
+
+/// Markdown 표로 관계 행렬을 변환하는 헬퍼 함수 (행/열 머리글은 원소 번호)
+fn markdown_matrix(matrix: &Matrix) -> String {
+    let n = matrix.len();
+    let header: Vec<String> = (1..=n).map(|x| x.to_string()).collect();
+    let mut out = format!("| R | {} |\n", header.join(" | "));
+    out.push_str(&format!("|---|{}\n", "---|".repeat(n)));
+    for (i, row) in matrix.iter().enumerate() {
+        let cells: Vec<String> = row.iter().map(|v| v.to_string()).collect();
+        out.push_str(&format!("| **{}** | {} |\n", i + 1, cells.join(" | ")));
+    }
+    out
+}
+
+impl Renderer for MarkdownRenderer {
+    fn render(&self, report: &RelationReport) -> String {
+        let mut out = format!("# {}\n", t(Msg::ReportTitle));
+
+        out.push_str(&format!("\n## {}\n\n", t(Msg::ReportMatrix)));
+        out.push_str(&markdown_matrix(&report.matrix));
+
+        out.push_str(&format!(
+            "\n## {}\n\n| {} | {} |\n|---|---|\n",
+            t(Msg::ReportProperties),
+            t(Msg::ReportProperty),
+            t(Msg::ReportResult)
+        ));
+        for (_, name, ok) in report.properties.entries() {
+            out.push_str(&format!("| {} | {} |\n", t(name), mark(ok)));
+        }
+
+        out.push_str(&format!("\n## {}\n\n", t(Msg::ReportClassification)));
+        if report.classifications.is_empty() {
+            out.push_str(&format!("- {}\n", t(Msg::ReportNone)));
+        }
+        for class in &report.classifications {
+            out.push_str(&format!("- {}\n", class.label()));
+        }
+
+        out.push_str(&format!("\n## {}\n", t(Msg::ReportViolations)));
+        for (name, lines, total) in violation_groups(&report.matrix, &report.violations) {
+            out.push_str(&format!("\n**{}**\n\n", t(name)));
+            if lines.is_empty() {
+                out.push_str(&format!("- {}\n", t(Msg::ReportNone)));
+            }
+            for line in &lines {
+                out.push_str(&format!("- `{}`\n", line));
+            }
+            if total > lines.len() {
+                let more = tf(Msg::ReportMoreViolations, &[&(total - lines.len())]);
+                out.push_str(&format!("- {}\n", more));
+            }
+        }
+
+        out.push_str(&format!("\n## {}\n\n", t(Msg::EquivalenceClassesTitle)));
+        match &report.classes {
+            Some(classes) => {
+                for class in classes {
+                    out.push_str(&format!("- `{}`\n", format_class(class)));
+                }
+            }
+            None => out.push_str(&format!("{}\n", t(Msg::IsNotEquivalence))),
+        }
+
+        out.push_str(&format!("\n## {}\n", t(Msg::ReportClosures)));
+        for closure in &report.closures {
+            out.push_str(&format!(
+                "\n### {}\n\n{}\n\n",
+                closure.kind.label(),
+                tf(Msg::ReportAddedCount, &[&closure.trace.added.len()])
+            ));
+            if !closure.trace.added.is_empty() {
+                out.push_str(&format!(
+                    "| {} | {} |\n|---|---|\n",
+                    t(Msg::JustificationPair),
+                    t(Msg::JustificationReason)
+                ));
+                for ((i, j), justification) in &closure.trace.added {
+                    out.push_str(&format!(
+                        "| {} | {} |\n",
+                        format_pair(*i, *j),
+                        describe_justification(justification)
+                    ));
+                }
+                out.push('\n');
+            }
+            out.push_str(&markdown_matrix(&closure.trace.matrix));
+        }
+
+        if let Some(order) = &report.order {
+            for (title, lines) in [
+                (Msg::ReportOrder, order_lines(order)),
+                (Msg::LatticeTitle, lattice_lines(order)),
+            ] {
+                out.push_str(&format!("\n## {}\n\n", t(title)));
+                for line in lines {
+                    out.push_str(&format!("- {}\n", line));
+                }
+            }
+        }
+        out
+    }
+}
+
+/// HTML 특수 문자를 이스케이프하는 함수
+pub fn escape_html(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+/// HTML 행렬 표의 칸에 붙이는 data 속성
+/// Plain: 없음, Cells: 행/열 번호(data-row, data-col), Graph: 행/열 번호와 그래프 간선 연결(data-graph)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum MatrixData {
+    Plain,
+    Cells,
+    Graph,
+}
+
+/// HTML 표로 관계 행렬을 변환하는 헬퍼 함수
+/// added에 포함된 칸은 added 클래스로 강조하며, data에 따라 대화형 보고서용 data 속성을 붙임
+pub(crate) fn html_matrix(matrix: &Matrix, added: &[(usize, usize)], data: MatrixData) -> String {
+    let n = matrix.len();
+    let tagged = data != MatrixData::Plain;
+    let row_attr = |i: usize| {
+        if tagged {
+            format!(" data-row=\"{}\"", i + 1)
+        } else {
+            String::new()
+        }
+    };
+    let col_attr = |j: usize| {
+        if tagged {
+            format!(" data-col=\"{}\"", j + 1)
+        } else {
+            String::new()
+        }
+    };
+    let mut out = match data {
+        MatrixData::Plain => String::from("<table class=\"matrix\">"),
+        MatrixData::Cells => String::from("<table class=\"matrix interactive\">"),
+        MatrixData::Graph => String::from("<table class=\"matrix interactive\" data-graph>"),
+    };
+    out.push_str("\n<tr><th>R</th>");
+    for j in 0..n {
+        out.push_str(&format!("<th{}>{}</th>", col_attr(j), j + 1));
+    }
+    out.push_str("</tr>\n");
+    for (i, row) in matrix.iter().enumerate() {
+        out.push_str(&format!("<tr><th{}>{}</th>", row_attr(i), i + 1));
+        for (j, value) in row.iter().enumerate() {
+            let class = if added.contains(&(i, j)) {
+                "added"
+            } else if *value == 1 {
+                "one"
+            } else {
+                "zero"
+            };
+            out.push_str(&format!(
+                "<td class=\"{}\"{}{}>{}</td>",
+                class,
+                row_attr(i),
+                col_attr(j),
+                value
+            ));
+        }
+        out.push_str("</tr>\n");
+    }
+    out.push_str("</table>\n");
+    out
+}
+
+/// HTML 보고서에 포함하는 기본 스타일
+pub const HTML_STYLE: &str =
+    "body { font-family: sans-serif; max-width: 960px; margin: 2em auto; padding: 0 1em; }
+table { border-collapse: collapse; margin: 0.5em 0; }
+th, td { border: 1px solid #999; padding: 0.25em 0.6em; text-align: center; }
+td.one { background: #cfe2f7; }
+td.added { background: #f5a623; }
+.ok { color: #2e7d32; }
+.fail { color: #c62828; }
+code { background: #f4f4f4; padding: 0 0.2em; }";
+
+impl HtmlRenderer {
+    /// 보고서 본문(<body> 안쪽)만 HTML로 변환하는 함수 - 다른 문서에 삽입할 때 사용
+    pub fn render_body(&self, report: &RelationReport) -> String {
+        let mut out = format!("<h1>{}</h1>\n", escape_html(t(Msg::ReportTitle)));
+
+        out.push_str(&format!("<h2>{}</h2>\n", escape_html(t(Msg::ReportMatrix))));
+        out.push_str(&html_matrix(&report.matrix, &[], MatrixData::Plain));
+
+        out.push_str(&format!(
+            "<h2>{}</h2>\n<table>\n<tr><th>{}</th><th>{}</th></tr>\n",
+            escape_html(t(Msg::ReportProperties)),
+            escape_html(t(Msg::ReportProperty)),
+            escape_html(t(Msg::ReportResult))
+        ));
+        for (_, name, ok) in report.properties.entries() {
+            out.push_str(&format!(
+                "<tr><td>{}</td><td class=\"{}\">{}</td></tr>\n",
+                escape_html(t(name)),
+                if ok { "ok" } else { "fail" },
+                escape_html(mark(ok))
+            ));
+        }
+        out.push_str("</table>\n");
+
+        out.push_str(&format!(
+            "<h2>{}</h2>\n<ul>\n",
+            escape_html(t(Msg::ReportClassification))
+        ));
+        if report.classifications.is_empty() {
+            out.push_str(&format!("<li>{}</li>\n", escape_html(t(Msg::ReportNone))));
+        }
+        for class in &report.classifications {
+            out.push_str(&format!("<li>{}</li>\n", escape_html(class.label())));
+        }
+        out.push_str("</ul>\n");
+
+        out.push_str(&format!(
+            "<h2>{}</h2>\n",
+            escape_html(t(Msg::ReportViolations))
+        ));
+        for (name, lines, total) in violation_groups(&report.matrix, &report.violations) {
+            out.push_str(&format!("<h3>{}</h3>\n<ul>\n", escape_html(t(name))));
+            if lines.is_empty() {
+                out.push_str(&format!("<li>{}</li>\n", escape_html(t(Msg::ReportNone))));
+            }
+            for line in &lines {
+                out.push_str(&format!("<li><code>{}</code></li>\n", escape_html(line)));
+            }
+            if total > lines.len() {
+                let more = tf(Msg::ReportMoreViolations, &[&(total - lines.len())]);
+                out.push_str(&format!("<li>{}</li>\n", escape_html(&more)));
+            }
+            out.push_str("</ul>\n");
+        }
+
+        out.push_str(&format!(
+            "<h2>{}</h2>\n",
+            escape_html(t(Msg::EquivalenceClassesTitle))
+        ));
+        match &report.classes {
+            Some(classes) => {
+                out.push_str("<ul>\n");
+                for class in classes {
+                    out.push_str(&format!("<li>{}</li>\n", escape_html(&format_class(class))));
+                }
+                out.push_str("</ul>\n");
+            }
+            None => out.push_str(&format!(
+                "<p>{}</p>\n",
+                escape_html(t(Msg::IsNotEquivalence))
+            )),
+        }
+
+        out.push_str(&format!(
+            "<h2>{}</h2>\n",
+            escape_html(t(Msg::ReportClosures))
+        ));
+        for closure in &report.closures {
+            out.push_str(&format!(
+                "<h3>{}</h3>\n<p>{}</p>\n",
+                escape_html(closure.kind.label()),
+                escape_html(&tf(Msg::ReportAddedCount, &[&closure.trace.added.len()]))
+            ));
+            if !closure.trace.added.is_empty() {
+                out.push_str(&format!(
+                    "<table>\n<tr><th>{}</th><th>{}</th></tr>\n",
+                    escape_html(t(Msg::JustificationPair)),
+                    escape_html(t(Msg::JustificationReason))
+                ));
+                for ((i, j), justification) in &closure.trace.added {
+                    out.push_str(&format!(
+                        "<tr><td>{}</td><td>{}</td></tr>\n",
+                        format_pair(*i, *j),
+                        escape_html(&describe_justification(justification))
+                    ));
+                }
+                out.push_str("</table>\n");
+            }
+            let added: Vec<(usize, usize)> =
+                closure.trace.added.iter().map(|(pair, _)| *pair).collect();
+            out.push_str(&html_matrix(
+                &closure.trace.matrix,
+                &added,
+                MatrixData::Plain,
+            ));
+        }
+
+        if let Some(order) = &report.order {
+            out.push_str(&html_order_sections(order, "h2"));
+        }
+        out
+    }
+}
+
+/// 순서 구조와 격자 판별 절을 제목 태그(h2 등)와 목록으로 변환하는 함수 (대화형 HTML 보고서와 공유)
+pub(crate) fn html_order_sections(order: &OrderReport, heading: &str) -> String {
+    let mut out = String::new();
+    for (title, lines) in [
+        (Msg::ReportOrder, order_lines(order)),
+        (Msg::LatticeTitle, lattice_lines(order)),
+    ] {
+        out.push_str(&format!(
+            "<{}>{}</{}>\n<ul>\n",
+            heading,
+            escape_html(t(title)),
+            heading
+        ));
+        for line in lines {
+            out.push_str(&format!("<li>{}</li>\n", escape_html(&line)));
+        }
+        out.push_str("</ul>\n");
+    }
+    out
+}
+
+impl Renderer for HtmlRenderer {
+    fn render(&self, report: &RelationReport) -> String {
+        format!(
+            "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n<style>\n{}\n</style>\n</head>\n<body>\n{}</body>\n</html>\n",
+            escape_html(t(Msg::ReportTitle)),
+            HTML_STYLE,
+            self.render_body(report)
+        )
+    }
+}
+
+/// JSON 문자열 리터럴로 변환하는 함수 (따옴표와 이스케이프 포함)
+pub fn json_string(text: &str) -> String {
+    let mut out = String::with_capacity(text.len() + 2);
+    out.push('"');
+    for c in text.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            _ => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// 관계 행렬을 JSON 2차원 배열로 변환하는 함수
+pub fn json_matrix(matrix: &Matrix) -> String {
+    let rows: Vec<String> = matrix
+        .iter()
+        .map(|row| {
+            let cells: Vec<String> = row.iter().map(|v| v.to_string()).collect();
+            format!("[{}]", cells.join(", "))
+        })
+        .collect();
+    format!("[{}]", rows.join(", "))
+}
+
+/// 1부터 시작하는 번호 목록을 JSON 배열로 변환하는 헬퍼 함수
+pub(crate) fn json_elements(elements: &[usize]) -> String {
+    let items: Vec<String> = elements.iter().map(|x| (x + 1).to_string()).collect();
+    format!("[{}]", items.join(", "))
+}
+
+/// 없을 수 있는 원소를 1부터 시작하는 번호 또는 null로 변환하는 헬퍼 함수
+fn json_option(element: Option<usize>) -> String {
+    element.map_or("null".to_string(), |x| (x + 1).to_string())
+}
+
+/// 원소 목록들을 JSON 2차원 배열로 변환하는 헬퍼 함수
+fn json_element_lists(lists: &[Vec<usize>]) -> String {
+    let items: Vec<String> = lists.iter().map(|list| json_elements(list)).collect();
+    format!("[{}]", items.join(", "))
+}
+
+/// 부분 순서 분석 결과를 JSON 객체로 변환하는 헬퍼 함수
+/// dimension과 lattice는 크기 제한으로 생략되면 null
+fn json_order(order: &OrderReport) -> String {
+    let triple = |value: Option<(usize, usize, usize)>| {
+        value.map_or("null".to_string(), |(a, b, c)| json_elements(&[a, b, c]))
+    };
+    let dimension = match &order.dimension {
+        Some(dimension) => format!(
+            "{{\"at_most_two\": {}, \"realizer\": {}, \"chain_product\": {}}}",
+            dimension.realizer.is_some(),
+            dimension
+                .realizer
+                .as_ref()
+                .map_or("null".to_string(), |(first, second)| {
+                    format!("[{}, {}]", json_elements(first), json_elements(second))
+                }),
+            dimension
+                .chain_product
+                .as_ref()
+                .map_or("null".to_string(), |sizes| {
+                    let items: Vec<String> = sizes.iter().map(|s| s.to_string()).collect();
+                    format!("[{}]", items.join(", "))
+                })
+        ),
+        None => "null".to_string(),
+    };
+    let lattice = match &order.lattice {
+        Some(lattice) => format!(
+            "{{\"lattice\": {}, \"bounded\": {}, \"distributive\": {}, \"modular\": {}, \"complemented\": {}, \"distributive_counterexample\": {}, \"modular_counterexample\": {}}}",
+            lattice.lattice,
+            lattice.bounded,
+            lattice.distributive(),
+            lattice.modular(),
+            lattice.complemented,
+            triple(lattice.distributive_counterexample),
+            triple(lattice.modular_counterexample)
+        ),
+        None => "null".to_string(),
+    };
+    format!(
+        "{{\"minimal\": {}, \"maximal\": {}, \"least\": {}, \"greatest\": {}, \"height\": {}, \"width\": {}, \"longest_chain\": {}, \"maximum_antichain\": {}, \"chain_cover\": {}, \"antichain_partition\": {}, \"dimension\": {}, \"lattice\": {}}}",
+        json_elements(&order.minimal),
+        json_elements(&order.maximal),
+        json_option(order.least),
+        json_option(order.greatest),
+        order.longest_chain.len(),
+        order.maximum_antichain.len(),
+        json_elements(&order.longest_chain),
+        json_elements(&order.maximum_antichain),
+        json_element_lists(&order.chain_cover),
+        json_element_lists(&order.antichain_partition),
+        dimension,
+        lattice
+    )
+}
+
+/// 근거를 JSON 객체로 변환하는 헬퍼 함수
+fn json_justification(justification: &Justification) -> String {
+    match justification {
+        Justification::Reflexive => "{\"type\": \"reflexive\"}".to_string(),
+        Justification::SymmetricOf(j, i) => format!(
+            "{{\"type\": \"symmetric\", \"of\": {}}}",
+            json_elements(&[*j, *i])
+        ),
+        Justification::Path(path) => {
+            format!("{{\"type\": \"path\", \"path\": {}}}", json_elements(path))
+        }
+        Justification::UndirectedPath(path) => format!(
+            "{{\"type\": \"undirected_path\", \"path\": {}}}",
+            json_elements(path)
+        ),
+    }
+}
+
+impl Renderer for JsonRenderer {
+    fn render(&self, report: &RelationReport) -> String {
+        let properties: Vec<String> = report
+            .properties
+            .entries()
+            .iter()
+            .map(|(key, _, ok)| format!("\"{}\": {}", key, ok))
+            .collect();
+        let classifications: Vec<String> = report
+            .classifications
+            .iter()
+            .map(|class| json_string(class.key()))
+            .collect();
+        let violations = &report.violations;
+        let symmetric: Vec<String> = violations
+            .symmetric
+            .iter()
+            .map(|&(i, j)| json_elements(&[i, j]))
+            .collect();
+        let transitive: Vec<String> = violations
+            .transitive
+            .iter()
+            .map(|&(i, j, k)| json_elements(&[i, j, k]))
+            .collect();
+        let pair_list = |pairs: &[(usize, usize)]| -> String {
+            let items: Vec<String> = pairs.iter().map(|&(i, j)| json_elements(&[i, j])).collect();
+            format!("[{}]", items.join(", "))
+        };
+        let classes = match &report.classes {
+            Some(classes) => json_element_lists(classes),
+            None => "null".to_string(),
+        };
+        let order = match &report.order {
+            Some(order) => json_order(order),
+            None => "null".to_string(),
+        };
+        let closures: Vec<String> = report
+            .closures
+            .iter()
+            .map(|closure| {
+                let added: Vec<String> = closure
+                    .trace
+                    .added
+                    .iter()
+                    .map(|((i, j), justification)| {
+                        format!(
+                            "{{\"pair\": {}, \"justification\": {}}}",
+                            json_elements(&[*i, *j]),
+                            json_justification(justification)
+                        )
+                    })
+                    .collect();
+                format!(
+                    "    {{\"kind\": {}, \"matrix\": {}, \"added\": [{}]}}",
+                    json_string(closure.kind.key()),
+                    json_matrix(&closure.trace.matrix),
+                    added.join(", ")
+                )
+            })
+            .collect();
+
+        format!(
+            "{{\n  \"size\": {},\n  \"matrix\": {},\n  \"properties\": {{{}}},\n  \"equivalence\": {},\n  \"classifications\": [{}],\n  \"violations\": {{\"reflexive\": {}, \"symmetric\": [{}], \"transitive\": [{}], \"transitive_count\": {}, \"antisymmetric\": {}, \"irreflexive\": {}, \"connected\": {}}},\n  \"equivalence_classes\": {},\n  \"closures\": [\n{}\n  ],\n  \"order\": {}\n}}\n",
+            report.matrix.len(),
+            json_matrix(&report.matrix),
+            properties.join(", "),
+            report.is_equivalence(),
+            classifications.join(", "),
+            json_elements(&violations.reflexive),
+            symmetric.join(", "),
+            transitive.join(", "),
+            violations.transitive_count,
+            pair_list(&violations.antisymmetric),
+            json_elements(&violations.irreflexive),
+            pair_list(&violations.connected),
+            classes,
+            closures.join(",\n"),
+            order
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::report::analyze_relation;
+
+    /// JSON 값 하나를 읽고 나머지 입력을 반환하는 최소한의 검사기 (구조만 확인)
+    fn skip_value(input: &str) -> Option<&str> {
+        let input = input.trim_start();
+        let (open, close) = match input.chars().next()? {
+            '{' => ('{', '}'),
+            '[' => ('[', ']'),
+            '"' => {
+                let mut escaped = false;
+                for (idx, c) in input.char_indices().skip(1) {
+                    match c {
+                        _ if escaped => escaped = false,
+                        '\\' => escaped = true,
+                        '"' => return Some(&input[idx + 1..]),
+                        _ => {}
+                    }
+                }
+                return None;
+            }
+            _ => {
+                let end = input
+                    .find(|c: char| !(c.is_ascii_alphanumeric() || c == '.' || c == '-'))
+                    .unwrap_or(input.len());
+                let token = &input[..end];
+                let valid =
+                    matches!(token, "true" | "false" | "null") || token.parse::<f64>().is_ok();
+                return valid.then_some(&input[end..]);
+            }
+        };
+        let mut rest = input[1..].trim_start();
+        if let Some(after) = rest.strip_prefix(close) {
+            return Some(after);
+        }
+        loop {
+            if open == '{' {
+                rest = skip_value(rest)?.trim_start().strip_prefix(':')?;
+            }
+            rest = skip_value(rest)?.trim_start();
+            if let Some(after) = rest.strip_prefix(',') {
+                rest = after;
+            } else {
+                return rest.strip_prefix(close);
+            }
+        }
+    }
+
+    /// 문자열 전체가 올바른 JSON 값 하나인지 확인하는 헬퍼 함수
+    fn is_json(text: &str) -> bool {
+        skip_value(text).is_some_and(|rest| rest.trim().is_empty())
+    }
+
+    /// 분석에 쓰는 관계들: 동치 관계, 다이아몬드 부분 순서, 경로, 빈 관계
+    fn samples() -> Vec<Matrix> {
+        vec![
+            vec![vec![1, 1, 0], vec![1, 1, 0], vec![0, 0, 1]],
+            vec![
+                vec![1, 1, 1, 1],
+                vec![0, 1, 0, 1],
+                vec![0, 0, 1, 1],
+                vec![0, 0, 0, 1],
+            ],
+            vec![vec![0, 1, 0], vec![0, 0, 1], vec![0, 0, 0]],
+            vec![vec![0]],
+        ]
+    }
+
+    #[test]
+    fn html_matrix_tags_cells_only_when_asked() {
+        let matrix = vec![vec![0, 1], vec![1, 0]];
+        let plain = html_matrix(&matrix, &[(0, 0)], MatrixData::Plain);
+        assert!(plain.starts_with("<table class=\"matrix\">"));
+        assert!(plain.contains("<td class=\"added\">0</td>") && !plain.contains("data-"));
+
+        let table = html_matrix(&matrix, &[(0, 0)], MatrixData::Graph);
+        assert!(table.contains("data-graph"));
+        assert_eq!(table.matches("<td ").count(), 4);
+        assert!(table.contains("<td class=\"added\" data-row=\"1\" data-col=\"1\">0</td>"));
+        assert!(table.contains("<td class=\"one\" data-row=\"2\" data-col=\"1\">1</td>"));
+        let cells = html_matrix(&matrix, &[], MatrixData::Cells);
+        assert!(cells.contains("data-row") && !cells.contains("data-graph"));
+    }
+
+    #[test]
+    fn json_checker_rejects_broken_json() {
+        assert!(is_json(
+            "{\"a\": [1, 2.5, null], \"b\": {\"c\": \"x\\\"y\"}}"
+        ));
+        assert!(!is_json("{\"a\": [1, 2}"));
+        assert!(!is_json("{\"a\" 1}"));
+        assert!(!is_json("[1, 2] 3"));
+    }
+
+    #[test]
+    fn json_report_is_valid_json() {
+        for matrix in samples() {
+            let json = JsonRenderer.render(&analyze_relation(&matrix));
+            assert!(is_json(&json), "{json}");
+        }
+    }
+
+    #[test]
+    fn json_report_contains_order_only_for_partial_orders() {
+        let samples = samples();
+        let equivalence = JsonRenderer.render(&analyze_relation(&samples[0]));
+        assert!(equivalence.contains("\"equivalence_classes\": [[1, 2], [3]]"));
+        assert!(equivalence.contains("\"order\": null"));
+
+        let diamond = JsonRenderer.render(&analyze_relation(&samples[1]));
+        assert!(diamond.contains("\"equivalence_classes\": null"));
+        assert!(diamond.contains("\"height\": 3, \"width\": 2"));
+        assert!(diamond.contains("\"least\": 1, \"greatest\": 4"));
+        assert!(diamond.contains("\"chain_product\": [2, 2]"));
+        assert!(diamond.contains("\"distributive\": true"));
+    }
+
+    #[test]
+    fn json_helpers_escape_and_number_from_one() {
+        assert_eq!(json_string("a\"b\\c\nd\u{1}"), "\"a\\\"b\\\\c\\nd\\u0001\"");
+        assert_eq!(
+            json_matrix(&vec![vec![1, 0], vec![0, 1]]),
+            "[[1, 0], [0, 1]]"
+        );
+        assert_eq!(json_elements(&[0, 2]), "[1, 3]");
+        assert_eq!(json_option(None), "null");
+    }
+
+    #[test]
+    fn html_is_escaped_and_balanced() {
+        assert_eq!(
+            escape_html("<a href=\"x\">'&'</a>"),
+            "&lt;a href=&quot;x&quot;&gt;&#39;&amp;&#39;&lt;/a&gt;"
+        );
+        for matrix in samples() {
+            let html = HtmlRenderer.render(&analyze_relation(&matrix));
+            for tag in ["table", "tr", "ul", "h2"] {
+                assert_eq!(
+                    html.matches(&format!("<{tag}")).count(),
+                    html.matches(&format!("</{tag}>")).count(),
+                    "<{tag}>"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn renderers_are_selected_by_name() {
+        for name in [
+            "terminal", "text", "markdown", "MD", "html", "json", "latex", "tex",
+        ] {
+            assert!(renderer_for(name).is_some(), "{name}");
+        }
+        assert!(renderer_for("pdf").is_none());
+        let report = analyze_relation(&samples()[1]);
+        let json = renderer_for("json").unwrap().render(&report);
+        assert_eq!(json, JsonRenderer.render(&report));
+        assert!(MarkdownRenderer.render(&report).starts_with("# "));
+    }
+}
@@ -0,0 +1,577 @@
+/// 대화형 셸 모듈 - 여러 관계에 이름을 붙여 두고 기존 분석 함수로 반복해서 살펴보는 REPL
+/// let/show/check/close/classes/save/list/help/quit 명령, 기록 파일, Tab 자동 완성을 지원
+use std::collections::BTreeMap;
+use std::io::{self, BufRead, IsTerminal, Read, Write};
+use std::path::{Path, PathBuf};
+
+use crate::Matrix;
+use crate::i18n::{Msg, t, tf};
+use crate::report::ClosureKind;
+use crate::tui::{RawMode, SpecialKey, parse_escape};
+
+/// 명령 이름 목록 (자동 완성에 사용)
+const COMMANDS: [&str; 9] = [
+    "let", "show", "check", "close", "classes", "save", "list", "help", "quit",
+];
+
+/// close 명령에서 사용하는 폐포 이름 (자동 완성에 사용)
+const CLOSURE_NAMES: [&str; 4] = ["refl", "sym", "trans", "equiv"];
+
+/// 관계 이름으로 쓸 수 있는지 검사하는 헬퍼 함수 (영문자로 시작하고 영문자, 숫자, _로 구성)
+fn is_valid_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+        && !COMMANDS.contains(&name)
+}
+
+/// 이름이 붙은 관계들을 보관하는 셸 세션
+#[derive(Debug, Clone, Default)]
+pub struct Session {
+    relations: BTreeMap<String, Matrix>,
+}
+
+impl Session {
+    /// 빈 세션을 만드는 함수
+    pub fn new() -> Session {
+        Session::default()
+    }
+
+    /// 이름으로 관계를 찾는 함수
+    pub fn get(&self, name: &str) -> Option<&Matrix> {
+        self.relations.get(name)
+    }
+
+    /// 이름으로 관계를 찾고, 없으면 오류 메시지를 반환하는 헬퍼 함수
+    fn relation(&self, name: &str) -> Result<&Matrix, String> {
+        self.relations
+            .get(name)
+            .ok_or_else(|| tf(Msg::ReplUnknownRelation, &[&name]))
+    }
+
+    /// 명령 한 줄을 실행하는 함수 (quit이면 false를 반환)
+    pub fn execute(&mut self, line: &str) -> Result<bool, String> {
+        let words: Vec<&str> = line.split_whitespace().collect();
+        match words.as_slice() {
+            [] => {}
+            ["let", ..] => self.execute_let(line)?,
+            ["show", name] => super::visualize::print_matrix(self.relation(name)?, name),
+            ["check", name] => super::equivalence::print_equivalence_result(self.relation(name)?),
+            ["classes", name] => {
+                let matrix = self.relation(name)?;
+                if super::equivalence::is_equivalence_relation(matrix) {
+                    super::visualize::print_equivalence_classes(matrix);
+                } else {
+                    println!("{}", tf(Msg::ReplNotEquivalence, &[name, name]));
+                }
+            }
+            ["close", name, kind] => self.execute_close(name, kind, name)?,
+            ["close", name, kind, "as", target] => self.execute_close(name, kind, target)?,
+            ["save", name, path] => {
+                super::parse::save_relation(self.relation(name)?, Path::new(path))?;
+                println!("{}", tf(Msg::ReplSaved, &[name, path]));
+            }
+            ["list"] => {
+                if self.relations.is_empty() {
+                    println!("{}", t(Msg::ReplNoRelations));
+                }
+                for (name, matrix) in &self.relations {
+                    println!("{}: {}×{}", name, matrix.len(), matrix.len());
+                }
+            }
+            ["help"] => println!("{}", t(Msg::ReplHelp)),
+            ["quit"] | ["exit"] => return Ok(false),
+            [command, ..] if COMMANDS.contains(command) => {
+                return Err(tf(Msg::ReplUsage, &[&t(Msg::ReplHelp)]));
+            }
+            [command, ..] => return Err(tf(Msg::ReplUnknownCommand, &[command])),
+        }
+        Ok(true)
+    }
+
+    /// let 이름 = 표기 | 다른 관계 이름 | load 파일
+    fn execute_let(&mut self, line: &str) -> Result<(), String> {
+        let rest = line.trim_start().trim_start_matches("let");
+        let (name, expression) = rest
+            .split_once('=')
+            .ok_or_else(|| tf(Msg::ReplUsage, &[&"let R = {(1, 2), (2, 3)}"]))?;
+        let (name, expression) = (name.trim(), expression.trim());
+        if !is_valid_name(name) {
+            return Err(tf(Msg::ReplBadName, &[&name]));
+        }
+
+        let matrix = if let Some(path) = expression.strip_prefix("load ") {
+            super::parse::load_relation(Path::new(path.trim()))?
+        } else if let Some(matrix) = self.relations.get(expression) {
+            matrix.clone()
+        } else {
+            super::parse::parse_relation(expression)?
+        };
+        super::visualize::print_matrix(&matrix, name);
+        self.relations.insert(name.to_string(), matrix);
+        Ok(())
+    }
+
+    /// close 이름 폐포 [as 새 이름] - 전후 비교와 추가된 쌍의 근거를 출력하고 결과를 저장
+    fn execute_close(&mut self, name: &str, kind: &str, target: &str) -> Result<(), String> {
+        let kind = ClosureKind::parse(kind).ok_or_else(|| tf(Msg::UnknownClosure, &[&kind]))?;
+        if !is_valid_name(target) {
+            return Err(tf(Msg::ReplBadName, &[&target]));
+        }
+        let matrix = self.relation(name)?;
+        let trace = kind.trace(matrix);
+        super::terminal::print_closure_diff(
+            matrix,
+            &trace.matrix,
+            (name, &format!("{} ({})", target, kind.label())),
+        );
+        super::closure::print_justification_table(&trace);
+        self.relations.insert(target.to_string(), trace.matrix);
+        Ok(())
+    }
+
+    /// 커서 앞까지의 입력에서 마지막 단어의 완성 후보를 찾는 함수
+    /// 첫 단어는 명령, close의 세 번째 단어는 폐포 이름, 그 밖에는 관계 이름을 후보로 사용
+    pub fn complete(&self, before_cursor: &str) -> Vec<String> {
+        let mut words: Vec<&str> = before_cursor.split_whitespace().collect();
+        if before_cursor.is_empty() || before_cursor.ends_with(char::is_whitespace) {
+            words.push("");
+        }
+        let Some((&partial, previous)) = words.split_last() else {
+            return Vec::new();
+        };
+
+        let candidates: Vec<&str> = match previous {
+            [] => COMMANDS.to_vec(),
+            ["close", _] => CLOSURE_NAMES.to_vec(),
+            ["save", _] | ["let", _] | ["let", _, "=", "load"] => Vec::new(),
+            _ => self.relations.keys().map(String::as_str).collect(),
+        };
+        candidates
+            .into_iter()
+            .filter(|candidate| candidate.starts_with(partial))
+            .map(str::to_string)
+            .collect()
+    }
+}
+
+/// 기록 파일 경로 (RELATION_HISTORY 환경 변수, 없으면 홈 디렉터리의 .relation_history)
+fn history_path() -> Option<PathBuf> {
+    std::env::var_os("RELATION_HISTORY")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| Path::new(&home).join(".relation_history")))
+}
+
+/// 기록 파일을 읽는 헬퍼 함수 (없으면 빈 기록)
+fn load_history(path: Option<&Path>) -> Vec<String> {
+    path.and_then(|path| std::fs::read_to_string(path).ok())
+        .map(|text| text.lines().map(str::to_string).collect())
+        .unwrap_or_default()
+}
+
+/// 기록 파일 끝에 한 줄을 덧붙이는 헬퍼 함수 (실패해도 셸은 계속 동작)
+fn append_history(path: Option<&Path>, line: &str) {
+    if let Some(path) = path
+        && let Ok(mut file) = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+    {
+        let _ = writeln!(file, "{}", line);
+    }
+}
+
+/// 표준 입력에서 UTF-8 문자 하나를 읽는 헬퍼 함수 (입력이 끝나면 None)
+fn read_char(input: &mut impl Read) -> io::Result<Option<char>> {
+    let mut bytes = [0u8; 4];
+    if input.read(&mut bytes[..1])? == 0 {
+        return Ok(None);
+    }
+    let length = match bytes[0] {
+        0xf0..=0xf7 => 4,
+        0xe0..=0xef => 3,
+        0xc0..=0xdf => 2,
+        _ => 1,
+    };
+    input.read_exact(&mut bytes[1..length])?;
+    Ok(std::str::from_utf8(&bytes[..length])
+        .ok()
+        .and_then(|text| text.chars().next()))
+}
+
+/// 줄 편집기에서 처리하는 입력 하나 - 일반 문자, 이스케이프 시퀀스로 들어온 특수 키, 모르는 시퀀스
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum EditInput {
+    Char(char),
+    Special(SpecialKey),
+    Unknown,
+}
+
+/// 줄 편집기의 입력 하나를 읽는 헬퍼 함수 (입력이 끝나면 None)
+/// 이스케이프 시퀀스는 tui::parse_escape로 CSI 전체를 한 번에 소비하므로 단독 ESC에서도 멈추지 않음
+fn read_edit_input(input: &mut impl BufRead) -> io::Result<Option<EditInput>> {
+    let bytes = input.fill_buf()?;
+    if bytes.first() == Some(&0x1b) {
+        let (length, special) = parse_escape(bytes);
+        input.consume(length);
+        return Ok(Some(special.map_or(EditInput::Unknown, EditInput::Special)));
+    }
+    Ok(read_char(input)?.map(EditInput::Char))
+}
+
+/// 터미널이 아닐 때 한 줄을 그대로 읽는 헬퍼 함수 (입력이 끝나면 None)
+fn read_line_plain() -> io::Result<Option<String>> {
+    let mut line = String::new();
+    if io::stdin().read_line(&mut line)? == 0 {
+        return Ok(None);
+    }
+    Ok(Some(line.trim_end_matches(['\r', '\n']).to_string()))
+}
+
+/// 여러 후보의 공통 접두사를 구하는 헬퍼 함수
+fn common_prefix(candidates: &[String]) -> String {
+    let first = &candidates[0];
+    let mut length = first.len();
+    for candidate in &candidates[1..] {
+        length = first
+            .char_indices()
+            .zip(candidate.chars())
+            .take_while(|((_, a), b)| a == b)
+            .map(|((i, a), _)| i + a.len_utf8())
+            .last()
+            .unwrap_or(0)
+            .min(length);
+    }
+    first[..length].to_string()
+}
+
+/// 줄 편집 모드(RawMode::line_editing)에서 한 줄을 편집하며 읽는 함수
+/// 좌우 화살표로 커서 이동, Home/End로 줄 처음/끝 이동, 위아래 화살표로 기록 탐색, Tab으로 자동 완성,
+/// Backspace/Delete로 삭제, Ctrl-C로 줄 비우기, 빈 줄에서 Ctrl-D를 누르면 None
+fn read_line_interactive(
+    prompt: &str,
+    history: &[String],
+    session: &Session,
+) -> io::Result<Option<String>> {
+    let mut stdin = io::stdin().lock();
+    let mut stdout = io::stdout();
+    let mut buffer: Vec<char> = Vec::new();
+    let mut cursor = 0;
+    let mut recall = history.len();
+
+    loop {
+        // 줄 전체를 다시 그리고 커서를 제자리로 옮김
+        let line: String = buffer.iter().collect();
+        let tail: String = buffer[cursor..].iter().collect();
+        write!(stdout, "\r\x1b[K{}{}", prompt, line)?;
+        let back = super::terminal::display_width(&tail);
+        if back > 0 {
+            write!(stdout, "\x1b[{}D", back)?;
+        }
+        stdout.flush()?;
+
+        let c = match read_edit_input(&mut stdin)? {
+            None => return Ok(None),
+            Some(EditInput::Char(c)) => c,
+            Some(EditInput::Special(key)) => {
+                match key {
+                    SpecialKey::Up | SpecialKey::Down => {
+                        recall = if key == SpecialKey::Up {
+                            recall.saturating_sub(1)
+                        } else {
+                            (recall + 1).min(history.len())
+                        };
+                        buffer = history
+                            .get(recall)
+                            .map(|line| line.chars().collect())
+                            .unwrap_or_default();
+                        cursor = buffer.len();
+                    }
+                    SpecialKey::Right => cursor = (cursor + 1).min(buffer.len()),
+                    SpecialKey::Left => cursor = cursor.saturating_sub(1),
+                    SpecialKey::Home => cursor = 0,
+                    SpecialKey::End => cursor = buffer.len(),
+                    SpecialKey::Delete if cursor < buffer.len() => {
+                        buffer.remove(cursor);
+                    }
+                    SpecialKey::Delete => {}
+                }
+                continue;
+            }
+            Some(EditInput::Unknown) => continue,
+        };
+        match c {
+            '\r' | '\n' => {
+                write!(stdout, "\r\n")?;
+                return Ok(Some(line));
+            }
+            '\x04' if buffer.is_empty() => {
+                write!(stdout, "\r\n")?;
+                return Ok(None);
+            }
+            '\x03' => {
+                write!(stdout, "^C\r\n")?;
+                buffer.clear();
+                cursor = 0;
+            }
+            '\x7f' | '\x08' if cursor > 0 => {
+                cursor -= 1;
+                buffer.remove(cursor);
+            }
+            '\t' => {
+                let before: String = buffer[..cursor].iter().collect();
+                let candidates = session.complete(&before);
+                if candidates.is_empty() {
+                    continue;
+                }
+                let partial_length = before
+                    .chars()
+                    .rev()
+                    .take_while(|c| !c.is_whitespace())
+                    .count();
+                let mut completion: Vec<char> = common_prefix(&candidates).chars().collect();
+                if candidates.len() == 1 {
+                    completion.push(' ');
+                } else if completion.len() == partial_length {
+                    write!(stdout, "\r\n{}\r\n", candidates.join("  "))?;
+                }
+                let start = cursor - partial_length;
+                buffer.splice(start..cursor, completion.iter().copied());
+                cursor = start + completion.len();
+            }
+            c if !c.is_control() => {
+                buffer.insert(cursor, c);
+                cursor += 1;
+            }
+            _ => {}
+        }
+    }
+}
+
+/// 대화형 셸을 실행하는 함수
+/// 표준 입력이 터미널이면 줄 편집, 기록, 자동 완성을 사용하고, 아니면 한 줄에 한 명령씩 읽어 실행
+pub fn run_repl() {
+    let interactive = io::stdin().is_terminal() && io::stdout().is_terminal();
+    let mut session = Session::new();
+    let path = history_path();
+    let mut history = if interactive {
+        load_history(path.as_deref())
+    } else {
+        Vec::new()
+    };
+
+    // 줄 편집 모드는 세션 동안 한 번만 켜고, 끝나면 가드가 원래 설정으로 복원
+    let _raw = match interactive.then(RawMode::line_editing).transpose() {
+        Ok(raw) => raw,
+        Err(e) => {
+            eprintln!("{}", tf(Msg::ErrorPrefix, &[&e]));
+            return;
+        }
+    };
+    if interactive {
+        println!("{}", t(Msg::ReplBanner));
+    }
+    loop {
+        let line = if interactive {
+            read_line_interactive("relation> ", &history, &session)
+        } else {
+            read_line_plain()
+        };
+        let line = match line {
+            Ok(Some(line)) => line,
+            Ok(None) => break,
+            Err(e) => {
+                eprintln!("{}", tf(Msg::ErrorPrefix, &[&e]));
+                break;
+            }
+        };
+        if line.trim().is_empty() {
+            continue;
+        }
+        if interactive && history.last() != Some(&line) {
+            append_history(path.as_deref(), &line);
+            history.push(line.clone());
+        }
+        match session.execute(&line) {
+            Ok(true) => {}
+            Ok(false) => break,
+            Err(e) => eprintln!("{}", tf(Msg::ErrorPrefix, &[&e])),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 여러 명령을 차례로 실행하는 헬퍼 함수 (모두 성공해야 함)
+    fn session_with(lines: &[&str]) -> Session {
+        let mut session = Session::new();
+        for line in lines {
+            assert_eq!(session.execute(line), Ok(true), "{}", line);
+        }
+        session
+    }
+
+    #[test]
+    fn let_stores_notation_and_copies() {
+        let session = session_with(&["let R = {(1, 2), (2, 3)}", "let S = R", "let T=[1 0; 0 1]"]);
+        let path = vec![vec![0, 1, 0], vec![0, 0, 1], vec![0, 0, 0]];
+        assert_eq!(session.get("R"), Some(&path));
+        assert_eq!(session.get("S"), Some(&path));
+        assert_eq!(session.get("T"), Some(&vec![vec![1, 0], vec![0, 1]]));
+        assert_eq!(session.get("U"), None);
+    }
+
+    #[test]
+    fn close_replaces_or_stores_as_new_name() {
+        let session = session_with(&[
+            "let R = {(1, 2), (2, 3)}",
+            "close R trans as T",
+            "close R refl",
+        ]);
+        assert_eq!(
+            session.get("T"),
+            Some(&vec![vec![0, 1, 1], vec![0, 0, 1], vec![0, 0, 0]])
+        );
+        assert_eq!(
+            session.get("R"),
+            Some(&vec![vec![1, 1, 0], vec![0, 1, 1], vec![0, 0, 1]])
+        );
+    }
+
+    #[test]
+    fn read_only_commands_keep_session() {
+        let mut session = session_with(&["let E = [1 1; 1 1]", "let R = {(1, 2)}"]);
+        for line in [
+            "",
+            "show R",
+            "check R",
+            "classes E",
+            "classes R",
+            "list",
+            "help",
+        ] {
+            assert_eq!(session.execute(line), Ok(true), "{}", line);
+        }
+        assert_eq!(session.relations.len(), 2);
+        assert_eq!(session.execute("quit"), Ok(false));
+        assert_eq!(session.execute("  exit "), Ok(false));
+    }
+
+    #[test]
+    fn errors_are_reported() {
+        let mut session = session_with(&["let R = {(1, 2)}"]);
+        assert_eq!(
+            session.execute("show X"),
+            Err(tf(Msg::ReplUnknownRelation, &[&"X"]))
+        );
+        assert_eq!(
+            session.execute("frobnicate R"),
+            Err(tf(Msg::ReplUnknownCommand, &[&"frobnicate"]))
+        );
+        assert_eq!(
+            session.execute("show"),
+            Err(tf(Msg::ReplUsage, &[&t(Msg::ReplHelp)]))
+        );
+        assert_eq!(
+            session.execute("let 1x = R"),
+            Err(tf(Msg::ReplBadName, &[&"1x"]))
+        );
+        assert_eq!(
+            session.execute("let list = R"),
+            Err(tf(Msg::ReplBadName, &[&"list"]))
+        );
+        assert_eq!(
+            session.execute("close R bogus"),
+            Err(tf(Msg::UnknownClosure, &[&"bogus"]))
+        );
+        assert_eq!(
+            session.execute("let S = nonsense"),
+            Err(tf(Msg::ParseUnknownNotation, &[&"nonsense"]))
+        );
+        // 실패한 명령은 세션을 바꾸지 않음
+        assert_eq!(session.relations.len(), 1);
+    }
+
+    #[test]
+    fn save_and_load_round_trip() {
+        let path = std::env::temp_dir().join(format!("relation-repl-{}.csv", std::process::id()));
+        let mut session = session_with(&["let R = {(1, 2), (2, 1)} on 3"]);
+        let save = format!("save R {}", path.display());
+        assert_eq!(session.execute(&save), Ok(true));
+        let load = format!("let S = load {}", path.display());
+        assert_eq!(session.execute(&load), Ok(true));
+        assert_eq!(session.get("S"), session.get("R"));
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn completes_commands_closures_and_names() {
+        let session = session_with(&["let R = [1]", "let Rel = [0]", "let S = [1]"]);
+        assert_eq!(session.complete("c"), vec!["check", "close", "classes"]);
+        assert_eq!(session.complete("").len(), COMMANDS.len());
+        assert_eq!(session.complete("show R"), vec!["R", "Rel"]);
+        assert_eq!(session.complete("show "), vec!["R", "Rel", "S"]);
+        assert_eq!(session.complete("close R "), CLOSURE_NAMES.to_vec());
+        assert_eq!(session.complete("close R tr"), vec!["trans"]);
+        assert_eq!(session.complete("close R trans as "), vec!["R", "Rel", "S"]);
+        assert_eq!(session.complete("let T = R"), vec!["R", "Rel"]);
+        // = 자리와 파일 경로는 완성하지 않음
+        assert!(session.complete("let T ").is_empty());
+        assert!(session.complete("save R ").is_empty());
+        assert!(session.complete("let T = load ").is_empty());
+        assert!(session.complete("show X").is_empty());
+    }
+
+    #[test]
+    fn common_prefix_respects_characters() {
+        let words = |list: &[&str]| list.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        assert_eq!(common_prefix(&words(&["check", "close", "classes"])), "c");
+        assert_eq!(common_prefix(&words(&["close", "classes"])), "cl");
+        assert_eq!(common_prefix(&words(&["관계1", "관계2"])), "관계");
+        assert_eq!(common_prefix(&words(&["R"])), "R");
+    }
+
+    #[test]
+    fn utf8_characters_are_read_whole() {
+        let mut input: &[u8] = "a관\x7f".as_bytes();
+        assert_eq!(read_char(&mut input).unwrap(), Some('a'));
+        assert_eq!(read_char(&mut input).unwrap(), Some('관'));
+        assert_eq!(read_char(&mut input).unwrap(), Some('\x7f'));
+        assert_eq!(read_char(&mut input).unwrap(), None);
+    }
+
+    #[test]
+    fn escape_sequences_are_read_whole() {
+        // Delete(ESC [ 3 ~)의 ~가 글자로 입력되지 않아야 함
+        let mut input: &[u8] = "\x1b[3~\x1b[H\x1b[1;5D관".as_bytes();
+        let mut inputs = Vec::new();
+        while let Some(next) = read_edit_input(&mut input).unwrap() {
+            inputs.push(next);
+        }
+        assert_eq!(
+            inputs,
+            vec![
+                EditInput::Special(SpecialKey::Delete),
+                EditInput::Special(SpecialKey::Home),
+                EditInput::Unknown,
+                EditInput::Char('관'),
+            ]
+        );
+    }
+
+    #[test]
+    fn history_file_is_appended() {
+        let path = std::env::temp_dir().join(format!("relation-history-{}", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        assert!(load_history(Some(&path)).is_empty());
+        append_history(Some(&path), "let R = [1]");
+        append_history(Some(&path), "show R");
+        assert_eq!(load_history(Some(&path)), vec!["let R = [1]", "show R"]);
+        // 경로가 없으면 아무것도 하지 않음
+        append_history(None, "list");
+        assert!(load_history(None).is_empty());
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,511 @@
+/// 출력과 분리된 순수 분석 계층 - 관계의 속성, 분류, 위반 사항, 동치류, 폐포를 데이터로 반환하는 모듈
+/// 같은 결과를 render 모듈의 Renderer 구현들이 터미널, Markdown, HTML, JSON 등으로 출력
+use crate::Matrix;
+use crate::closure::ClosureTrace;
+use crate::i18n::{Msg, t};
+
+/// 관계의 기본 속성 판별 결과
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Properties {
+    pub reflexive: bool,
+    pub symmetric: bool,
+    pub transitive: bool,
+    pub antisymmetric: bool,
+    pub irreflexive: bool,
+    pub connected: bool,
+}
+
+impl Properties {
+    /// 관계 행렬의 속성을 모두 판별하는 함수
+    pub fn of(matrix: &Matrix) -> Properties {
+        Properties {
+            reflexive: super::equivalence::is_reflexive(matrix),
+            symmetric: super::equivalence::is_symmetric(matrix),
+            transitive: super::equivalence::is_transitive(matrix),
+            antisymmetric: super::equivalence::is_antisymmetric(matrix),
+            irreflexive: super::equivalence::is_irreflexive(matrix),
+            connected: super::equivalence::is_connected_relation(matrix),
+        }
+    }
+
+    /// 반사성, 대칭성, 추이성을 모두 만족하는지 여부
+    pub fn is_equivalence(&self) -> bool {
+        self.reflexive && self.symmetric && self.transitive
+    }
+
+    /// 반대칭성과 추이성을 모두 만족하는지 여부 (equivalence::is_partial_order와 같은 기준)
+    pub fn is_partial_order(&self) -> bool {
+        self.antisymmetric && self.transitive
+    }
+
+    /// (키, 이름 메시지, 만족 여부) 목록 - 출력 순서를 한 곳에서 정하기 위한 함수
+    pub fn entries(&self) -> [(&'static str, Msg, bool); 6] {
+        [
+            ("reflexive", Msg::Reflexivity, self.reflexive),
+            ("symmetric", Msg::Symmetry, self.symmetric),
+            ("transitive", Msg::Transitivity, self.transitive),
+            ("antisymmetric", Msg::Antisymmetry, self.antisymmetric),
+            ("irreflexive", Msg::Irreflexivity, self.irreflexive),
+            ("connected", Msg::Connectedness, self.connected),
+        ]
+    }
+}
+
+/// 관계 분류
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Classification {
+    /// 반사적, 대칭적, 추이적
+    Equivalence,
+    /// 반대칭적, 추이적 (equivalence::is_partial_order와 같은 기준으로 반사성은 보지 않음)
+    PartialOrder,
+    /// 비반사적, 추이적 (따라서 비대칭적)
+    StrictPartialOrder,
+    /// 비반사적, 추이적, 연결적
+    StrictTotalOrder,
+    /// 반사적, 반대칭적, 추이적, 연결적
+    TotalOrder,
+}
+
+impl Classification {
+    /// JSON 등에서 사용하는 고정된 키
+    pub fn key(self) -> &'static str {
+        match self {
+            Classification::Equivalence => "equivalence",
+            Classification::PartialOrder => "partial_order",
+            Classification::StrictPartialOrder => "strict_partial_order",
+            Classification::StrictTotalOrder => "strict_total_order",
+            Classification::TotalOrder => "total_order",
+        }
+    }
+
+    /// 현재 언어로 된 분류 이름
+    pub fn label(self) -> &'static str {
+        t(match self {
+            Classification::Equivalence => Msg::ClassEquivalence,
+            Classification::PartialOrder => Msg::ClassPartialOrder,
+            Classification::StrictPartialOrder => Msg::ClassStrictPartialOrder,
+            Classification::StrictTotalOrder => Msg::ClassStrictTotalOrder,
+            Classification::TotalOrder => Msg::ClassTotalOrder,
+        })
+    }
+}
+
+/// 속성 판별 결과로부터 관계가 속하는 분류들을 반환하는 함수
+pub fn classify(properties: &Properties) -> Vec<Classification> {
+    let p = properties;
+    let mut classes = Vec::new();
+    if p.reflexive && p.symmetric && p.transitive {
+        classes.push(Classification::Equivalence);
+    }
+    if p.is_partial_order() {
+        classes.push(Classification::PartialOrder);
+    }
+    if p.irreflexive && p.transitive {
+        classes.push(Classification::StrictPartialOrder);
+    }
+    if p.irreflexive && p.transitive && p.connected {
+        classes.push(Classification::StrictTotalOrder);
+    }
+    if p.reflexive && p.antisymmetric && p.transitive && p.connected {
+        classes.push(Classification::TotalOrder);
+    }
+    classes
+}
+
+/// 추이성 위반 사항 목록에 담는 최대 개수 (위반은 최대 n³개까지 생길 수 있음)
+pub const MAX_TRANSITIVE_WITNESSES: usize = 100;
+
+/// 각 속성의 위반 사항 (속성이 성립하면 비어 있음)
+/// reflexive: R(i,i) = 0인 i, symmetric: R(i,j) ≠ R(j,i)인 (i, j),
+/// transitive: R(i,j) = R(j,k) = 1 이지만 R(i,k) = 0인 (i, j, k) 중 앞의 MAX_TRANSITIVE_WITNESSES개,
+/// transitive_count: 추이성 위반 (i, j, k)의 전체 개수,
+/// antisymmetric: i < j 이고 R(i,j) = R(j,i) = 1인 (i, j), irreflexive: R(i,i) = 1인 i,
+/// connected: i < j 이고 R(i,j) = R(j,i) = 0인 (i, j)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Violations {
+    pub reflexive: Vec<usize>,
+    pub symmetric: Vec<(usize, usize)>,
+    pub transitive: Vec<(usize, usize, usize)>,
+    pub transitive_count: usize,
+    pub antisymmetric: Vec<(usize, usize)>,
+    pub irreflexive: Vec<usize>,
+    pub connected: Vec<(usize, usize)>,
+}
+
+/// 모든 속성의 위반 사항을 찾는 함수
+pub fn find_violations(matrix: &Matrix) -> Violations {
+    let n = matrix.len();
+    let mut violations = Violations {
+        reflexive: (0..n).filter(|&i| matrix[i][i] != 1).collect(),
+        symmetric: Vec::new(),
+        transitive: Vec::new(),
+        transitive_count: 0,
+        antisymmetric: Vec::new(),
+        irreflexive: (0..n).filter(|&i| matrix[i][i] == 1).collect(),
+        connected: Vec::new(),
+    };
+    for i in 0..n {
+        for j in 0..n {
+            if matrix[i][j] != matrix[j][i] {
+                violations.symmetric.push((i, j));
+            }
+            if i < j && matrix[i][j] == 1 && matrix[j][i] == 1 {
+                violations.antisymmetric.push((i, j));
+            }
+            if i < j && matrix[i][j] == 0 && matrix[j][i] == 0 {
+                violations.connected.push((i, j));
+            }
+            if matrix[i][j] != 1 {
+                continue;
+            }
+            for (k, &value) in matrix[j].iter().enumerate() {
+                if value == 1 && matrix[i][k] != 1 {
+                    violations.transitive_count += 1;
+                    if violations.transitive.len() < MAX_TRANSITIVE_WITNESSES {
+                        violations.transitive.push((i, j, k));
+                    }
+                }
+            }
+        }
+    }
+    violations
+}
+
+/// 보고서에 포함되는 폐포의 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClosureKind {
+    Reflexive,
+    Symmetric,
+    Transitive,
+    Equivalence,
+}
+
+impl ClosureKind {
+    /// 폐포 이름을 해석하는 함수 (짧은 이름 refl/sym/trans/equiv와 key 모두 허용)
+    pub fn parse(name: &str) -> Option<ClosureKind> {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "refl" | "reflexive" => Some(ClosureKind::Reflexive),
+            "sym" | "symmetric" => Some(ClosureKind::Symmetric),
+            "trans" | "transitive" => Some(ClosureKind::Transitive),
+            "equiv" | "equivalence" => Some(ClosureKind::Equivalence),
+            _ => None,
+        }
+    }
+
+    /// JSON 등에서 사용하는 고정된 키
+    pub fn key(self) -> &'static str {
+        match self {
+            ClosureKind::Reflexive => "reflexive",
+            ClosureKind::Symmetric => "symmetric",
+            ClosureKind::Transitive => "transitive",
+            ClosureKind::Equivalence => "equivalence",
+        }
+    }
+
+    /// 현재 언어로 된 폐포 이름
+    pub fn label(self) -> &'static str {
+        t(match self {
+            ClosureKind::Reflexive => Msg::ReflexiveClosure,
+            ClosureKind::Symmetric => Msg::SymmetricClosure,
+            ClosureKind::Transitive => Msg::TransitiveClosure,
+            ClosureKind::Equivalence => Msg::EquivalenceClosureName,
+        })
+    }
+
+    /// 해당 폐포를 추가된 쌍의 근거와 함께 계산하는 함수
+    pub fn trace(self, matrix: &Matrix) -> ClosureTrace {
+        match self {
+            ClosureKind::Reflexive => super::closure::reflexive_closure_trace(matrix),
+            ClosureKind::Symmetric => super::closure::symmetric_closure_trace(matrix),
+            ClosureKind::Transitive => super::closure::transitive_closure_trace(matrix),
+            ClosureKind::Equivalence => super::closure::equivalence_closure_trace(matrix),
+        }
+    }
+}
+
+/// 한 폐포의 결과와 추가된 쌍의 근거
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClosureResult {
+    pub kind: ClosureKind,
+    pub trace: ClosureTrace,
+}
+
+/// 차원 ≤ 2 판별과 사슬 곱 판별(되추적 탐색)을 보고서에 포함하는 최대 원소 개수
+pub const MAX_DIMENSION_SIZE: usize = 12;
+
+/// 격자 판별(만남/이음 연산표 계산)을 보고서에 포함하는 최대 원소 개수
+pub const MAX_LATTICE_SIZE: usize = 64;
+
+/// 부분 순서의 차원과 사슬 곱 구조
+/// realizer: 교집합이 원래 순서가 되는 두 선형 확장 (차원 ≥ 3이면 None)
+/// chain_product: 순서 동형인 사슬 곱의 사슬 크기들 (없으면 None)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DimensionReport {
+    pub realizer: Option<(Vec<usize>, Vec<usize>)>,
+    pub chain_product: Option<Vec<usize>>,
+}
+
+/// 격자 판별 결과 - 격자가 아니면 나머지 값은 모두 false/None
+/// 반례는 equivalence::distributivity_counterexample, modularity_counterexample와 같은 순서
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LatticeReport {
+    pub lattice: bool,
+    pub bounded: bool,
+    pub distributive_counterexample: Option<(usize, usize, usize)>,
+    pub modular_counterexample: Option<(usize, usize, usize)>,
+    pub complemented: bool,
+}
+
+impl LatticeReport {
+    /// 관계 행렬의 격자 성질을 판별하는 함수
+    pub fn of(matrix: &Matrix) -> LatticeReport {
+        use super::equivalence::*;
+        let Some(tables) = lattice_tables(matrix) else {
+            return LatticeReport {
+                lattice: false,
+                bounded: false,
+                distributive_counterexample: None,
+                modular_counterexample: None,
+                complemented: false,
+            };
+        };
+        LatticeReport {
+            lattice: true,
+            bounded: tables.is_bounded(),
+            distributive_counterexample: distributivity_counterexample(&tables),
+            modular_counterexample: modularity_counterexample(&tables),
+            complemented: is_complemented_lattice(&tables),
+        }
+    }
+
+    /// 분배 격자인지 여부
+    pub fn distributive(&self) -> bool {
+        self.lattice && self.distributive_counterexample.is_none()
+    }
+
+    /// 모듈러 격자인지 여부
+    pub fn modular(&self) -> bool {
+        self.lattice && self.modular_counterexample.is_none()
+    }
+}
+
+/// 부분 순서의 구조 분석 결과
+/// longest_chain/maximum_antichain의 길이가 높이/폭이며,
+/// dimension은 원소가 MAX_DIMENSION_SIZE개, lattice는 MAX_LATTICE_SIZE개 이하일 때만 Some
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OrderReport {
+    pub minimal: Vec<usize>,
+    pub maximal: Vec<usize>,
+    pub least: Option<usize>,
+    pub greatest: Option<usize>,
+    pub longest_chain: Vec<usize>,
+    pub maximum_antichain: Vec<usize>,
+    pub chain_cover: Vec<Vec<usize>>,
+    pub antichain_partition: Vec<Vec<usize>>,
+    pub dimension: Option<DimensionReport>,
+    pub lattice: Option<LatticeReport>,
+}
+
+/// 부분 순서인 관계의 구조를 분석하는 함수 (부분 순서가 아니면 None)
+pub fn analyze_order(matrix: &Matrix) -> Option<OrderReport> {
+    use super::order::*;
+    if !Properties::of(matrix).is_partial_order() {
+        return None;
+    }
+    let n = matrix.len();
+    Some(OrderReport {
+        minimal: minimal_elements(matrix),
+        maximal: maximal_elements(matrix),
+        least: least_element(matrix),
+        greatest: greatest_element(matrix),
+        longest_chain: longest_chain(matrix),
+        maximum_antichain: maximum_antichain(matrix),
+        chain_cover: minimum_chain_cover(matrix),
+        antichain_partition: mirsky_decomposition(matrix),
+        dimension: (n <= MAX_DIMENSION_SIZE).then(|| DimensionReport {
+            realizer: two_dimensional_realizer(matrix),
+            chain_product: product_of_chains_factors(matrix),
+        }),
+        lattice: (n <= MAX_LATTICE_SIZE).then(|| LatticeReport::of(matrix)),
+    })
+}
+
+/// 관계 하나에 대한 전체 분석 결과
+/// classes는 동치 관계일 때만, order는 부분 순서일 때만 Some
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RelationReport {
+    pub matrix: Matrix,
+    pub properties: Properties,
+    pub classifications: Vec<Classification>,
+    pub violations: Violations,
+    pub classes: Option<Vec<Vec<usize>>>,
+    pub closures: Vec<ClosureResult>,
+    pub order: Option<OrderReport>,
+}
+
+impl RelationReport {
+    /// 동치 관계인지 여부
+    pub fn is_equivalence(&self) -> bool {
+        self.properties.is_equivalence()
+    }
+}
+
+/// 관계 행렬을 분석하여 출력 없이 보고서 데이터를 만드는 함수
+pub fn analyze_relation(matrix: &Matrix) -> RelationReport {
+    let properties = Properties::of(matrix);
+    let classes = properties
+        .is_equivalence()
+        .then(|| super::visualize::equivalence_classes(matrix));
+    let closures = [
+        ClosureKind::Reflexive,
+        ClosureKind::Symmetric,
+        ClosureKind::Transitive,
+        ClosureKind::Equivalence,
+    ]
+    .into_iter()
+    .map(|kind| ClosureResult {
+        kind,
+        trace: kind.trace(matrix),
+    })
+    .collect();
+    RelationReport {
+        matrix: matrix.clone(),
+        properties,
+        classifications: classify(&properties),
+        violations: find_violations(matrix),
+        classes,
+        closures,
+        order: analyze_order(matrix),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 0 ≤ 1 ≤ 2 인 사슬 (전순서)
+    fn chain() -> Matrix {
+        vec![vec![1, 1, 1], vec![0, 1, 1], vec![0, 0, 1]]
+    }
+
+    #[test]
+    fn equivalence_report_has_classes_and_no_order() {
+        let matrix = vec![vec![1, 1, 0], vec![1, 1, 0], vec![0, 0, 1]];
+        let report = analyze_relation(&matrix);
+        assert!(report.is_equivalence());
+        assert_eq!(report.classifications, vec![Classification::Equivalence]);
+        assert_eq!(report.classes, Some(vec![vec![0, 1], vec![2]]));
+        assert_eq!(report.order, None);
+        assert_eq!(report.closures.len(), 4);
+        assert!(report.closures.iter().all(|c| c.trace.added.is_empty()));
+    }
+
+    #[test]
+    fn total_order_report() {
+        let report = analyze_relation(&chain());
+        assert_eq!(
+            report.classifications,
+            vec![Classification::PartialOrder, Classification::TotalOrder]
+        );
+        assert_eq!(report.classes, None);
+        let order = report.order.expect("부분 순서");
+        assert_eq!((order.least, order.greatest), (Some(0), Some(2)));
+        assert_eq!(order.longest_chain, vec![0, 1, 2]);
+        assert_eq!(order.maximum_antichain.len(), 1);
+        let lattice = order.lattice.unwrap();
+        assert!(lattice.distributive() && lattice.modular() && !lattice.complemented);
+        assert_eq!(order.dimension.unwrap().chain_product, Some(vec![3]));
+    }
+
+    #[test]
+    fn strict_orders_are_classified() {
+        // 1 < 2 < 3 (엄밀 전순서)
+        let less = vec![vec![0, 1, 1], vec![0, 0, 1], vec![0, 0, 0]];
+        assert_eq!(
+            classify(&Properties::of(&less)),
+            vec![
+                Classification::PartialOrder,
+                Classification::StrictPartialOrder,
+                Classification::StrictTotalOrder
+            ]
+        );
+        // 1 < 2, 3은 비교 불가 (연결적이지 않은 엄밀 부분 순서)
+        let partial = vec![vec![0, 1, 0], vec![0, 0, 0], vec![0, 0, 0]];
+        assert_eq!(
+            classify(&Properties::of(&partial)),
+            vec![
+                Classification::PartialOrder,
+                Classification::StrictPartialOrder
+            ]
+        );
+        // 대칭적인 쌍이 있으면 비반사적이고 추이적일 수 없음
+        let symmetric = vec![vec![0, 1], vec![1, 0]];
+        assert!(classify(&Properties::of(&symmetric)).is_empty());
+    }
+
+    #[test]
+    fn violations_list_every_failure() {
+        // 1 → 2 → 3 (반사, 대칭, 추이 모두 위반)
+        let path = vec![vec![0, 1, 0], vec![0, 0, 1], vec![0, 0, 0]];
+        let violations = find_violations(&path);
+        assert_eq!(violations.reflexive, vec![0, 1, 2]);
+        assert_eq!(violations.symmetric, vec![(0, 1), (1, 0), (1, 2), (2, 1)]);
+        assert_eq!(violations.transitive, vec![(0, 1, 2)]);
+        assert_eq!(violations.transitive_count, 1);
+        assert!(violations.antisymmetric.is_empty() && violations.irreflexive.is_empty());
+        assert_eq!(violations.connected, vec![(0, 2)]);
+
+        let properties = Properties::of(&path);
+        for (key, _, ok) in properties.entries() {
+            let expected = matches!(key, "antisymmetric" | "irreflexive");
+            assert_eq!(ok, expected, "{key}");
+        }
+    }
+
+    #[test]
+    fn transitive_witnesses_are_capped() {
+        // A → B → C (각 5개 원소) 이지만 A → C는 없음: 위반 5 × 5 × 5 = 125개
+        let n = 15;
+        let matrix: Matrix = (0..n)
+            .map(|i| (0..n).map(|j| u8::from(j / 5 == i / 5 + 1)).collect())
+            .collect();
+        let violations = find_violations(&matrix);
+        assert_eq!(violations.transitive_count, 125);
+        assert_eq!(violations.transitive.len(), MAX_TRANSITIVE_WITNESSES);
+        assert_eq!(violations.transitive[0], (0, 5, 10));
+    }
+
+    #[test]
+    fn order_analysis_respects_size_limits() {
+        let antichain = |n: usize| -> Matrix {
+            (0..n)
+                .map(|i| (0..n).map(|j| u8::from(i == j)).collect())
+                .collect()
+        };
+        let small = analyze_order(&antichain(MAX_DIMENSION_SIZE)).unwrap();
+        assert!(small.dimension.is_some() && small.lattice.is_some());
+        let medium = analyze_order(&antichain(MAX_DIMENSION_SIZE + 1)).unwrap();
+        assert!(medium.dimension.is_none() && medium.lattice.is_some());
+        let large = analyze_order(&antichain(MAX_LATTICE_SIZE + 1)).unwrap();
+        assert!(large.dimension.is_none() && large.lattice.is_none());
+        assert_eq!(large.maximum_antichain.len(), MAX_LATTICE_SIZE + 1);
+    }
+
+    #[test]
+    fn closure_kinds_parse_names_and_keys() {
+        for kind in [
+            ClosureKind::Reflexive,
+            ClosureKind::Symmetric,
+            ClosureKind::Transitive,
+            ClosureKind::Equivalence,
+        ] {
+            assert_eq!(ClosureKind::parse(kind.key()), Some(kind));
+        }
+        assert_eq!(
+            ClosureKind::parse(" Equiv "),
+            Some(ClosureKind::Equivalence)
+        );
+        assert_eq!(ClosureKind::parse("sym"), Some(ClosureKind::Symmetric));
+        assert_eq!(ClosureKind::parse("bogus"), None);
+    }
+}
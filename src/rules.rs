@@ -0,0 +1,554 @@
+/// Horn 형태의 폐포 규칙으로 임의의 속성에 대한 폐포를 계산하는 일반 폐포 엔진 모듈
+/// 규칙을 더 이상 새 쌍이 생기지 않을 때까지 반복 적용하여 최소 고정점을 구하고, 추가된 쌍마다 유도 과정을 기록
+use crate::Matrix;
+use crate::i18n::{Msg, t, tf};
+
+/// 관계의 순서쌍 (i, j)
+pub type Pair = (usize, usize);
+
+/// 규칙 한 번의 적용 결과 - 결론 쌍과 그 근거가 된 전제 쌍들
+pub type Inference = (Pair, Vec<Pair>);
+
+/// 폐포 규칙 - "전제 쌍들이 모두 관계에 있으면 결론 쌍도 있어야 한다" 형태의 Horn 규칙
+pub trait ClosureRule {
+    /// 출력에 사용할 규칙 이름
+    fn name(&self) -> &str;
+
+    /// 현재 관계에서 규칙으로 유도되는 모든 (결론, 전제) 목록을 반환
+    /// 이미 관계에 있는 결론은 엔진이 걸러내므로 그대로 반환해도 됨
+    fn infer(&self, matrix: &Matrix) -> Vec<Inference>;
+}
+
+/// 반사 규칙: ⊢ (i, i)
+pub struct ReflexiveRule;
+
+/// 대칭 규칙: (i, j) ⊢ (j, i)
+pub struct SymmetricRule;
+
+/// 추이 규칙: (i, j), (j, k) ⊢ (i, k)
+pub struct TransitiveRule;
+
+/// 유클리드 규칙: (i, j), (i, k) ⊢ (j, k)
+pub struct EuclideanRule;
+
+/// 함수 상 규칙: (a, b) ⊢ (f(a), f(b)), mapping[a] = f(a)
+/// mapping에 없는 원소가 포함된 쌍은 건너뛰고, 범위를 벗어난 상은 엔진이 무시함
+pub struct ImageRule {
+    pub mapping: Vec<usize>,
+}
+
+/// 사용자 정의 규칙의 추론 함수 - 현재 관계로부터 (결론, 전제) 목록을 만듦
+pub type InferFn = Box<dyn Fn(&Matrix) -> Vec<Inference>>;
+
+/// 사용자 정의 규칙 - 이름과 추론 함수를 직접 지정
+pub struct CustomRule {
+    pub name: String,
+    pub infer: InferFn,
+}
+
+/// 관계에 있는 모든 순서쌍을 행 우선 순서로 반환하는 헬퍼 함수
+fn pairs_of(matrix: &Matrix) -> Vec<Pair> {
+    let n = matrix.len();
+    (0..n)
+        .flat_map(|i| (0..n).map(move |j| (i, j)))
+        .filter(|&(i, j)| matrix[i][j] == 1)
+        .collect()
+}
+
+impl ClosureRule for ReflexiveRule {
+    fn name(&self) -> &str {
+        t(Msg::RuleReflexive)
+    }
+
+    fn infer(&self, matrix: &Matrix) -> Vec<Inference> {
+        (0..matrix.len()).map(|i| ((i, i), Vec::new())).collect()
+    }
+}
+
+impl ClosureRule for SymmetricRule {
+    fn name(&self) -> &str {
+        t(Msg::RuleSymmetric)
+    }
+
+    fn infer(&self, matrix: &Matrix) -> Vec<Inference> {
+        pairs_of(matrix)
+            .into_iter()
+            .map(|(i, j)| ((j, i), vec![(i, j)]))
+            .collect()
+    }
+}
+
+impl ClosureRule for TransitiveRule {
+    fn name(&self) -> &str {
+        t(Msg::RuleTransitive)
+    }
+
+    fn infer(&self, matrix: &Matrix) -> Vec<Inference> {
+        let mut result = Vec::new();
+        for (i, j) in pairs_of(matrix) {
+            for (k, &value) in matrix[j].iter().enumerate() {
+                if value == 1 {
+                    result.push(((i, k), vec![(i, j), (j, k)]));
+                }
+            }
+        }
+        result
+    }
+}
+
+impl ClosureRule for EuclideanRule {
+    fn name(&self) -> &str {
+        t(Msg::RuleEuclidean)
+    }
+
+    fn infer(&self, matrix: &Matrix) -> Vec<Inference> {
+        let mut result = Vec::new();
+        for (i, j) in pairs_of(matrix) {
+            for (k, &value) in matrix[i].iter().enumerate() {
+                if value == 1 {
+                    result.push(((j, k), vec![(i, j), (i, k)]));
+                }
+            }
+        }
+        result
+    }
+}
+
+impl ClosureRule for ImageRule {
+    fn name(&self) -> &str {
+        t(Msg::RuleImage)
+    }
+
+    fn infer(&self, matrix: &Matrix) -> Vec<Inference> {
+        pairs_of(matrix)
+            .into_iter()
+            .filter_map(|(a, b)| {
+                let image = (*self.mapping.get(a)?, *self.mapping.get(b)?);
+                Some((image, vec![(a, b)]))
+            })
+            .collect()
+    }
+}
+
+impl ClosureRule for CustomRule {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn infer(&self, matrix: &Matrix) -> Vec<Inference> {
+        (self.infer)(matrix)
+    }
+}
+
+/// 폐포 엔진이 추가한 쌍 하나의 유도 기록
+/// round는 몇 번째 반복에서 추가되었는지(1부터), rule은 사용된 규칙 이름
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Derivation {
+    pub pair: Pair,
+    pub rule: String,
+    pub premises: Vec<Pair>,
+    pub round: usize,
+}
+
+/// 일반 폐포 계산 결과 - 폐포 행렬과 추가된 쌍들의 유도 기록 (추가된 순서)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuleClosure {
+    pub matrix: Matrix,
+    pub derivations: Vec<Derivation>,
+}
+
+/// 규칙 집합에 대한 최소 고정점(폐포)을 계산하는 함수
+/// 매 반복마다 현재 관계에 모든 규칙을 적용하여 새 결론을 한꺼번에 추가하므로,
+/// 각 쌍은 가장 적은 반복 횟수로 유도되는 근거와 함께 기록됨
+pub fn closure_under(matrix: &Matrix, rules: &[&dyn ClosureRule]) -> RuleClosure {
+    let mut current = matrix.clone();
+    let mut derivations = Vec::new();
+    let n = matrix.len();
+    let mut round = 0;
+
+    loop {
+        round += 1;
+        let mut next = current.clone();
+        let mut added = 0;
+        for rule in rules {
+            for ((i, j), premises) in rule.infer(&current) {
+                if i >= n || j >= n || next[i][j] == 1 {
+                    continue;
+                }
+                next[i][j] = 1;
+                added += 1;
+                derivations.push(Derivation {
+                    pair: (i, j),
+                    rule: rule.name().to_string(),
+                    premises,
+                    round,
+                });
+            }
+        }
+        current = next;
+        if added == 0 {
+            break;
+        }
+    }
+
+    RuleClosure {
+        matrix: current,
+        derivations,
+    }
+}
+
+/// 폐포 엔진이 다루는 관계 속성
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Property {
+    Reflexive,
+    Symmetric,
+    Transitive,
+    Euclidean,
+    Equivalence,
+    Irreflexive,
+    Antisymmetric,
+    Asymmetric,
+    Connected,
+}
+
+/// 속성에 대한 폐포가 존재하지 않는 이유
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NoClosure {
+    /// 전체 관계조차 속성을 만족하지 않아, 속성을 만족하는 상위 관계가 없을 수 있음
+    FullRelationFails,
+    /// 속성을 만족하는 두 관계의 교집합이 속성을 만족하지 않아, 가장 작은 상위 관계가 유일하지 않을 수 있음
+    NotClosedUnderIntersection(Matrix, Matrix),
+}
+
+impl Property {
+    /// 모든 속성 목록
+    pub const ALL: [Property; 9] = [
+        Property::Reflexive,
+        Property::Symmetric,
+        Property::Transitive,
+        Property::Euclidean,
+        Property::Equivalence,
+        Property::Irreflexive,
+        Property::Antisymmetric,
+        Property::Asymmetric,
+        Property::Connected,
+    ];
+
+    /// 출력에 사용할 속성 이름
+    pub fn name(&self) -> &'static str {
+        t(match self {
+            Property::Reflexive => Msg::Reflexivity,
+            Property::Symmetric => Msg::Symmetry,
+            Property::Transitive => Msg::Transitivity,
+            Property::Euclidean => Msg::Euclideanness,
+            Property::Equivalence => Msg::EquivalenceProperty,
+            Property::Irreflexive => Msg::Irreflexivity,
+            Property::Antisymmetric => Msg::Antisymmetry,
+            Property::Asymmetric => Msg::Asymmetry,
+            Property::Connected => Msg::Connectedness,
+        })
+    }
+
+    /// 관계가 속성을 만족하는지 판별하는 함수
+    pub fn holds(&self, matrix: &Matrix) -> bool {
+        use super::equivalence::*;
+        match self {
+            Property::Reflexive => is_reflexive(matrix),
+            Property::Symmetric => is_symmetric(matrix),
+            Property::Transitive => is_transitive(matrix),
+            Property::Euclidean => EuclideanRule
+                .infer(matrix)
+                .iter()
+                .all(|&((j, k), _)| matrix[j][k] == 1),
+            Property::Equivalence => is_equivalence_relation(matrix),
+            Property::Irreflexive => is_irreflexive(matrix),
+            Property::Antisymmetric => is_antisymmetric(matrix),
+            Property::Asymmetric => is_antisymmetric(matrix) && is_irreflexive(matrix),
+            Property::Connected => is_connected_relation(matrix),
+        }
+    }
+
+    /// 속성의 폐포를 계산하는 Horn 규칙들을 반환하는 함수
+    /// 폐포가 존재하지 않는 속성이면 알려진 반례를 Err로 반환
+    pub fn closure_rules(&self) -> Result<Vec<Box<dyn ClosureRule>>, NoClosure> {
+        match self {
+            Property::Reflexive => Ok(vec![Box::new(ReflexiveRule)]),
+            Property::Symmetric => Ok(vec![Box::new(SymmetricRule)]),
+            Property::Transitive => Ok(vec![Box::new(TransitiveRule)]),
+            Property::Euclidean => Ok(vec![Box::new(EuclideanRule)]),
+            Property::Equivalence => Ok(vec![
+                Box::new(ReflexiveRule),
+                Box::new(SymmetricRule),
+                Box::new(TransitiveRule),
+            ]),
+            // 전체 관계는 비반사적이지도 반대칭적이지도 않음
+            Property::Irreflexive | Property::Antisymmetric | Property::Asymmetric => {
+                Err(NoClosure::FullRelationFails)
+            }
+            // {(1, 2)}와 {(2, 1)}은 연결 관계지만 교집합인 빈 관계는 아님
+            Property::Connected => Err(NoClosure::NotClosedUnderIntersection(
+                vec![vec![0, 1], vec![0, 0]],
+                vec![vec![0, 0], vec![1, 0]],
+            )),
+        }
+    }
+}
+
+/// 속성 술어에 대해 폐포가 존재하지 못하는 이유를 크기 size 이하의 관계에서 찾는 함수
+/// 사용자 정의 속성처럼 반례가 알려지지 않은 술어를 검사할 때 사용
+/// 모든 관계가 폐포를 가지려면 전체 관계가 속성을 만족하고, 속성을 만족하는 관계들이 교집합에 대해 닫혀 있어야 함
+/// 작은 크기에서 반례가 없으면 None (모든 크기에서 폐포가 존재함을 보장하지는 않음)
+pub fn find_closure_obstruction(
+    predicate: impl Fn(&Matrix) -> bool,
+    size: usize,
+) -> Option<NoClosure> {
+    for n in 1..=size {
+        if !predicate(&vec![vec![1; n]; n]) {
+            return Some(NoClosure::FullRelationFails);
+        }
+    }
+
+    for n in 1..=size {
+        let cells = n * n;
+        let to_matrix = |mask: u32| -> Matrix {
+            (0..n)
+                .map(|i| (0..n).map(|j| ((mask >> (i * n + j)) & 1) as u8).collect())
+                .collect()
+        };
+        let satisfying: Vec<u32> = (0..(1u32 << cells))
+            .filter(|&mask| predicate(&to_matrix(mask)))
+            .collect();
+        for (idx, &a) in satisfying.iter().enumerate() {
+            for &b in &satisfying[idx + 1..] {
+                if !predicate(&to_matrix(a & b)) {
+                    return Some(NoClosure::NotClosedUnderIntersection(
+                        to_matrix(a),
+                        to_matrix(b),
+                    ));
+                }
+            }
+        }
+    }
+    None
+}
+
+/// 순서쌍 목록을 (1, 2), (2, 3) 형식의 문자열로 변환하는 헬퍼 함수
+fn format_pairs(pairs: &[Pair]) -> String {
+    pairs
+        .iter()
+        .map(|&(i, j)| format!("({}, {})", i + 1, j + 1))
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+/// 관계 행렬을 {(1, 2), (2, 3)} 형식의 문자열로 변환하는 헬퍼 함수
+fn format_relation(matrix: &Matrix) -> String {
+    format!("{{{}}}", format_pairs(&pairs_of(matrix)))
+}
+
+/// 유도 기록 하나를 "(1, 3) ← 추이: (1, 2), (2, 3)" 형식의 문자열로 변환하는 함수
+pub fn describe_derivation(derivation: &Derivation) -> String {
+    let (i, j) = derivation.pair;
+    if derivation.premises.is_empty() {
+        tf(
+            Msg::DerivationAxiom,
+            &[&(i + 1), &(j + 1), &derivation.rule],
+        )
+    } else {
+        tf(
+            Msg::DerivationStep,
+            &[
+                &(i + 1),
+                &(j + 1),
+                &derivation.rule,
+                &format_pairs(&derivation.premises),
+            ],
+        )
+    }
+}
+
+/// 모든 속성에 대해 일반 폐포 엔진을 적용하고 유도 과정 또는 폐포가 없는 이유를 출력하는 함수
+pub fn print_rule_closure_analysis(matrix: &Matrix) {
+    println!("\n=== {} ===", t(Msg::RuleClosureTitle));
+
+    const SHOWN: usize = 20;
+    for property in Property::ALL {
+        println!("\n--- {} ---", property.name());
+        match property.closure_rules() {
+            Ok(rules) => {
+                let rule_refs: Vec<&dyn ClosureRule> = rules.iter().map(|r| r.as_ref()).collect();
+                let closure = closure_under(matrix, &rule_refs);
+                if closure.derivations.is_empty() {
+                    println!("{}", tf(Msg::RuleClosureAlready, &[&property.name()]));
+                    continue;
+                }
+                println!(
+                    "{}",
+                    tf(
+                        Msg::RuleClosureAdded,
+                        &[
+                            &closure.derivations.last().map_or(0, |d| d.round),
+                            &closure.derivations.len(),
+                        ]
+                    )
+                );
+                for derivation in closure.derivations.iter().take(SHOWN) {
+                    println!("  {}", describe_derivation(derivation));
+                }
+                if closure.derivations.len() > SHOWN {
+                    println!(
+                        "{}",
+                        tf(
+                            Msg::RuleClosureMore,
+                            &[&(closure.derivations.len() - SHOWN)]
+                        )
+                    );
+                }
+            }
+            Err(NoClosure::FullRelationFails) => println!("{}", t(Msg::NoClosureFullFails)),
+            Err(NoClosure::NotClosedUnderIntersection(a, b)) => println!(
+                "{}",
+                tf(
+                    Msg::NoClosureIntersection,
+                    &[&format_relation(&a), &format_relation(&b)]
+                )
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::closure;
+    use crate::order::{SimpleRng, random_relation};
+
+    /// 속성의 Horn 규칙들로 폐포를 계산하는 헬퍼 함수
+    fn closure_of(matrix: &Matrix, property: Property) -> RuleClosure {
+        let rules = property.closure_rules().expect("폐포가 존재해야 함");
+        let refs: Vec<&dyn ClosureRule> = rules.iter().map(|rule| rule.as_ref()).collect();
+        closure_under(matrix, &refs)
+    }
+
+    #[test]
+    fn engine_matches_dedicated_closures() {
+        for seed in 0..30 {
+            let matrix = random_relation(&mut SimpleRng::new(seed), 5, 4);
+            assert_eq!(
+                closure_of(&matrix, Property::Reflexive).matrix,
+                closure::reflexive_closure(&matrix)
+            );
+            assert_eq!(
+                closure_of(&matrix, Property::Symmetric).matrix,
+                closure::symmetric_closure(&matrix)
+            );
+            assert_eq!(
+                closure_of(&matrix, Property::Transitive).matrix,
+                closure::transitive_closure(&matrix)
+            );
+            assert_eq!(
+                closure_of(&matrix, Property::Equivalence).matrix,
+                closure::equivalence_closure(&matrix)
+            );
+            assert!(Property::Euclidean.holds(&closure_of(&matrix, Property::Euclidean).matrix));
+        }
+    }
+
+    #[test]
+    fn derivations_only_use_known_pairs() {
+        for seed in 0..30 {
+            let matrix = random_relation(&mut SimpleRng::new(seed), 5, 4);
+            let result = closure_of(&matrix, Property::Equivalence);
+            let mut known = matrix.clone();
+            let mut round = 1;
+            for derivation in &result.derivations {
+                assert!(derivation.round >= round);
+                round = derivation.round;
+                // 같은 반복에서 추가된 쌍은 전제로 쓰이지 않음
+                let earlier: Vec<Pair> = result
+                    .derivations
+                    .iter()
+                    .filter(|d| d.round < derivation.round)
+                    .map(|d| d.pair)
+                    .collect();
+                for &(a, b) in &derivation.premises {
+                    assert!(matrix[a][b] == 1 || earlier.contains(&(a, b)));
+                }
+                let (i, j) = derivation.pair;
+                assert_eq!(known[i][j], 0, "같은 쌍이 두 번 추가됨");
+                known[i][j] = 1;
+            }
+            assert_eq!(known, result.matrix);
+        }
+    }
+
+    #[test]
+    fn transitive_chain_needs_logarithmic_rounds() {
+        // 0 → 1 → ... → 7: 반복마다 도달 거리가 두 배가 되므로 3번째 반복까지 새 쌍이 생김
+        let mut path = vec![vec![0; 8]; 8];
+        for i in 0..7 {
+            path[i][i + 1] = 1;
+        }
+        let result = closure_of(&path, Property::Transitive);
+        assert_eq!(result.derivations.iter().map(|d| d.round).max(), Some(3));
+        assert_eq!(result.derivations.len(), 28 - 7);
+    }
+
+    #[test]
+    fn image_and_custom_rules() {
+        // f = (0 1 2) 순환: (0, 0)의 상을 계속 따라가면 대각선 전체
+        let rule = ImageRule {
+            mapping: vec![1, 2, 0],
+        };
+        let mut matrix = vec![vec![0; 3]; 3];
+        matrix[0][0] = 1;
+        assert_eq!(
+            closure_under(&matrix, &[&rule]).matrix,
+            closure::reflexive_closure(&vec![vec![0; 3]; 3])
+        );
+
+        // 범위를 벗어난 결론은 무시
+        let custom = CustomRule {
+            name: "out of range".to_string(),
+            infer: Box::new(|_| vec![((5, 5), Vec::new()), ((0, 1), Vec::new())]),
+        };
+        let result = closure_under(&matrix, &[&custom]);
+        assert_eq!(result.derivations.len(), 1);
+        assert_eq!(result.derivations[0].rule, "out of range");
+        assert_eq!(result.matrix[0][1], 1);
+    }
+
+    #[test]
+    fn properties_without_closures_are_explained() {
+        for property in [
+            Property::Irreflexive,
+            Property::Antisymmetric,
+            Property::Asymmetric,
+        ] {
+            assert_eq!(
+                property.closure_rules().err(),
+                Some(NoClosure::FullRelationFails)
+            );
+        }
+        let Err(NoClosure::NotClosedUnderIntersection(a, b)) = Property::Connected.closure_rules()
+        else {
+            panic!("연결성은 교집합에 대해 닫혀 있지 않음");
+        };
+        let meet: Matrix = a
+            .iter()
+            .zip(&b)
+            .map(|(x, y)| x.iter().zip(y).map(|(p, q)| p & q).collect())
+            .collect();
+        assert!(Property::Connected.holds(&a) && Property::Connected.holds(&b));
+        assert!(!Property::Connected.holds(&meet));
+        assert_eq!(
+            find_closure_obstruction(|m| Property::Antisymmetric.holds(m), 3),
+            Some(NoClosure::FullRelationFails)
+        );
+        assert!(find_closure_obstruction(|m| Property::Connected.holds(m), 3).is_some());
+        assert_eq!(
+            find_closure_obstruction(|m| Property::Transitive.holds(m), 2),
+            None
+        );
+    }
+}
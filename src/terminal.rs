@@ -0,0 +1,327 @@
+/// 터미널용 행렬 출력 모듈 - 행/열 머리글, ANSI 색상, 위반 칸 강조, 폐포 전후 나란히 비교
+/// 표준 출력이 터미널이 아니면 (또는 NO_COLOR가 설정되면) 색상 없이 [ ]로 칸을 표시
+use std::io::IsTerminal;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+use crate::Matrix;
+use crate::i18n::{Msg, t, tf};
+
+/// 색상 사용 방식
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorMode {
+    Auto,
+    Always,
+    Never,
+}
+
+/// 현재 색상 사용 방식 (ColorMode as u8)
+static MODE: AtomicU8 = AtomicU8::new(0);
+
+const RESET: &str = "\x1b[0m";
+const HEADER: &str = "\x1b[1;36m";
+const ONE: &str = "\x1b[32m";
+const ZERO: &str = "\x1b[2m";
+const MARKED: &str = "\x1b[1;97;41m";
+
+impl ColorMode {
+    /// "auto", "always", "never"를 해석하는 함수
+    pub fn parse(name: &str) -> Option<ColorMode> {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "auto" => Some(ColorMode::Auto),
+            "always" => Some(ColorMode::Always),
+            "never" => Some(ColorMode::Never),
+            _ => None,
+        }
+    }
+}
+
+/// 색상 사용 방식을 설정하는 함수
+pub fn set_color_mode(mode: ColorMode) {
+    MODE.store(mode as u8, Ordering::Relaxed);
+}
+
+/// 현재 설정에서 ANSI 색상을 사용할지 여부
+/// Auto일 때는 표준 출력이 터미널이고 NO_COLOR가 없을 때만 사용
+pub fn use_color() -> bool {
+    match MODE.load(Ordering::Relaxed) {
+        1 => true,
+        2 => false,
+        _ => std::io::stdout().is_terminal() && std::env::var_os("NO_COLOR").is_none(),
+    }
+}
+
+/// 선택한 속성을 위반하는 칸들을 반환하는 함수 (키는 Properties::entries와 같음)
+/// 반사성: 비어 있는 대각선, 대칭성: R(i,j) ≠ R(j,i)인 칸, 추이성: 채워져야 하는 R(i,k),
+/// 반대칭성: 양방향인 쌍, 비반사성: 채워진 대각선, 연결성: 양방향 모두 비어 있는 쌍
+/// 알 수 없는 키는 None
+pub fn violation_cells(matrix: &Matrix, key: &str) -> Option<Vec<(usize, usize)>> {
+    let violations = super::report::find_violations(matrix);
+    let both =
+        |pairs: &[(usize, usize)]| pairs.iter().flat_map(|&(i, j)| [(i, j), (j, i)]).collect();
+    let mut cells: Vec<(usize, usize)> = match key {
+        "reflexive" => violations.reflexive.iter().map(|&i| (i, i)).collect(),
+        "symmetric" => violations.symmetric,
+        // 위반 목록은 잘려 있을 수 있으므로 R∘R에는 있지만 R에는 없는 칸을 직접 계산
+        "transitive" => {
+            let n = matrix.len();
+            (0..n)
+                .flat_map(|i| (0..n).map(move |k| (i, k)))
+                .filter(|&(i, k)| {
+                    matrix[i][k] == 0 && (0..n).any(|j| matrix[i][j] == 1 && matrix[j][k] == 1)
+                })
+                .collect()
+        }
+        "antisymmetric" => both(&violations.antisymmetric),
+        "irreflexive" => violations.irreflexive.iter().map(|&i| (i, i)).collect(),
+        "connected" => both(&violations.connected),
+        _ => return None,
+    };
+    cells.sort_unstable();
+    cells.dedup();
+    Some(cells)
+}
+
+/// 화면에 표시되는 폭을 계산하는 헬퍼 함수 (한글은 두 칸)
+pub(crate) fn display_width(text: &str) -> usize {
+    text.chars()
+        .map(|c| match c {
+            '\u{1100}'..='\u{115F}' | '\u{3130}'..='\u{318F}' | '\u{AC00}'..='\u{D7A3}' => 2,
+            _ => 1,
+        })
+        .sum()
+}
+
+/// 범례에 쓰는 표시된 칸의 견본
+fn marker_sample(color: bool) -> String {
+    if color {
+        format!("{}   {}", MARKED, RESET)
+    } else {
+        "[ ]".to_string()
+    }
+}
+
+/// 칸 하나를 세 글자 폭으로 만드는 헬퍼 함수
+fn format_cell(value: u8, marked: bool, color: bool) -> String {
+    match (marked, color) {
+        (true, true) => format!("{} {} {}", MARKED, value, RESET),
+        (true, false) => format!("[{}]", value),
+        (false, true) => format!(
+            "{} {} {}",
+            if value == 1 { ONE } else { ZERO },
+            value,
+            RESET
+        ),
+        (false, false) => format!(" {} ", value),
+    }
+}
+
+/// 머리글이 붙은 행렬을 줄 단위로 만드는 헬퍼 함수
+/// 반환값은 (줄 목록, 색상 코드를 제외한 줄의 폭)
+fn matrix_lines(matrix: &Matrix, marked: &[(usize, usize)], color: bool) -> (Vec<String>, usize) {
+    let n = matrix.len();
+    let label = n.to_string().len();
+    let paint = |text: String| {
+        if color {
+            format!("{}{}{}", HEADER, text, RESET)
+        } else {
+            text
+        }
+    };
+
+    let header: String = (1..=n).map(|j| format!(" {:>2}", j)).collect();
+    let mut lines = vec![format!("{:>label$} {}", "", paint(header))];
+    for (i, row) in matrix.iter().enumerate() {
+        let cells: String = row
+            .iter()
+            .enumerate()
+            .map(|(j, &value)| format_cell(value, marked.contains(&(i, j)), color))
+            .collect();
+        lines.push(format!("{} {}", paint(format!("{:>label$}", i + 1)), cells));
+    }
+    (lines, label + 1 + 3 * n)
+}
+
+/// 행렬을 행/열 머리글과 함께 문자열로 만드는 함수
+/// marked에 포함된 칸은 색상 모드에서는 빨간 배경, 아니면 [1]처럼 괄호로 표시
+pub fn format_matrix(matrix: &Matrix, marked: &[(usize, usize)], color: bool) -> String {
+    let (lines, _) = matrix_lines(matrix, marked, color);
+    lines
+        .iter()
+        .map(|line| format!("{}\n", line.trim_end()))
+        .collect()
+}
+
+/// 변환 전/후 행렬을 나란히 놓고, 변환 후 행렬에서 새로 1이 된 칸을 표시하는 함수
+pub fn format_diff(before: &Matrix, after: &Matrix, titles: (&str, &str), color: bool) -> String {
+    let n = before.len();
+    let added: Vec<(usize, usize)> = (0..n)
+        .flat_map(|i| (0..n).map(move |j| (i, j)))
+        .filter(|&(i, j)| before[i][j] == 0 && after[i][j] == 1)
+        .collect();
+    let (left, matrix_width) = matrix_lines(before, &[], color);
+    let (right, _) = matrix_lines(after, &added, color);
+    let width = matrix_width.max(display_width(titles.0));
+
+    let pad = |text: &str, visible: usize| format!("{}{}", text, " ".repeat(width - visible));
+    let mut out = format!(
+        "{}    {}\n",
+        pad(titles.0, display_width(titles.0)),
+        titles.1
+    );
+    for (k, (l, r)) in left.iter().zip(&right).enumerate() {
+        let arrow = if k == n / 2 + 1 { " => " } else { "    " };
+        out.push_str(&format!(
+            "{}{}{}\n",
+            pad(l, matrix_width),
+            arrow,
+            r.trim_end()
+        ));
+    }
+    if !added.is_empty() {
+        out.push_str(&format!(
+            "{}\n",
+            tf(Msg::DiffLegend, &[&marker_sample(color)])
+        ));
+    }
+    out
+}
+
+/// 행렬을 제목과 함께 출력하고, 선택한 속성을 위반하는 칸을 강조하는 함수
+pub fn print_violation_highlight(matrix: &Matrix, key: &str, title: &str) {
+    let Some(cells) = violation_cells(matrix, key) else {
+        return;
+    };
+    let color = use_color();
+    println!("\n=== {} ===", title);
+    print!("{}", format_matrix(matrix, &cells, color));
+    let name = super::report::Properties::of(matrix)
+        .entries()
+        .iter()
+        .find(|(k, _, _)| *k == key)
+        .map(|(_, name, _)| t(*name))
+        .unwrap_or(key);
+    if cells.is_empty() {
+        println!("{}", tf(Msg::NoViolationCells, &[&name]));
+    } else {
+        println!(
+            "{}",
+            tf(Msg::ViolationLegend, &[&marker_sample(color), &name])
+        );
+    }
+}
+
+/// 폐포 전후 행렬을 나란히 출력하는 함수
+pub fn print_closure_diff(before: &Matrix, after: &Matrix, titles: (&str, &str)) {
+    println!();
+    print!("{}", format_diff(before, after, titles, use_color()));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// ANSI 색상 코드를 지운 문자열을 반환하는 헬퍼 함수
+    fn strip_ansi(text: &str) -> String {
+        let mut out = String::new();
+        let mut chars = text.chars();
+        while let Some(c) = chars.next() {
+            if c == '\x1b' {
+                for c in chars.by_ref() {
+                    if c == 'm' {
+                        break;
+                    }
+                }
+            } else {
+                out.push(c);
+            }
+        }
+        out
+    }
+
+    /// 1 → 2 → 3 경로 (반사, 대칭, 추이, 연결 모두 위반)
+    fn path() -> Matrix {
+        vec![vec![0, 1, 0], vec![0, 0, 1], vec![0, 0, 0]]
+    }
+
+    #[test]
+    fn violation_cells_per_property() {
+        let matrix = path();
+        assert_eq!(
+            violation_cells(&matrix, "reflexive"),
+            Some(vec![(0, 0), (1, 1), (2, 2)])
+        );
+        assert_eq!(
+            violation_cells(&matrix, "symmetric"),
+            Some(vec![(0, 1), (1, 0), (1, 2), (2, 1)])
+        );
+        assert_eq!(violation_cells(&matrix, "transitive"), Some(vec![(0, 2)]));
+        assert_eq!(
+            violation_cells(&matrix, "connected"),
+            Some(vec![(0, 2), (2, 0)])
+        );
+        assert_eq!(violation_cells(&matrix, "antisymmetric"), Some(Vec::new()));
+        assert_eq!(violation_cells(&matrix, "irreflexive"), Some(Vec::new()));
+        assert_eq!(violation_cells(&matrix, "bogus"), None);
+    }
+
+    #[test]
+    fn plain_matrix_marks_cells_with_brackets() {
+        assert_eq!(
+            format_matrix(&path(), &[(0, 2)], false),
+            "    1  2  3\n1  0  1 [0]\n2  0  0  1\n3  0  0  0\n"
+        );
+    }
+
+    #[test]
+    fn colored_matrix_has_same_layout() {
+        let plain = format_matrix(&path(), &[(0, 2)], false);
+        let colored = format_matrix(&path(), &[(0, 2)], true);
+        assert!(colored.contains(&format!("{} 0 {}", MARKED, RESET)));
+        assert!(!strip_ansi(&colored).contains('['));
+        // 색상 코드를 지우면 괄호 대신 공백이 들어간 일반 출력과 같은 배치
+        for (c, p) in colored.lines().zip(plain.lines()) {
+            assert_eq!(
+                strip_ansi(c).trim_end(),
+                p.replace(['[', ']'], " ").trim_end()
+            );
+        }
+    }
+
+    #[test]
+    fn diff_marks_only_added_cells() {
+        let before = path();
+        let after = crate::closure::transitive_closure(&before);
+        let diff = format_diff(&before, &after, ("전", "후"), false);
+        let lines: Vec<&str> = diff.lines().collect();
+        // 제목, 머리글, 3개 행, 범례
+        assert_eq!(lines.len(), 6);
+        assert!(lines[0].starts_with("전") && lines[0].ends_with("후"));
+        assert!(lines[3].contains(" => "));
+        // 오른쪽 행렬은 15번째 칸부터 시작하고, 추가된 (1, 3)만 표시
+        let right: Vec<&str> = lines[1..5].iter().map(|line| &line[15..]).collect();
+        let expected = format_matrix(&after, &[(0, 2)], false);
+        assert_eq!(right, expected.lines().collect::<Vec<_>>());
+        assert_eq!(lines[..5].concat().matches('[').count(), 1);
+        assert_eq!(lines[5], tf(Msg::DiffLegend, &[&marker_sample(false)]));
+
+        // 바뀐 칸이 없으면 범례도 없음
+        let same = format_diff(&after, &after, ("a", "b"), false);
+        assert_eq!(same.lines().count(), 5);
+        assert!(!same.contains('['));
+    }
+
+    #[test]
+    fn hangul_is_two_columns_wide() {
+        assert_eq!(display_width("abc"), 3);
+        assert_eq!(display_width("폐포"), 4);
+        assert_eq!(display_width("R 전"), 4);
+    }
+
+    #[test]
+    fn color_modes_are_parsed() {
+        assert_eq!(ColorMode::parse("Always"), Some(ColorMode::Always));
+        assert_eq!(ColorMode::parse(" never "), Some(ColorMode::Never));
+        assert_eq!(ColorMode::parse("auto"), Some(ColorMode::Auto));
+        assert_eq!(ColorMode::parse("sometimes"), None);
+    }
+}
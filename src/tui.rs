@@ -0,0 +1,483 @@
+/// 터미널 UI 모듈 - 커서로 행렬의 칸을 토글하며 속성, 분류, 동치류, 연결 요소를 실시간으로 확인
+/// 외부 크레이트 없이 stty로 raw 모드를 켜고 ANSI 이스케이프 코드로 화면을 그림
+use std::io::{self, BufRead, IsTerminal, Write};
+use std::process::{Command, Stdio};
+
+use crate::Matrix;
+use crate::i18n::{Msg, mark, t, tf};
+use crate::report::{ClosureKind, Properties, classify};
+
+/// 편집기에서 처리하는 입력
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Key {
+    Up,
+    Down,
+    Left,
+    Right,
+    Toggle,
+    Closure(ClosureKind),
+    Undo,
+    Redo,
+    Quit,
+}
+
+/// 관계 행렬 편집 상태 (커서 위치와 되돌리기/다시 실행 기록 포함)
+#[derive(Debug, Clone)]
+pub struct Editor {
+    matrix: Matrix,
+    cursor: (usize, usize),
+    undo: Vec<Matrix>,
+    redo: Vec<Matrix>,
+    message: String,
+}
+
+impl Editor {
+    /// 주어진 행렬로 편집기를 만드는 함수 (커서는 (1, 1))
+    /// 커서를 놓을 칸이 없는 빈 행렬이면 None
+    pub fn new(matrix: Matrix) -> Option<Editor> {
+        if matrix.is_empty() {
+            return None;
+        }
+        Some(Editor {
+            matrix,
+            cursor: (0, 0),
+            undo: Vec::new(),
+            redo: Vec::new(),
+            message: String::new(),
+        })
+    }
+
+    /// 현재 행렬
+    pub fn matrix(&self) -> &Matrix {
+        &self.matrix
+    }
+
+    /// 현재 커서 위치 (0부터 시작하는 (행, 열))
+    pub fn cursor(&self) -> (usize, usize) {
+        self.cursor
+    }
+
+    /// 행렬을 바꾸고 이전 상태를 되돌리기 기록에 저장하는 헬퍼 함수
+    /// 행렬이 그대로이면 기록하지 않음
+    fn edit(&mut self, next: Matrix) {
+        if next != self.matrix {
+            self.undo.push(std::mem::replace(&mut self.matrix, next));
+            self.redo.clear();
+        }
+    }
+
+    /// 입력 하나를 처리하는 함수 (Quit이면 false를 반환)
+    pub fn handle(&mut self, key: Key) -> bool {
+        let n = self.matrix.len();
+        let (row, col) = self.cursor;
+        self.message.clear();
+        match key {
+            Key::Up => self.cursor.0 = row.saturating_sub(1),
+            Key::Down => self.cursor.0 = (row + 1).min(n - 1),
+            Key::Left => self.cursor.1 = col.saturating_sub(1),
+            Key::Right => self.cursor.1 = (col + 1).min(n - 1),
+            Key::Toggle => {
+                let mut next = self.matrix.clone();
+                next[row][col] ^= 1;
+                self.edit(next);
+            }
+            Key::Closure(kind) => {
+                let trace = kind.trace(&self.matrix);
+                let added = trace.added.len();
+                self.edit(trace.matrix);
+                self.message =
+                    format!("{}: {}", kind.label(), tf(Msg::ReportAddedCount, &[&added]));
+            }
+            Key::Undo => match self.undo.pop() {
+                Some(previous) => self
+                    .redo
+                    .push(std::mem::replace(&mut self.matrix, previous)),
+                None => self.message = t(Msg::TuiNothingToUndo).to_string(),
+            },
+            Key::Redo => match self.redo.pop() {
+                Some(next) => self.undo.push(std::mem::replace(&mut self.matrix, next)),
+                None => self.message = t(Msg::TuiNothingToRedo).to_string(),
+            },
+            Key::Quit => return false,
+        }
+        true
+    }
+}
+
+/// 이스케이프 시퀀스로 들어오는 특수 키
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum SpecialKey {
+    Up,
+    Down,
+    Right,
+    Left,
+    Home,
+    End,
+    Delete,
+}
+
+/// ESC로 시작하는 바이트열에서 시퀀스 하나의 길이와 그 키를 구하는 함수
+/// CSI(ESC [ 매개변수 종료 바이트)와 SS3(ESC O 종료 바이트)는 끝까지 하나로 묶고,
+/// 단독 ESC나 모르는 시퀀스는 키 없이 길이만 반환 (이미 읽은 바이트만 보므로 기다리지 않음)
+pub(crate) fn parse_escape(bytes: &[u8]) -> (usize, Option<SpecialKey>) {
+    let final_key = |byte: u8| match byte {
+        b'A' => Some(SpecialKey::Up),
+        b'B' => Some(SpecialKey::Down),
+        b'C' => Some(SpecialKey::Right),
+        b'D' => Some(SpecialKey::Left),
+        b'H' => Some(SpecialKey::Home),
+        b'F' => Some(SpecialKey::End),
+        _ => None,
+    };
+    match bytes.get(1) {
+        Some(b'[') => {
+            let params = bytes[2..]
+                .iter()
+                .take_while(|&&byte| (0x20..=0x3f).contains(&byte))
+                .count();
+            let Some(&last) = bytes.get(2 + params) else {
+                return (bytes.len(), None);
+            };
+            if !(0x40..=0x7e).contains(&last) {
+                return (2 + params, None);
+            }
+            let key = match (&bytes[2..2 + params], last) {
+                (b"", _) => final_key(last),
+                (b"1" | b"7", b'~') => Some(SpecialKey::Home),
+                (b"4" | b"8", b'~') => Some(SpecialKey::End),
+                (b"3", b'~') => Some(SpecialKey::Delete),
+                _ => None,
+            };
+            (3 + params, key)
+        }
+        Some(b'O') => match bytes.get(2) {
+            Some(&last) => (3, final_key(last)),
+            None => (2, None),
+        },
+        Some(_) => (2, None),
+        None => (1, None),
+    }
+}
+
+/// 표준 입력에서 입력 하나를 읽는 함수
+/// 화살표 키와 hjkl로 이동, Space/Enter로 토글, r/s/t/e로 폐포 적용, u/y로 되돌리기/다시 실행,
+/// q 또는 Ctrl-C로 종료하며, 입력이 끝나면 Quit, 알 수 없는 키는 None
+/// 이스케이프 시퀀스는 한 번에 읽힌 바이트 안에서만 해석하므로 단독 ESC에서 멈추지 않음
+pub fn read_key(input: &mut impl BufRead) -> io::Result<Option<Key>> {
+    let bytes = input.fill_buf()?;
+    let Some(&first) = bytes.first() else {
+        return Ok(Some(Key::Quit));
+    };
+    if first == 0x1b {
+        let (length, special) = parse_escape(bytes);
+        input.consume(length);
+        return Ok(match special {
+            Some(SpecialKey::Up) => Some(Key::Up),
+            Some(SpecialKey::Down) => Some(Key::Down),
+            Some(SpecialKey::Right) => Some(Key::Right),
+            Some(SpecialKey::Left) => Some(Key::Left),
+            _ => None,
+        });
+    }
+    input.consume(1);
+    let key = match first {
+        b'k' => Key::Up,
+        b'j' => Key::Down,
+        b'l' => Key::Right,
+        b'h' => Key::Left,
+        b' ' | b'\r' | b'\n' => Key::Toggle,
+        b'r' => Key::Closure(ClosureKind::Reflexive),
+        b's' => Key::Closure(ClosureKind::Symmetric),
+        b't' => Key::Closure(ClosureKind::Transitive),
+        b'e' => Key::Closure(ClosureKind::Equivalence),
+        b'u' => Key::Undo,
+        b'y' | 0x12 => Key::Redo,
+        b'q' | 0x03 => Key::Quit,
+        _ => return Ok(None),
+    };
+    Ok(Some(key))
+}
+
+/// 편집기 화면 전체를 문자열로 만드는 함수 (raw 모드이므로 줄바꿈은 \r\n)
+/// 행렬, 여섯 가지 속성, 관계 분류, 동치류, 약연결 요소, 최근 메시지, 키 안내를 표시
+pub fn render_screen(editor: &Editor, color: bool) -> String {
+    let matrix = editor.matrix();
+    let properties = Properties::of(matrix);
+    let mut lines = vec![format!("=== {} ===", t(Msg::TuiTitle)), String::new()];
+
+    lines.extend(
+        super::terminal::format_matrix(matrix, &[editor.cursor()], color)
+            .lines()
+            .map(str::to_string),
+    );
+    lines.push(String::new());
+
+    for (_, name, ok) in properties.entries() {
+        lines.push(format!("{}: {}", t(name), mark(ok)));
+    }
+
+    let classifications: Vec<&str> = classify(&properties)
+        .iter()
+        .map(|class| class.label())
+        .collect();
+    lines.push(format!(
+        "{}: {}",
+        t(Msg::ReportClassification),
+        if classifications.is_empty() {
+            t(Msg::ReportNone).to_string()
+        } else {
+            classifications.join(", ")
+        }
+    ));
+
+    let format_sets = |sets: Vec<Vec<usize>>| {
+        sets.iter()
+            .map(|set| super::order::format_set(set))
+            .collect::<Vec<String>>()
+            .join(" ")
+    };
+    lines.push(format!(
+        "{}: {}",
+        t(Msg::EquivalenceClassesTitle),
+        if properties.is_equivalence() {
+            format_sets(super::visualize::equivalence_classes(matrix))
+        } else {
+            t(Msg::ReportNone).to_string()
+        }
+    ));
+    lines.push(format!(
+        "{}: {}",
+        t(Msg::ComponentsTitle),
+        format_sets(super::visualize::weakly_connected_components(matrix))
+    ));
+
+    lines.push(String::new());
+    lines.push(editor.message.clone());
+    lines.push(t(Msg::TuiHelp).to_string());
+
+    // 화면 지우기 후 맨 위부터 그림
+    format!("\x1b[H\x1b[2J{}\r\n", lines.join("\r\n"))
+}
+
+/// stty로 터미널을 raw 모드로 바꾸고, 값이 사라질 때 원래 설정으로 복원하는 가드
+/// alternate가 참이면 대체 화면으로 전환하고 커서를 숨김 (repl의 줄 편집 모드에서는 사용하지 않음)
+pub(crate) struct RawMode {
+    saved: String,
+    alternate: bool,
+}
+
+/// 표준 입력의 터미널 설정을 stty로 바꾸는 헬퍼 함수
+fn stty(args: &[&str]) -> io::Result<String> {
+    let output = Command::new("stty")
+        .args(args)
+        .stdin(Stdio::inherit())
+        .output()?;
+    if !output.status.success() {
+        return Err(io::Error::other(
+            String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        ));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+impl RawMode {
+    /// 전체 화면 편집용 raw 모드 - 대체 화면으로 전환하고 커서를 숨김
+    pub(crate) fn enable() -> io::Result<RawMode> {
+        let saved = stty(&["-g"])?;
+        stty(&["raw", "-echo"])?;
+        print!("\x1b[?1049h\x1b[?25l");
+        io::stdout().flush()?;
+        Ok(RawMode {
+            saved,
+            alternate: true,
+        })
+    }
+
+    /// 줄 편집용 모드 - 입력은 한 바이트씩 받되 출력 처리(\n → \r\n)는 유지하여
+    /// 명령의 출력이 그대로 보이므로 대화형 셸 세션 동안 한 번만 켜 두면 됨
+    pub(crate) fn line_editing() -> io::Result<RawMode> {
+        let saved = stty(&["-g"])?;
+        stty(&["-icanon", "-echo", "-isig", "min", "1", "time", "0"])?;
+        Ok(RawMode {
+            saved,
+            alternate: false,
+        })
+    }
+}
+
+impl Drop for RawMode {
+    fn drop(&mut self) {
+        if self.alternate {
+            print!("\x1b[?25h\x1b[?1049l");
+            let _ = io::stdout().flush();
+        }
+        let _ = stty(&[&self.saved]);
+    }
+}
+
+/// 터미널 UI를 실행하고, 종료할 때의 행렬을 반환하는 함수
+/// 표준 입력과 출력이 모두 터미널이어야 함
+pub fn run_tui(matrix: &Matrix) -> io::Result<Matrix> {
+    if !io::stdin().is_terminal() || !io::stdout().is_terminal() {
+        return Err(io::Error::other(t(Msg::TuiNeedsTerminal)));
+    }
+
+    let mut editor =
+        Editor::new(matrix.clone()).ok_or_else(|| io::Error::other(t(Msg::TuiEmptyMatrix)))?;
+    let color = super::terminal::use_color();
+    let _raw = RawMode::enable()?;
+    let mut stdin = io::stdin().lock();
+    let mut stdout = io::stdout();
+    loop {
+        write!(stdout, "{}", render_screen(&editor, color))?;
+        stdout.flush()?;
+        match read_key(&mut stdin)? {
+            Some(key) => {
+                if !editor.handle(key) {
+                    break;
+                }
+            }
+            None => continue,
+        }
+    }
+    Ok(editor.matrix().clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 입력 목록을 차례로 처리하는 헬퍼 함수
+    fn press(editor: &mut Editor, keys: &[Key]) {
+        for &key in keys {
+            assert!(editor.handle(key));
+        }
+    }
+
+    #[test]
+    fn undo_and_redo_toggles() {
+        let empty = vec![vec![0; 2]; 2];
+        let mut editor = Editor::new(empty.clone()).unwrap();
+        press(&mut editor, &[Key::Toggle, Key::Right, Key::Toggle]);
+        let both = vec![vec![1, 1], vec![0, 0]];
+        assert_eq!(editor.matrix(), &both);
+
+        press(&mut editor, &[Key::Undo]);
+        assert_eq!(editor.matrix(), &vec![vec![1, 0], vec![0, 0]]);
+        press(&mut editor, &[Key::Undo]);
+        assert_eq!(editor.matrix(), &empty);
+        press(&mut editor, &[Key::Redo, Key::Redo]);
+        assert_eq!(editor.matrix(), &both);
+        // 되돌리기/다시 실행은 커서를 움직이지 않음
+        assert_eq!(editor.cursor(), (0, 1));
+    }
+
+    #[test]
+    fn new_edit_clears_redo() {
+        let mut editor = Editor::new(vec![vec![0; 2]; 2]).unwrap();
+        press(
+            &mut editor,
+            &[Key::Toggle, Key::Undo, Key::Down, Key::Toggle],
+        );
+        press(&mut editor, &[Key::Redo]);
+        assert_eq!(editor.matrix(), &vec![vec![0, 0], vec![1, 0]]);
+        assert_eq!(editor.message, t(Msg::TuiNothingToRedo));
+    }
+
+    #[test]
+    fn empty_history_reports_message() {
+        let mut editor = Editor::new(vec![vec![1]]).unwrap();
+        press(&mut editor, &[Key::Undo]);
+        assert_eq!(editor.message, t(Msg::TuiNothingToUndo));
+        // 다음 입력에서 메시지가 지워짐
+        press(&mut editor, &[Key::Left]);
+        assert!(editor.message.is_empty());
+        assert_eq!(editor.matrix(), &vec![vec![1]]);
+    }
+
+    #[test]
+    fn closure_is_one_undo_step() {
+        let path = vec![vec![0, 1, 0], vec![0, 0, 1], vec![0, 0, 0]];
+        let mut editor = Editor::new(path.clone()).unwrap();
+        press(&mut editor, &[Key::Closure(ClosureKind::Transitive)]);
+        assert_eq!(editor.matrix()[0][2], 1);
+        assert!(editor.message.starts_with(ClosureKind::Transitive.label()));
+
+        // 이미 추이적이면 기록이 남지 않아 한 번의 되돌리기로 원래 행렬
+        press(
+            &mut editor,
+            &[Key::Closure(ClosureKind::Transitive), Key::Undo],
+        );
+        assert_eq!(editor.matrix(), &path);
+        press(&mut editor, &[Key::Undo]);
+        assert_eq!(editor.message, t(Msg::TuiNothingToUndo));
+    }
+
+    #[test]
+    fn cursor_stays_inside_matrix() {
+        let mut editor = Editor::new(vec![vec![0; 3]; 3]).unwrap();
+        press(&mut editor, &[Key::Up, Key::Left]);
+        assert_eq!(editor.cursor(), (0, 0));
+        press(&mut editor, &[Key::Down; 5]);
+        press(&mut editor, &[Key::Right; 5]);
+        assert_eq!(editor.cursor(), (2, 2));
+        assert!(!editor.handle(Key::Quit));
+        assert!(Editor::new(Vec::new()).is_none());
+    }
+
+    #[test]
+    fn keys_are_decoded() {
+        let mut input: &[u8] = b"\x1b[A\x1b[Dj tuyqx";
+        let mut keys = Vec::new();
+        while !input.is_empty() {
+            keys.push(read_key(&mut input).unwrap());
+        }
+        assert_eq!(
+            keys,
+            vec![
+                Some(Key::Up),
+                Some(Key::Left),
+                Some(Key::Down),
+                Some(Key::Toggle),
+                Some(Key::Closure(ClosureKind::Transitive)),
+                Some(Key::Undo),
+                Some(Key::Redo),
+                Some(Key::Quit),
+                None,
+            ]
+        );
+        // 입력이 끝나면 종료
+        assert_eq!(read_key(&mut input).unwrap(), Some(Key::Quit));
+    }
+
+    #[test]
+    fn escape_sequences_are_consumed_whole() {
+        // Delete, Ctrl-→, SS3 ↑ 다음의 j가 시퀀스의 일부로 읽히지 않아야 함
+        let mut input: &[u8] = b"\x1b[3~\x1b[1;5C\x1bOAj";
+        assert_eq!(read_key(&mut input).unwrap(), None);
+        assert_eq!(read_key(&mut input).unwrap(), None);
+        assert_eq!(read_key(&mut input).unwrap(), Some(Key::Up));
+        assert_eq!(read_key(&mut input).unwrap(), Some(Key::Down));
+        // 단독 ESC는 더 기다리지 않고 알 수 없는 키로 처리
+        let mut input: &[u8] = b"\x1b";
+        assert_eq!(read_key(&mut input).unwrap(), None);
+        assert!(input.is_empty());
+
+        assert_eq!(parse_escape(b"\x1b[3~x"), (4, Some(SpecialKey::Delete)));
+        assert_eq!(parse_escape(b"\x1b[H"), (3, Some(SpecialKey::Home)));
+        assert_eq!(parse_escape(b"\x1b[4~"), (4, Some(SpecialKey::End)));
+        assert_eq!(parse_escape(b"\x1b[1;5"), (5, None));
+        assert_eq!(parse_escape(b"\x1bx"), (2, None));
+    }
+
+    #[test]
+    fn screen_shows_cursor_and_message() {
+        let mut editor = Editor::new(vec![vec![1, 0], vec![0, 1]]).unwrap();
+        press(&mut editor, &[Key::Redo]);
+        let screen = render_screen(&editor, false);
+        assert!(screen.starts_with("\x1b[H\x1b[2J"));
+        assert!(screen.contains("1 [1] 0\r\n"));
+        assert!(screen.contains(t(Msg::TuiNothingToRedo)));
+        assert!(screen.contains(&format!("{}: {{1}} {{2}}", t(Msg::ComponentsTitle))));
+    }
+}
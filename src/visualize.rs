@@ -1,10 +1,12 @@
 /// 관계 행렬을 텍스트 기반으로 시각화하는 모듈 (그래프, 연결 요소 표시)
 use crate::Matrix;
+use crate::i18n::{Msg, mark, t, tf};
 use std::collections::VecDeque;
 
 /// 인접 리스트 형태로 그래프를 출력
+#[allow(clippy::needless_range_loop)]
 pub fn print_adjacency_lists(matrix: &Matrix) {
-    println!("\n=== 그래프 (인접 리스트) ===");
+    println!("\n=== {} ===", t(Msg::AdjacencyTitle));
     let n = matrix.len();
     for i in 0..n {
         let mut neighbors = Vec::new();
@@ -18,12 +20,12 @@ pub fn print_adjacency_lists(matrix: &Matrix) {
     }
 }
 
-/// 약연결성(weak connectivity)을 기준으로 연결 요소들을 출력
+/// 약연결성(weak connectivity)을 기준으로 연결 요소들을 반환하는 함수
 /// 방향을 무시한 그래프에서의 연결 요소
-pub fn print_weakly_connected_components(matrix: &Matrix) {
-    println!("\n=== 연결 요소 (약연결성) ===");
+pub fn weakly_connected_components(matrix: &Matrix) -> Vec<Vec<usize>> {
     let n = matrix.len();
     let mut visited = vec![false; n];
+    let mut components = Vec::new();
 
     for start in 0..n {
         if visited[start] {
@@ -45,7 +47,15 @@ pub fn print_weakly_connected_components(matrix: &Matrix) {
                 }
             }
         }
+        components.push(component);
+    }
+    components
+}
 
+/// 약연결성(weak connectivity)을 기준으로 연결 요소들을 출력
+pub fn print_weakly_connected_components(matrix: &Matrix) {
+    println!("\n=== {} ===", t(Msg::ComponentsTitle));
+    for component in weakly_connected_components(matrix) {
         let text = component
             .iter()
             .map(|&x| (x + 1).to_string())
@@ -55,19 +65,19 @@ pub fn print_weakly_connected_components(matrix: &Matrix) {
     }
 }
 
-/// 행렬을 지정된 제목으로 표준 출력에 행렬 형태로 표시하는 함수
+/// 행렬을 지정된 제목으로 표준 출력에 행/열 번호와 함께 표시하는 함수
+/// 터미널에서는 색상으로 0과 1을 구분 (terminal::use_color)
 pub fn print_matrix(matrix: &Matrix, title: &str) {
     println!("\n=== {} ===", title);
-    for row in matrix {
-        for &val in row {
-            print!("{} ", val);
-        }
-        println!();
-    }
+    print!(
+        "{}",
+        super::terminal::format_matrix(matrix, &[], super::terminal::use_color())
+    );
 }
 
 /// 주어진 원소의 동치류를 계산하여 반환하는 함수
 /// 동치 관계에서 특정 원소와 같은 동치류에 속하는 모든 원소들의 벡터를 반환
+#[allow(clippy::needless_range_loop)]
 pub fn get_equivalence_class(matrix: &Matrix, element: usize) -> Vec<usize> {
     let mut class = Vec::new();
     for i in 0..matrix.len() {
@@ -78,10 +88,28 @@ pub fn get_equivalence_class(matrix: &Matrix, element: usize) -> Vec<usize> {
     class
 }
 
+/// 동치 관계의 모든 동치류를 원소 번호가 작은 대표원 순서로 반환하는 함수
+pub fn equivalence_classes(matrix: &Matrix) -> Vec<Vec<usize>> {
+    let n = matrix.len();
+    let mut processed = vec![false; n];
+    let mut classes = Vec::new();
+
+    for i in 0..n {
+        if !processed[i] {
+            let class = get_equivalence_class(matrix, i);
+            for &elem in &class {
+                processed[elem] = true;
+            }
+            classes.push(class);
+        }
+    }
+    classes
+}
+
 /// 동치 관계의 모든 동치류를 찾아서 출력하는 함수
 /// 각 동치류를 [원소] = {원소들} 형식으로 표시하며 중복 출력을 방지
 pub fn print_equivalence_classes(matrix: &Matrix) {
-    println!("\n=== 동치류 ===");
+    println!("\n=== {} ===", t(Msg::EquivalenceClassesTitle));
 
     let n = matrix.len();
     let mut processed = vec![false; n];
@@ -107,18 +135,18 @@ pub fn print_equivalence_classes(matrix: &Matrix) {
 
 /// 동치류의 상세한 분석과 예시를 보여주는 함수
 pub fn demonstrate_equivalence_classes(matrix: &Matrix) {
-    println!("\n=== 동치류 상세 분석 ===");
+    println!("\n=== {} ===", t(Msg::ClassDetailTitle));
 
     if !super::equivalence::is_equivalence_relation(matrix) {
-        println!("동치 관계가 아니므로 동치류 분석을 수행할 수 없습니다.");
+        println!("{}", t(Msg::ClassDetailNotEquivalence));
         return;
     }
 
     let n = matrix.len();
-    println!("집합: {{1, 2, ..., {}}}", n);
+    println!("{}", tf(Msg::SetLabel, &[&format!("{{1, 2, ..., {}}}", n)]));
 
     // 각 원소의 동치류 표시
-    println!("\n각 원소의 동치류:");
+    println!("\n{}", t(Msg::ClassOfEachElement));
     for i in 0..n {
         let class = get_equivalence_class(matrix, i);
         let class_str = class
@@ -126,24 +154,41 @@ pub fn demonstrate_equivalence_classes(matrix: &Matrix) {
             .map(|&x| (x + 1).to_string())
             .collect::<Vec<String>>()
             .join(", ");
-        println!("동치류 [{}]: {{{}}}", i + 1, class_str);
+        println!(
+            "{}",
+            tf(
+                Msg::ClassOfElement,
+                &[&(i + 1), &format!("{{{}}}", class_str)]
+            )
+        );
     }
 
     // 기존의 동치류 출력 함수 사용
-    println!("\n동치 관계의 파티션 구조:");
+    println!("\n{}", t(Msg::PartitionStructure));
     print_equivalence_classes(matrix);
 }
 
+/// 속성의 만족 여부를 "반사성 만족 ✓" 형식의 문장으로 만드는 헬퍼 함수
+fn property_result(ok: bool, property: Msg) -> String {
+    tf(
+        if ok {
+            Msg::PropertyHolds
+        } else {
+            Msg::PropertyFails
+        },
+        &[&t(property)],
+    )
+}
+
 /// 각 속성을 개별적으로 상세히 분석하여 출력하는 함수
+#[allow(clippy::needless_range_loop)]
 pub fn analyze_individual_properties(matrix: &Matrix) {
-    println!("\n=== 개별 속성 상세 분석 ===");
+    println!("\n=== {} ===", t(Msg::IndividualTitle));
 
-    let reflexive = super::equivalence::is_reflexive(matrix);
-    let symmetric = super::equivalence::is_symmetric(matrix);
-    let transitive = super::equivalence::is_transitive(matrix);
-    let equivalence = super::equivalence::is_equivalence_relation(matrix);
+    let properties = super::report::Properties::of(matrix);
+    let violations = super::report::find_violations(matrix);
 
-    println!("반사성 검증:");
+    println!("{}", t(Msg::ReflexivityCheck));
     for i in 0..matrix.len() {
         let has_self = matrix[i][i] == 1;
         println!(
@@ -155,27 +200,18 @@ pub fn analyze_individual_properties(matrix: &Matrix) {
         );
     }
     println!(
-        "결과: {}",
-        if reflexive {
-            "반사성 만족 ✓"
-        } else {
-            "반사성 불만족 ✗"
-        }
+        "{}",
+        tf(
+            Msg::ResultLine,
+            &[&property_result(properties.reflexive, Msg::Reflexivity)]
+        )
     );
 
-    println!("\n대칭성 검증:");
-    let mut symmetry_violations = Vec::new();
-    for i in 0..matrix.len() {
-        for j in 0..matrix.len() {
-            if matrix[i][j] != matrix[j][i] {
-                symmetry_violations.push((i, j));
-            }
-        }
-    }
-    if symmetry_violations.is_empty() {
-        println!("  모든 쌍이 대칭적입니다 ✓");
+    println!("\n{}", t(Msg::SymmetryCheck));
+    if violations.symmetric.is_empty() {
+        println!("{}", t(Msg::AllPairsSymmetric));
     } else {
-        for (i, j) in symmetry_violations {
+        for &(i, j) in &violations.symmetric {
             println!(
                 "  R({}, {}) = {} vs R({}, {}) = {}",
                 i + 1,
@@ -188,138 +224,88 @@ pub fn analyze_individual_properties(matrix: &Matrix) {
         }
     }
     println!(
-        "결과: {}",
-        if symmetric {
-            "대칭성 만족 ✓"
-        } else {
-            "대칭성 불만족 ✗"
-        }
+        "{}",
+        tf(
+            Msg::ResultLine,
+            &[&property_result(properties.symmetric, Msg::Symmetry)]
+        )
     );
 
-    println!("\n추이성 검증:");
-    let mut transitivity_violations = Vec::new();
-    for i in 0..matrix.len() {
-        for j in 0..matrix.len() {
-            for k in 0..matrix.len() {
-                if matrix[i][j] == 1 && matrix[j][k] == 1 && matrix[i][k] != 1 {
-                    transitivity_violations.push((i, j, k));
-                }
-            }
-        }
-    }
-    if transitivity_violations.is_empty() {
-        println!("  추이성 위반사항이 없습니다 ✓");
+    println!("\n{}", t(Msg::TransitivityCheck));
+    if violations.transitive.is_empty() {
+        println!("{}", t(Msg::NoTransitivityViolation));
     } else {
-        for (i, j, k) in transitivity_violations {
+        for &(i, j, k) in &violations.transitive {
             println!(
-                "  R({}, {}) ∧ R({}, {}) = 1, 1이지만 R({}, {}) = {}",
-                i + 1,
-                j + 1,
-                j + 1,
-                k + 1,
-                i + 1,
-                k + 1,
-                matrix[i][k]
+                "{}",
+                tf(
+                    Msg::TransitivityViolation,
+                    &[
+                        &(i + 1),
+                        &(j + 1),
+                        &(j + 1),
+                        &(k + 1),
+                        &(i + 1),
+                        &(k + 1),
+                        &matrix[i][k]
+                    ]
+                )
             );
         }
+        let omitted = violations.transitive_count - violations.transitive.len();
+        if omitted > 0 {
+            println!("  {}", tf(Msg::ReportMoreViolations, &[&omitted]));
+        }
     }
     println!(
-        "결과: {}",
-        if transitive {
-            "추이성 만족 ✓"
-        } else {
-            "추이성 불만족 ✗"
-        }
+        "{}",
+        tf(
+            Msg::ResultLine,
+            &[&property_result(properties.transitive, Msg::Transitivity)]
+        )
     );
 
     println!(
-        "\n통합 결과: {}",
-        if equivalence {
-            "이 관계는 동치 관계입니다! ✓"
-        } else {
-            "이 관계는 동치 관계가 아닙니다 ✗"
-        }
+        "\n{}",
+        tf(
+            Msg::CombinedResult,
+            &[&t(if properties.is_equivalence() {
+                Msg::IsEquivalenceMark
+            } else {
+                Msg::IsNotEquivalenceMark
+            })]
+        )
     );
 }
 
 /// 관계의 연결성과 추가 속성들을 종합적으로 분석하는 함수
 pub fn analyze_relationship_properties(matrix: &Matrix) {
-    println!("\n=== 관계 속성 종합 분석 ===");
+    println!("\n=== {} ===", t(Msg::RelationshipTitle));
 
-    let reflexive = super::equivalence::is_reflexive(matrix);
-    let symmetric = super::equivalence::is_symmetric(matrix);
-    let transitive = super::equivalence::is_transitive(matrix);
-    let antisymmetric = super::equivalence::is_antisymmetric(matrix);
-    let irreflexive = super::equivalence::is_irreflexive(matrix);
-    let connected = super::equivalence::is_connected_relation(matrix);
+    let properties = super::report::Properties::of(matrix);
+    let [reflexive, symmetric, transitive, additional @ ..] = properties.entries();
 
-    println!("기본 동치 관계 속성:");
-    println!(
-        "  반사성: {}",
-        if reflexive {
-            "✓ 만족"
-        } else {
-            "✗ 불만족"
-        }
-    );
-    println!(
-        "  대칭성: {}",
-        if symmetric {
-            "✓ 만족"
-        } else {
-            "✗ 불만족"
-        }
-    );
-    println!(
-        "  추이성: {}",
-        if transitive {
-            "✓ 만족"
-        } else {
-            "✗ 불만족"
-        }
-    );
-
-    println!("\n추가 관계 속성:");
-    println!(
-        "  반대칭성: {}",
-        if antisymmetric {
-            "✓ 만족"
-        } else {
-            "✗ 불만족"
-        }
-    );
-    println!(
-        "  비반사성: {}",
-        if irreflexive {
-            "✓ 만족"
-        } else {
-            "✗ 불만족"
-        }
-    );
-    println!(
-        "  연결성: {}",
-        if connected {
-            "✓ 만족"
-        } else {
-            "✗ 불만족"
-        }
-    );
-
-    println!("\n관계 분류:");
-    if reflexive && symmetric && transitive {
-        println!("  ✓ 동치 관계 (Equivalence Relation)");
+    println!("{}", t(Msg::BasicProperties));
+    for (_, name, ok) in [reflexive, symmetric, transitive] {
+        println!("  {}: {}", t(name), mark(ok));
     }
-    if antisymmetric && transitive {
-        println!("  ✓ 부분 순서 관계 (Partial Order Relation)");
-    }
-    if irreflexive && symmetric && transitive {
-        println!("  ✓ 엄밀 부분 순서 관계 (Strict Partial Order)");
+
+    println!("\n{}", t(Msg::AdditionalProperties));
+    for (_, name, ok) in additional {
+        println!("  {}: {}", t(name), mark(ok));
     }
-    if irreflexive && antisymmetric && transitive {
-        println!("  ✓ 선형 순서 관계 (Linear Order)");
+
+    println!("\n{}", t(Msg::Classification));
+    for class in super::report::classify(&properties) {
+        println!("  {}", tf(Msg::ClassMatched, &[&class.label()]));
     }
-    if reflexive && antisymmetric && transitive && connected {
-        println!("  ✓ 전순서 관계 (Total Order)");
+
+    // 부분 순서일 경우 순서 구조 상세 분석
+    if properties.is_partial_order() {
+        super::order::print_poset_analysis(matrix);
+        super::order::print_chain_decomposition(matrix);
+        super::order::print_order_dimension_analysis(matrix);
+        super::equivalence::print_lattice_analysis(matrix);
     }
 }
 
@@ -0,0 +1,358 @@
+/// Warshall 알고리즘의 단계별 실행 과정을 추적하고 출력/내보내기하는 모듈
+/// 피벗 k를 처리할 때마다 중간 행렬 W_k와 새로 1이 된 칸을 기록
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use crate::Matrix;
+use crate::i18n::{Msg, t, tf};
+
+/// Warshall 알고리즘의 한 단계
+/// pivot: 이번 단계에서 경유 원소로 허용한 원소 k (0부터 시작)
+/// matrix: 피벗 0..=k를 경유 원소로 허용한 중간 행렬 W_(k+1)
+/// changed: 이번 단계에서 0에서 1로 바뀐 칸 (행 우선 순서)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WarshallStep {
+    pub pivot: usize,
+    pub matrix: Matrix,
+    pub changed: Vec<(usize, usize)>,
+}
+
+/// Warshall 알고리즘의 단계를 차례로 생성하는 반복자
+/// n개의 원소에 대해 정확히 n개의 단계를 생성하며, 마지막 단계의 행렬은 추이 폐포와 같음
+#[derive(Debug, Clone)]
+pub struct WarshallSteps {
+    current: Matrix,
+    pivot: usize,
+}
+
+impl Iterator for WarshallSteps {
+    type Item = WarshallStep;
+
+    fn next(&mut self) -> Option<WarshallStep> {
+        let n = self.current.len();
+        let k = self.pivot;
+        if k >= n {
+            return None;
+        }
+        // W_k(i,j) = W_(k-1)(i,j) ∨ (W_(k-1)(i,k) ∧ W_(k-1)(k,j))
+        let previous = self.current.clone();
+        let mut changed = Vec::new();
+        for i in 0..n {
+            for j in 0..n {
+                if previous[i][j] == 0 && previous[i][k] == 1 && previous[k][j] == 1 {
+                    self.current[i][j] = 1;
+                    changed.push((i, j));
+                }
+            }
+        }
+        self.pivot += 1;
+        Some(WarshallStep {
+            pivot: k,
+            matrix: self.current.clone(),
+            changed,
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.current.len().saturating_sub(self.pivot);
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for WarshallSteps {}
+
+/// 관계 행렬에 대한 Warshall 알고리즘의 단계 반복자를 만드는 함수
+pub fn warshall_steps(matrix: &Matrix) -> WarshallSteps {
+    WarshallSteps {
+        current: matrix.clone(),
+        pivot: 0,
+    }
+}
+
+/// 내보낼 프레임의 형식
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FrameFormat {
+    Text,
+    Svg,
+}
+
+impl FrameFormat {
+    /// 프레임 파일의 확장자
+    pub fn extension(self) -> &'static str {
+        match self {
+            FrameFormat::Text => "txt",
+            FrameFormat::Svg => "svg",
+        }
+    }
+}
+
+/// 프레임의 제목 (W_0은 원래 관계, W_k는 피벗 k까지 허용한 행렬)
+fn frame_title(pivot: Option<usize>) -> String {
+    match pivot {
+        None => t(Msg::FrameOriginal).to_string(),
+        Some(k) => tf(Msg::FramePivot, &[&(k + 1), &(k + 1)]),
+    }
+}
+
+/// 행렬 한 프레임을 텍스트로 변환하는 헬퍼 함수
+/// 피벗의 행/열 머리글은 *로, 이번 단계에서 바뀐 칸은 [1]로 표시
+fn format_frame_text(matrix: &Matrix, pivot: Option<usize>, changed: &[(usize, usize)]) -> String {
+    let n = matrix.len();
+    let mark = |x: usize| if pivot == Some(x) { '*' } else { ' ' };
+    let mut text = format!("{}\n", frame_title(pivot));
+
+    text.push_str("     ");
+    for j in 0..n {
+        text.push_str(&format!("{:>3}{}", j + 1, mark(j)));
+    }
+    text.push('\n');
+    for (i, row) in matrix.iter().enumerate() {
+        text.push_str(&format!("{:>3}{} ", i + 1, mark(i)));
+        for (j, &cell) in row.iter().enumerate() {
+            if changed.contains(&(i, j)) {
+                text.push_str(&format!(" [{}]", cell));
+            } else {
+                text.push_str(&format!("  {} ", cell));
+            }
+        }
+        text.push('\n');
+    }
+    if pivot.is_some() {
+        if changed.is_empty() {
+            text.push_str(&tf(Msg::FrameChanged, &[&t(Msg::ReportNone)]));
+            text.push('\n');
+        } else {
+            let cells: Vec<String> = changed
+                .iter()
+                .map(|&(i, j)| format!("({}, {})", i + 1, j + 1))
+                .collect();
+            text.push_str(&tf(Msg::FrameChanged, &[&cells.join(", ")]));
+            text.push('\n');
+        }
+    }
+    text
+}
+
+/// 행렬 한 프레임을 SVG 문서로 변환하는 헬퍼 함수
+/// 1인 칸은 파란색, 이번 단계에서 바뀐 칸은 주황색, 피벗의 행/열은 굵은 테두리로 표시
+fn format_frame_svg(matrix: &Matrix, pivot: Option<usize>, changed: &[(usize, usize)]) -> String {
+    const CELL: usize = 40;
+    const MARGIN: usize = 40;
+    const TITLE: usize = 30;
+    let n = matrix.len();
+    let width = MARGIN + n * CELL + 10;
+    let height = TITLE + MARGIN + n * CELL + 10;
+
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" viewBox=\"0 0 {} {}\" font-family=\"sans-serif\">\n",
+        width, height, width, height
+    );
+    svg.push_str(&format!(
+        "  <rect width=\"{}\" height=\"{}\" fill=\"white\"/>\n",
+        width, height
+    ));
+    svg.push_str(&format!(
+        "  <text x=\"10\" y=\"20\" font-size=\"16\">{}</text>\n",
+        frame_title(pivot)
+    ));
+
+    let top = TITLE + MARGIN;
+    for x in 0..n {
+        let center = MARGIN + x * CELL + CELL / 2;
+        let weight = if pivot == Some(x) { "bold" } else { "normal" };
+        svg.push_str(&format!(
+            "  <text x=\"{}\" y=\"{}\" font-size=\"14\" font-weight=\"{}\" text-anchor=\"middle\">{}</text>\n",
+            center,
+            top - 12,
+            weight,
+            x + 1
+        ));
+        svg.push_str(&format!(
+            "  <text x=\"{}\" y=\"{}\" font-size=\"14\" font-weight=\"{}\" text-anchor=\"middle\">{}</text>\n",
+            MARGIN / 2,
+            top + x * CELL + CELL / 2 + 5,
+            weight,
+            x + 1
+        ));
+    }
+
+    for (i, row) in matrix.iter().enumerate() {
+        for (j, &cell) in row.iter().enumerate() {
+            let fill = if changed.contains(&(i, j)) {
+                "#f5a623"
+            } else if cell == 1 {
+                "#4a90d9"
+            } else {
+                "#ffffff"
+            };
+            let x = MARGIN + j * CELL;
+            let y = top + i * CELL;
+            svg.push_str(&format!(
+                "  <rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"{}\" stroke=\"#999999\"/>\n",
+                x, y, CELL, CELL, fill
+            ));
+            svg.push_str(&format!(
+                "  <text x=\"{}\" y=\"{}\" font-size=\"16\" text-anchor=\"middle\">{}</text>\n",
+                x + CELL / 2,
+                y + CELL / 2 + 6,
+                cell
+            ));
+        }
+    }
+
+    if let Some(k) = pivot {
+        // 피벗의 행과 열 강조
+        svg.push_str(&format!(
+            "  <rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"none\" stroke=\"#d0021b\" stroke-width=\"3\"/>\n",
+            MARGIN,
+            top + k * CELL,
+            n * CELL,
+            CELL
+        ));
+        svg.push_str(&format!(
+            "  <rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"none\" stroke=\"#d0021b\" stroke-width=\"3\"/>\n",
+            MARGIN + k * CELL,
+            top,
+            CELL,
+            n * CELL
+        ));
+    }
+    svg.push_str("</svg>\n");
+    svg
+}
+
+/// 단계 하나를 텍스트 프레임으로 변환하는 함수
+pub fn format_step_text(step: &WarshallStep) -> String {
+    format_frame_text(&step.matrix, Some(step.pivot), &step.changed)
+}
+
+/// 단계 하나를 SVG 프레임으로 변환하는 함수
+pub fn format_step_svg(step: &WarshallStep) -> String {
+    format_frame_svg(&step.matrix, Some(step.pivot), &step.changed)
+}
+
+/// 원래 관계(W_0)부터 마지막 단계(W_n)까지의 모든 프레임을 주어진 형식으로 생성하는 함수
+pub fn warshall_frames(matrix: &Matrix, format: FrameFormat) -> Vec<String> {
+    let render = match format {
+        FrameFormat::Text => format_frame_text,
+        FrameFormat::Svg => format_frame_svg,
+    };
+    let mut frames = vec![render(matrix, None, &[])];
+    frames.extend(
+        warshall_steps(matrix).map(|step| render(&step.matrix, Some(step.pivot), &step.changed)),
+    );
+    frames
+}
+
+/// 모든 프레임을 디렉터리에 frame_00.txt, frame_01.txt, ... (또는 .svg) 파일로 저장하는 함수
+/// 디렉터리가 없으면 생성하며, 저장한 파일 경로들을 순서대로 반환
+pub fn export_frames(matrix: &Matrix, dir: &Path, format: FrameFormat) -> io::Result<Vec<PathBuf>> {
+    std::fs::create_dir_all(dir)?;
+    let mut paths = Vec::new();
+    for (index, frame) in warshall_frames(matrix, format).iter().enumerate() {
+        let path = dir.join(format!("frame_{:02}.{}", index, format.extension()));
+        std::fs::write(&path, frame)?;
+        paths.push(path);
+    }
+    Ok(paths)
+}
+
+/// Warshall 알고리즘의 과정을 단계별로 출력하는 함수
+/// pause가 참이면 각 단계를 출력한 뒤 Enter 입력을 기다림
+pub fn print_warshall_steps(matrix: &Matrix, pause: bool) {
+    println!("\n=== {} ===", t(Msg::WarshallTitle));
+    println!("W_k(i,j) = W_(k-1)(i,j) ∨ (W_(k-1)(i,k) ∧ W_(k-1)(k,j))");
+    println!("{}\n", t(Msg::WarshallLegend));
+    print!("{}", format_frame_text(matrix, None, &[]));
+
+    let mut total = 0;
+    for step in warshall_steps(matrix) {
+        if pause {
+            print!("\n{}", t(Msg::WarshallPause));
+            io::stdout().flush().unwrap();
+            let mut input = String::new();
+            // 입력이 끝난 경우에도 나머지 단계는 계속 출력
+            let _ = io::stdin().read_line(&mut input);
+        }
+        println!();
+        print!("{}", format_step_text(&step));
+        total += step.changed.len();
+    }
+    println!("\n{}", tf(Msg::WarshallTotal, &[&total]));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 1 → 2 → 3 → 4 경로
+    fn path() -> Matrix {
+        let mut matrix = vec![vec![0; 4]; 4];
+        for (i, row) in matrix.iter_mut().enumerate().take(3) {
+            row[i + 1] = 1;
+        }
+        matrix
+    }
+
+    #[test]
+    fn steps_end_in_transitive_closure() {
+        let matrix = path();
+        let steps: Vec<WarshallStep> = warshall_steps(&matrix).collect();
+        assert_eq!(warshall_steps(&matrix).len(), 4);
+        assert_eq!(steps.len(), 4);
+        assert_eq!(
+            steps.last().unwrap().matrix,
+            crate::closure::transitive_closure(&matrix)
+        );
+
+        // 바뀐 칸은 정확히 이전 행렬과 달라진 칸
+        let mut previous = matrix.clone();
+        for (k, step) in steps.iter().enumerate() {
+            assert_eq!(step.pivot, k);
+            let diff: Vec<(usize, usize)> = (0..4)
+                .flat_map(|i| (0..4).map(move |j| (i, j)))
+                .filter(|&(i, j)| previous[i][j] != step.matrix[i][j])
+                .collect();
+            assert_eq!(step.changed, diff);
+            previous = step.matrix.clone();
+        }
+        assert!(steps[0].changed.is_empty());
+        assert_eq!(steps[1].changed, vec![(0, 2)]);
+        assert_eq!(steps[2].changed, vec![(0, 3), (1, 3)]);
+    }
+
+    #[test]
+    fn text_frame_marks_pivot_and_changes() {
+        let step = warshall_steps(&path()).nth(1).unwrap();
+        let text = format_step_text(&step);
+        assert!(text.contains("  2*"), "{text}");
+        assert!(text.contains(" [1]"), "{text}");
+        assert_eq!(text.matches("[1]").count(), 1);
+        assert!(text.contains("(1, 3)"), "{text}");
+    }
+
+    #[test]
+    fn svg_frame_has_one_rect_per_cell() {
+        let step = warshall_steps(&path()).nth(2).unwrap();
+        let svg = format_step_svg(&step);
+        assert!(svg.starts_with("<svg") && svg.ends_with("</svg>\n"));
+        // 배경 1개 + 칸 16개 + 피벗 행/열 강조 2개
+        assert_eq!(svg.matches("<rect").count(), 1 + 16 + 2);
+        assert_eq!(svg.matches("#f5a623").count(), 2);
+    }
+
+    #[test]
+    fn frames_are_exported_in_order() {
+        let dir = std::env::temp_dir().join(format!("warshall_frames_{}", std::process::id()));
+        let paths = export_frames(&path(), &dir, FrameFormat::Svg).unwrap();
+        assert_eq!(paths.len(), 5);
+        assert_eq!(paths[0].file_name().unwrap(), "frame_00.svg");
+        assert_eq!(paths[4].file_name().unwrap(), "frame_04.svg");
+        let frames = warshall_frames(&path(), FrameFormat::Svg);
+        for (path, frame) in paths.iter().zip(&frames) {
+            assert_eq!(&std::fs::read_to_string(path).unwrap(), frame);
+        }
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(FrameFormat::Text.extension(), "txt");
+    }
+}
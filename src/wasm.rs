@@ -0,0 +1,114 @@
+/// 브라우저용 API 모듈 - 정적 웹 페이지에서 동치 판별, 폐포, 분류, 동치류를 호출
+/// 행렬은 행 우선(row-major) 0/1 배열과 크기 n, 또는 [[1, 0], [0, 1]] 형식의 JSON 문자열로 주고받음
+/// wasm 기능을 켜면 wasm-bindgen으로 JavaScript에 camelCase 이름으로 노출되며, 오류는 문자열로 throw됨
+/// 결과 JSON의 원소 번호는 JSON 보고서와 같이 1부터 시작
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use crate::Matrix;
+use crate::i18n::{Msg, t, tf};
+use crate::report::{ClosureKind, Properties};
+
+/// 행 우선 배열을 관계 행렬로 바꾸는 헬퍼 함수
+fn from_flat(cells: &[u8], n: usize) -> Result<Matrix, String> {
+    if n == 0 || n.checked_mul(n) != Some(cells.len()) {
+        return Err(tf(Msg::WasmBadLength, &[&cells.len(), &n, &n]));
+    }
+    let matrix: Matrix = cells.chunks(n).map(<[u8]>::to_vec).collect();
+    super::parse::validate_matrix(&matrix)?;
+    Ok(matrix)
+}
+
+/// 폐포 이름을 해석하는 헬퍼 함수
+fn closure_kind(kind: &str) -> Result<ClosureKind, String> {
+    ClosureKind::parse(kind).ok_or_else(|| tf(Msg::UnknownClosure, &[&kind]))
+}
+
+/// 반사성 판별
+#[cfg_attr(feature = "wasm", wasm_bindgen(js_name = isReflexive))]
+pub fn is_reflexive(cells: &[u8], n: usize) -> Result<bool, String> {
+    Ok(super::equivalence::is_reflexive(&from_flat(cells, n)?))
+}
+
+/// 대칭성 판별
+#[cfg_attr(feature = "wasm", wasm_bindgen(js_name = isSymmetric))]
+pub fn is_symmetric(cells: &[u8], n: usize) -> Result<bool, String> {
+    Ok(super::equivalence::is_symmetric(&from_flat(cells, n)?))
+}
+
+/// 추이성 판별
+#[cfg_attr(feature = "wasm", wasm_bindgen(js_name = isTransitive))]
+pub fn is_transitive(cells: &[u8], n: usize) -> Result<bool, String> {
+    Ok(super::equivalence::is_transitive(&from_flat(cells, n)?))
+}
+
+/// 동치 관계 판별
+#[cfg_attr(feature = "wasm", wasm_bindgen(js_name = isEquivalenceRelation))]
+pub fn is_equivalence_relation(cells: &[u8], n: usize) -> Result<bool, String> {
+    Ok(super::equivalence::is_equivalence_relation(&from_flat(
+        cells, n,
+    )?))
+}
+
+/// 폐포를 계산하여 행 우선 배열로 반환 (kind: refl, sym, trans, equiv)
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn closure(cells: &[u8], n: usize, kind: &str) -> Result<Vec<u8>, String> {
+    let closed = closure_kind(kind)?.trace(&from_flat(cells, n)?).matrix;
+    Ok(closed.into_iter().flatten().collect())
+}
+
+/// 폐포를 계산하여 JSON 행렬로 반환 (kind: refl, sym, trans, equiv)
+#[cfg_attr(feature = "wasm", wasm_bindgen(js_name = closureJson))]
+pub fn closure_json(matrix_json: &str, kind: &str) -> Result<String, String> {
+    let matrix = super::parse::parse_json_matrix(matrix_json)?;
+    let closed = closure_kind(kind)?.trace(&matrix).matrix;
+    Ok(super::render::json_matrix(&closed))
+}
+
+/// 관계 분류 키 목록을 JSON 배열로 반환 (예: ["equivalence"])
+#[cfg_attr(feature = "wasm", wasm_bindgen(js_name = classify))]
+pub fn classify_json(cells: &[u8], n: usize) -> Result<String, String> {
+    let properties = Properties::of(&from_flat(cells, n)?);
+    let keys: Vec<String> = super::report::classify(&properties)
+        .iter()
+        .map(|class| super::render::json_string(class.key()))
+        .collect();
+    Ok(format!("[{}]", keys.join(", ")))
+}
+
+/// 동치류를 JSON 배열로 반환 (예: [[1, 2], [3]]), 동치 관계가 아니면 오류
+#[cfg_attr(feature = "wasm", wasm_bindgen(js_name = equivalenceClasses))]
+pub fn equivalence_classes_json(cells: &[u8], n: usize) -> Result<String, String> {
+    let matrix = from_flat(cells, n)?;
+    if !super::equivalence::is_equivalence_relation(&matrix) {
+        return Err(t(Msg::NoEquivalenceClasses).to_string());
+    }
+    let classes: Vec<String> = super::visualize::equivalence_classes(&matrix)
+        .iter()
+        .map(|class| super::render::json_elements(class))
+        .collect();
+    Ok(format!("[{}]", classes.join(", ")))
+}
+
+/// JSON 행렬을 분석하여 JSON 보고서(--format json과 같은 형식)를 반환
+#[cfg_attr(feature = "wasm", wasm_bindgen(js_name = analyze))]
+pub fn analyze_json(matrix_json: &str) -> Result<String, String> {
+    let matrix = super::parse::parse_json_matrix(matrix_json)?;
+    let report = super::report::analyze_relation(&matrix);
+    Ok(super::render::Renderer::render(
+        &super::render::JsonRenderer,
+        &report,
+    ))
+}
+
+/// 오류 메시지와 분류 이름의 언어를 정하는 함수 ("ko" 또는 "en", 알 수 없으면 false)
+#[cfg_attr(feature = "wasm", wasm_bindgen(js_name = setLanguage))]
+pub fn set_language(code: &str) -> bool {
+    match super::i18n::Lang::parse(code) {
+        Some(lang) => {
+            super::i18n::set_lang(lang);
+            true
+        }
+        None => false,
+    }
+}
@@ -0,0 +1,80 @@
+// 브라우저용 API(src/wasm.rs) 테스트 - wasm-bindgen-test-runner로 실행
+// cargo test --target wasm32-unknown-unknown --features wasm --test wasm
+#![cfg(all(target_arch = "wasm32", feature = "wasm"))]
+
+use discrete_mathematics_equivalence::wasm;
+use wasm_bindgen::JsValue;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+/// 3개 원소 위의 관계 {(1,1), (1,2), (2,2), (2,3), (3,3)} (반사적이지만 대칭/추이적이지 않음)
+const PATH: [u8; 9] = [1, 1, 0, 0, 1, 1, 0, 0, 1];
+
+#[wasm_bindgen_test]
+fn is_equivalence_relation_checks_all_three_properties() {
+    assert_eq!(wasm::is_equivalence_relation(&PATH, 3), Ok(false));
+    assert_eq!(
+        wasm::is_equivalence_relation(&[1, 1, 0, 1, 1, 0, 0, 0, 1], 3),
+        Ok(true)
+    );
+    assert_eq!(wasm::is_equivalence_relation(&[0], 1), Ok(false));
+}
+
+#[wasm_bindgen_test]
+fn closure_returns_row_major_cells() {
+    assert_eq!(wasm::closure(&PATH, 3, "refl"), Ok(PATH.to_vec()));
+    assert_eq!(
+        wasm::closure(&PATH, 3, "sym"),
+        Ok(vec![1, 1, 0, 1, 1, 1, 0, 1, 1])
+    );
+    assert_eq!(
+        wasm::closure(&PATH, 3, "trans"),
+        Ok(vec![1, 1, 1, 0, 1, 1, 0, 0, 1])
+    );
+    assert_eq!(wasm::closure(&PATH, 3, "equiv"), Ok(vec![1; 9]));
+    assert_eq!(
+        wasm::closure_json("[[0, 1], [0, 0]]", "equivalence"),
+        Ok("[[1, 1], [1, 1]]".to_string())
+    );
+}
+
+#[wasm_bindgen_test]
+fn equivalence_classes_are_one_based_json() {
+    assert_eq!(
+        wasm::equivalence_classes_json(&[1, 0, 1, 0, 1, 0, 1, 0, 1], 3),
+        Ok("[[1, 3], [2]]".to_string())
+    );
+    let closed = wasm::closure(&PATH, 3, "equiv").unwrap();
+    assert_eq!(
+        wasm::equivalence_classes_json(&closed, 3),
+        Ok("[[1, 2, 3]]".to_string())
+    );
+}
+
+/// 오류는 wasm-bindgen이 JavaScript 문자열로 throw하는 값과 같아야 함
+fn thrown(result: Result<impl std::fmt::Debug, String>) -> String {
+    let message = result.expect_err("오류가 발생해야 함");
+    JsValue::from(message.clone())
+        .as_string()
+        .expect("문자열로 throw되어야 함")
+}
+
+#[wasm_bindgen_test]
+fn invalid_input_throws() {
+    let message = thrown(wasm::is_equivalence_relation(&[1, 0, 1], 2));
+    assert!(message.contains('3'), "{message}");
+    assert!(!thrown(wasm::is_equivalence_relation(&[], 0)).is_empty());
+    assert!(!thrown(wasm::is_reflexive(&[1, 2, 0, 1], 2)).is_empty());
+    assert!(thrown(wasm::closure(&PATH, 3, "bogus")).contains("bogus"));
+    assert!(!thrown(wasm::closure_json("[[1, 0]", "refl")).is_empty());
+    assert!(!thrown(wasm::equivalence_classes_json(&PATH, 3)).is_empty());
+}
+
+#[wasm_bindgen_test]
+fn set_language_switches_error_messages() {
+    assert!(!wasm::set_language("xx"));
+    assert!(wasm::set_language("en"));
+    let english = thrown(wasm::equivalence_classes_json(&PATH, 3));
+    assert!(english.is_ascii(), "{english}");
+    assert!(wasm::set_language("ko"));
+    assert!(!thrown(wasm::equivalence_classes_json(&PATH, 3)).is_ascii());
+}